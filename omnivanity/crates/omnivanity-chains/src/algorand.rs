@@ -2,8 +2,8 @@
 //!
 //! Algorand address: Ed25519 pubkey + 4-byte checksum (last 4 bytes of SHA512/256), Base32 encoded
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
-use omnivanity_crypto::{Ed25519Keypair, hex};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
+use omnivanity_crypto::{Ed25519Keypair, hex, encoding::algorand_decode};
 use sha2::{Sha512_256, Digest};
 
 /// Algorand chain
@@ -76,6 +76,10 @@ impl Chain for Algorand {
     fn address_prefix(&self, _address_type: AddressType) -> &'static str {
         ""
     }
+
+    fn validate_address(&self, address: &str, _address_type: AddressType) -> bool {
+        algorand_decode(address).is_ok()
+    }
 }
 
 impl Algorand {
@@ -102,6 +106,9 @@ impl Algorand {
             public_key_hex: hex::encode(public_key),
             chain: "ALGO".to_string(),
             address_type: AddressType::Algorand,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         }
     }
 }
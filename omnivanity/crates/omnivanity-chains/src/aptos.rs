@@ -2,7 +2,7 @@
 //!
 //! Aptos address: SHA3-256(pubkey || signature_scheme_id) = 32 bytes, hex encoded
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
 use omnivanity_crypto::{Ed25519Keypair, hash::sha3_256, hex};
 
 /// Aptos chain
@@ -73,6 +73,9 @@ impl Aptos {
             public_key_hex: hex::encode(public_key),
             chain: "APT".to_string(),
             address_type: AddressType::Aptos,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         }
     }
 }
@@ -1,8 +1,8 @@
 //! Bitcoin Cash (BCH) CashAddr adapter
 //!
-//! BCH uses CashAddr format: bitcoincash:q...
+//! BCH uses CashAddr format: bitcoincash:q... (P2PKH) / bitcoincash:p... (P2SH)
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
 use omnivanity_crypto::{
     Secp256k1Keypair,
     hash::hash160,
@@ -13,6 +13,8 @@ use omnivanity_crypto::{
 /// Bitcoin Cash chain
 pub struct BitcoinCash;
 
+const CASHADDR_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
 // CashAddr polymod checksum
 fn cashaddr_polymod(values: &[u8]) -> u64 {
     let mut c: u64 = 1;
@@ -28,53 +30,144 @@ fn cashaddr_polymod(values: &[u8]) -> u64 {
     c ^ 1
 }
 
-fn cashaddr_encode(prefix: &str, payload: &[u8]) -> String {
-    const CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
-    
-    // Convert prefix to 5-bit values
-    let mut values: Vec<u8> = prefix.bytes().map(|c| c & 0x1f).collect();
-    values.push(0); // separator
-    
-    // Version byte (0 = P2PKH) + payload converted to 5-bit
-    let mut payload_5bit = Vec::new();
-    payload_5bit.push(0); // Version: P2PKH, 160-bit hash
-    
-    // Convert 8-bit payload to 5-bit
+fn convert_bits_8_to_5(data: &[u8]) -> Vec<u8> {
     let mut acc = 0u32;
     let mut bits = 0;
-    for byte in payload {
-        acc = (acc << 8) | (*byte as u32);
+    let mut out = Vec::with_capacity(data.len() * 8 / 5 + 1);
+    for &byte in data {
+        acc = (acc << 8) | (byte as u32);
         bits += 8;
         while bits >= 5 {
             bits -= 5;
-            payload_5bit.push(((acc >> bits) & 0x1f) as u8);
+            out.push(((acc >> bits) & 0x1f) as u8);
         }
     }
     if bits > 0 {
-        payload_5bit.push(((acc << (5 - bits)) & 0x1f) as u8);
+        out.push(((acc << (5 - bits)) & 0x1f) as u8);
     }
-    
-    values.extend(&payload_5bit);
-    
-    // Add checksum placeholder
-    for _ in 0..8 {
-        values.push(0);
+    out
+}
+
+fn convert_bits_5_to_8(data: &[u8]) -> Option<Vec<u8>> {
+    let mut acc = 0u32;
+    let mut bits = 0;
+    let mut out = Vec::with_capacity(data.len() * 5 / 8);
+    for &v in data {
+        acc = (acc << 5) | (v as u32);
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            out.push(((acc >> bits) & 0xff) as u8);
+        }
+    }
+    // Remaining bits must be zero padding, not leftover data.
+    if bits >= 5 || (acc & ((1 << bits) - 1)) != 0 {
+        return None;
     }
-    
+    Some(out)
+}
+
+/// CashAddr version byte: high bit reserved at 0, bit 3 is the type (0 =
+/// P2PKH, 1 = P2SH), and the low 3 bits encode the hash size per BCH's
+/// CashAddr spec (0 = 160 bits, 1 = 192, ... 7 = 512).
+fn cashaddr_version_byte(type_bit: u8, payload_len: usize) -> Option<u8> {
+    let size_bits = match payload_len {
+        20 => 0,
+        24 => 1,
+        28 => 2,
+        32 => 3,
+        40 => 4,
+        48 => 5,
+        56 => 6,
+        64 => 7,
+        _ => return None,
+    };
+    Some((type_bit << 3) | size_bits)
+}
+
+fn cashaddr_payload_len(size_bits: u8) -> Option<usize> {
+    Some(match size_bits {
+        0 => 20,
+        1 => 24,
+        2 => 28,
+        3 => 32,
+        4 => 40,
+        5 => 48,
+        6 => 56,
+        7 => 64,
+        _ => return None,
+    })
+}
+
+/// Encode `payload` (a 20-byte hash160, or a larger hash for P2SH-32) as a
+/// CashAddr with the given `prefix` and type bit (0 = P2PKH, 1 = P2SH).
+fn cashaddr_encode(prefix: &str, type_bit: u8, payload: &[u8]) -> Option<String> {
+    let version_byte = cashaddr_version_byte(type_bit, payload.len())?;
+
+    let mut values: Vec<u8> = prefix.bytes().map(|c| c & 0x1f).collect();
+    values.push(0); // separator
+
+    let mut versioned_payload = Vec::with_capacity(1 + payload.len());
+    versioned_payload.push(version_byte);
+    versioned_payload.extend_from_slice(payload);
+    let payload_5bit = convert_bits_8_to_5(&versioned_payload);
+
+    values.extend(&payload_5bit);
+    values.extend(std::iter::repeat(0).take(8)); // checksum placeholder
+
     let checksum = cashaddr_polymod(&values);
     let checksum_values: Vec<u8> = (0..8).map(|i| ((checksum >> (5 * (7 - i))) & 0x1f) as u8).collect();
-    
-    // Build result
+
     let mut result = String::from(prefix);
     result.push(':');
     for v in payload_5bit {
-        result.push(CHARSET[v as usize] as char);
+        result.push(CASHADDR_CHARSET[v as usize] as char);
     }
     for v in checksum_values {
-        result.push(CHARSET[v as usize] as char);
+        result.push(CASHADDR_CHARSET[v as usize] as char);
     }
-    
-    result
+
+    Some(result)
+}
+
+/// Decode a CashAddr string back into its `(prefix, type_bit, payload)`
+/// (type bit 0 = P2PKH, 1 = P2SH), verifying the polymod checksum. Accepts addresses with or without an
+/// explicit `prefix:` (bare payload is assumed to be `bitcoincash`).
+pub fn cashaddr_decode(addr: &str) -> Option<(String, u8, Vec<u8>)> {
+    let lower = addr.to_lowercase();
+    let (prefix, body) = match lower.split_once(':') {
+        Some((p, b)) => (p.to_string(), b),
+        None => ("bitcoincash".to_string(), lower.as_str()),
+    };
+
+    let mut values = Vec::with_capacity(body.len());
+    for c in body.chars() {
+        let pos = CASHADDR_CHARSET.iter().position(|&x| x as char == c)?;
+        values.push(pos as u8);
+    }
+    if values.len() < 8 {
+        return None;
+    }
+
+    let mut check_input: Vec<u8> = prefix.bytes().map(|c| c & 0x1f).collect();
+    check_input.push(0);
+    check_input.extend_from_slice(&values);
+    if cashaddr_polymod(&check_input) != 0 {
+        return None;
+    }
+
+    let data = &values[..values.len() - 8];
+    let decoded = convert_bits_5_to_8(data)?;
+    let (&version_byte, payload) = decoded.split_first()?;
+
+    let type_bit = (version_byte >> 3) & 0x01;
+    let size_bits = version_byte & 0x07;
+    let expected_len = cashaddr_payload_len(size_bits)?;
+    if payload.len() != expected_len {
+        return None;
+    }
+
+    Some((prefix, type_bit, payload.to_vec()))
 }
 
 impl Chain for BitcoinCash {
@@ -91,7 +184,7 @@ impl Chain for BitcoinCash {
     }
 
     fn address_types(&self) -> Vec<AddressType> {
-        vec![AddressType::CashAddr]
+        vec![AddressType::CashAddr, AddressType::CashAddrP2sh]
     }
 
     fn default_address_type(&self) -> AddressType {
@@ -117,29 +210,49 @@ impl Chain for BitcoinCash {
         "qpzry9x8gf2tvdw0s3jn54khce6mua7l"
     }
 
-    fn address_prefix(&self, _address_type: AddressType) -> &'static str {
-        "bitcoincash:q"
+    fn address_prefix(&self, address_type: AddressType) -> &'static str {
+        match address_type {
+            AddressType::CashAddrP2sh => "bitcoincash:p",
+            _ => "bitcoincash:q",
+        }
+    }
+
+    fn validate_address(&self, address: &str, address_type: AddressType) -> bool {
+        match cashaddr_decode(address) {
+            Some((_, type_bit, _)) => {
+                let expected = matches!(address_type, AddressType::CashAddrP2sh) as u8;
+                type_bit == expected
+            }
+            None => false,
+        }
     }
 }
 
 impl BitcoinCash {
-    fn generate_from_keypair(&self, keypair: &Secp256k1Keypair, _address_type: AddressType) -> GeneratedAddress {
+    fn generate_from_keypair(&self, keypair: &Secp256k1Keypair, address_type: AddressType) -> GeneratedAddress {
         let private_key = keypair.private_key_bytes();
         let pubkey = keypair.public_key_compressed();
-        
-        // BCH CashAddr: bitcoincash: + bech32-like encoding of hash160
+
+        // BCH's P2SH path has no redeem script of its own here, so it wraps
+        // the same hash160(pubkey) a real P2SH-P2WPKH script hash would use
+        // - this demonstrates the P2SH CashAddr encoding, not a specific
+        // multisig/script template.
         let h160 = hash160(&pubkey);
-        let address = cashaddr_encode("bitcoincash", &h160);
-        
+        let type_bit = matches!(address_type, AddressType::CashAddrP2sh) as u8;
+        let address = cashaddr_encode("bitcoincash", type_bit, &h160).unwrap_or_default();
+
         let wif = wif_encode(&private_key, true, true);
-        
+
         GeneratedAddress {
             address,
             private_key_hex: hex::encode(private_key),
             private_key_native: wif,
             public_key_hex: hex::encode(pubkey),
             chain: "BCH".to_string(),
-            address_type: AddressType::CashAddr,
+            address_type,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         }
     }
 }
@@ -155,4 +268,42 @@ mod tests {
         assert!(addr.address.starts_with("bitcoincash:q"));
         assert_eq!(addr.chain, "BCH");
     }
+
+    #[test]
+    fn test_bch_p2sh_generation() {
+        let bch = BitcoinCash;
+        let addr = bch.generate(AddressType::CashAddrP2sh);
+        assert!(addr.address.starts_with("bitcoincash:p"));
+    }
+
+    #[test]
+    fn test_cashaddr_roundtrip_p2pkh() {
+        let bch = BitcoinCash;
+        let addr = bch.generate(AddressType::CashAddr);
+        let (prefix, type_bit, payload) = cashaddr_decode(&addr.address).unwrap();
+        assert_eq!(prefix, "bitcoincash");
+        assert_eq!(type_bit, 0);
+        assert_eq!(payload.len(), 20);
+    }
+
+    #[test]
+    fn test_cashaddr_roundtrip_p2sh() {
+        let bch = BitcoinCash;
+        let addr = bch.generate(AddressType::CashAddrP2sh);
+        let (prefix, type_bit, payload) = cashaddr_decode(&addr.address).unwrap();
+        assert_eq!(prefix, "bitcoincash");
+        assert_eq!(type_bit, 1);
+        assert_eq!(payload.len(), 20);
+    }
+
+    #[test]
+    fn test_cashaddr_decode_rejects_corrupted_checksum() {
+        let bch = BitcoinCash;
+        let addr = bch.generate(AddressType::CashAddr);
+        let mut corrupted = addr.address.clone();
+        let last = corrupted.pop().unwrap();
+        let replacement = if last == 'q' { 'p' } else { 'q' };
+        corrupted.push(replacement);
+        assert!(cashaddr_decode(&corrupted).is_none());
+    }
 }
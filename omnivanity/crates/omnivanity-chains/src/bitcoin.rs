@@ -1,10 +1,10 @@
 //! Bitcoin chain adapter
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
 use omnivanity_crypto::{
     Secp256k1Keypair,
     hash::hash160,
-    encoding::{base58check_encode, wif_encode, bech32_encode_v0},
+    encoding::{base58check_encode, base58check_decode, wif_encode, bech32_encode_v0, bech32_decode, bech32},
     hex,
 };
 
@@ -25,7 +25,7 @@ impl Chain for Bitcoin {
     }
 
     fn address_types(&self) -> Vec<AddressType> {
-        vec![AddressType::P2pkh, AddressType::P2wpkh, AddressType::P2tr]
+        vec![AddressType::P2pkh, AddressType::P2wpkh, AddressType::P2tr, AddressType::Bech32, AddressType::P2shP2wpkh]
     }
 
     fn default_address_type(&self) -> AddressType {
@@ -47,10 +47,58 @@ impl Chain for Bitcoin {
         Some(self.generate_from_keypair(&keypair, address_type))
     }
 
+    fn generate_for_network(&self, network: Network, address_type: AddressType) -> GeneratedAddress {
+        let keypair = Secp256k1Keypair::generate();
+        self.generate_from_keypair_for_network(&keypair, address_type, network)
+    }
+
+    fn generate_batch(&self, address_type: AddressType, count: usize) -> Vec<GeneratedAddress> {
+        if count == 0 {
+            return Vec::new();
+        }
+
+        let base = Secp256k1Keypair::generate();
+        let mut result = Vec::with_capacity(count);
+        result.push(self.generate_from_keypair(&base, address_type));
+
+        // `increment_batch` can return fewer than `count - 1` if it hit an
+        // edge case partway through (see its doc comment) - top up any
+        // shortfall with fresh `generate()` calls so callers always get
+        // exactly `count` addresses back.
+        for keypair in base.increment_batch(count - 1) {
+            result.push(self.generate_from_keypair(&keypair, address_type));
+        }
+        while result.len() < count {
+            result.push(self.generate(address_type));
+        }
+        result
+    }
+
+    fn address_prefix_for_network(&self, address_type: AddressType, network: Network) -> &'static str {
+        match (network, address_type) {
+            (Network::Mainnet, _) => self.address_prefix(address_type),
+            (_, AddressType::P2pkh) => "m",
+            (_, AddressType::P2sh | AddressType::P2shP2wpkh) => "2",
+            (Network::Testnet, AddressType::P2wpkh | AddressType::Bech32) => "tb1q",
+            (Network::Testnet, AddressType::P2tr) => "tb1p",
+            (Network::Regtest, AddressType::P2wpkh | AddressType::Bech32) => "bcrt1q",
+            (Network::Regtest, AddressType::P2tr) => "bcrt1p",
+            _ => "",
+        }
+    }
+
+    fn address_version_byte(&self, address_type: AddressType) -> Option<u8> {
+        match address_type {
+            AddressType::P2pkh => Some(0x00),
+            AddressType::P2sh | AddressType::P2shP2wpkh => Some(0x05),
+            _ => None,
+        }
+    }
+
     fn valid_address_chars(&self, address_type: AddressType) -> &'static str {
         match address_type {
-            AddressType::P2pkh | AddressType::P2sh => "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
-            AddressType::P2wpkh | AddressType::P2tr => "023456789acdefghjklmnpqrstuvwxyz",
+            AddressType::P2pkh | AddressType::P2sh | AddressType::P2shP2wpkh => "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
+            AddressType::P2wpkh | AddressType::P2tr | AddressType::Bech32 => "023456789acdefghjklmnpqrstuvwxyz",
             _ => "",
         }
     }
@@ -58,40 +106,94 @@ impl Chain for Bitcoin {
     fn address_prefix(&self, address_type: AddressType) -> &'static str {
         match address_type {
             AddressType::P2pkh => "1",
-            AddressType::P2sh => "3",
-            AddressType::P2wpkh => "bc1q",
+            AddressType::P2sh | AddressType::P2shP2wpkh => "3",
+            AddressType::P2wpkh | AddressType::Bech32 => "bc1q",
             AddressType::P2tr => "bc1p",
             _ => "",
         }
     }
+
+    fn validate_address(&self, address: &str, address_type: AddressType) -> bool {
+        match address_type {
+            AddressType::P2pkh | AddressType::P2sh | AddressType::P2shP2wpkh => base58check_decode(address).is_ok(),
+            AddressType::P2wpkh | AddressType::P2tr | AddressType::Bech32 => {
+                bech32_decode(address).map(|(hrp, _, _)| hrp == "bc").unwrap_or(false)
+            }
+            _ => false,
+        }
+    }
+
+    fn address_from_public_key(&self, pubkey: &[u8], address_type: AddressType) -> Option<String> {
+        // Every address type below only ever hashes the compressed public
+        // key - no private scalar needed - except Taproot, whose key-path
+        // tweak needs the secret to pick the even-Y internal key (see
+        // `Secp256k1Keypair::taproot_output_key`), so that case is left
+        // unsupported for split-key search (mainnet only, like `generate`).
+        if pubkey.len() != 33 {
+            return None;
+        }
+        match address_type {
+            AddressType::P2pkh => Some(base58check_encode(0x00, &hash160(pubkey))),
+            AddressType::P2wpkh => bech32_encode_v0("bc", &hash160(pubkey)),
+            AddressType::Bech32 => bech32::encode("bc", 0, &hash160(pubkey)),
+            AddressType::P2shP2wpkh => {
+                let redeem_script = p2wpkh_redeem_script(pubkey);
+                Some(base58check_encode(0x05, &hash160(&redeem_script)))
+            }
+            _ => None,
+        }
+    }
 }
 
 impl Bitcoin {
     fn generate_from_keypair(&self, keypair: &Secp256k1Keypair, address_type: AddressType) -> GeneratedAddress {
+        self.generate_from_keypair_for_network(keypair, address_type, Network::Mainnet)
+    }
+
+    /// `generate_from_keypair`, but swapping in testnet/regtest version
+    /// bytes, bech32 HRPs, and WIF version so the result is only ever valid
+    /// on the requested `network`.
+    fn generate_from_keypair_for_network(&self, keypair: &Secp256k1Keypair, address_type: AddressType, network: Network) -> GeneratedAddress {
         let private_key = keypair.private_key_bytes();
         let pubkey_compressed = keypair.public_key_compressed();
-        
+        let (p2pkh_version, p2sh_version, hrp) = match network {
+            Network::Mainnet => (0x00, 0x05, "bc"),
+            Network::Testnet => (0x6f, 0xc4, "tb"),
+            Network::Regtest => (0x6f, 0xc4, "bcrt"),
+        };
+
         let address = match address_type {
             AddressType::P2pkh => {
-                // P2PKH: Base58Check(0x00 || HASH160(compressed_pubkey))
+                // P2PKH: Base58Check(version || HASH160(compressed_pubkey))
                 let h160 = hash160(&pubkey_compressed);
-                base58check_encode(0x00, &h160)
+                base58check_encode(p2pkh_version, &h160)
             }
             AddressType::P2wpkh => {
-                // P2WPKH: bech32(bc, 0, HASH160(compressed_pubkey))
+                // P2WPKH: bech32(hrp, 0, HASH160(compressed_pubkey))
                 let h160 = hash160(&pubkey_compressed);
-                bech32_encode_v0("bc", &h160).unwrap_or_default()
+                bech32_encode_v0(hrp, &h160).unwrap_or_default()
             }
             AddressType::P2tr => {
-                // Taproot: For now, simplified - real taproot needs tweaking
-                // TODO: Implement proper taproot with key tweaking
+                // Taproot (key-path-only, BIP341): witness v1 bech32m over
+                // the tweaked x-only output key.
+                let output_key = keypair.taproot_output_key();
+                bech32::encode(hrp, 1, &output_key).unwrap_or_default()
+            }
+            AddressType::Bech32 => {
+                // Native SegWit via the self-contained encoder (witness v0 P2WPKH)
                 let h160 = hash160(&pubkey_compressed);
-                bech32_encode_v0("bc", &h160).unwrap_or_default().replace("bc1q", "bc1p")
+                bech32::encode(hrp, 0, &h160).unwrap_or_default()
+            }
+            AddressType::P2shP2wpkh => {
+                // Nested SegWit: P2SH(OP_0 <20-byte hash160(pubkey)>)
+                let redeem_script = p2wpkh_redeem_script(&pubkey_compressed);
+                let script_hash = hash160(&redeem_script);
+                base58check_encode(p2sh_version, &script_hash)
             }
             _ => String::new(),
         };
 
-        let wif = wif_encode(&private_key, true, true);
+        let wif = wif_encode(&private_key, true, network == Network::Mainnet);
 
         GeneratedAddress {
             address,
@@ -100,10 +202,24 @@ impl Bitcoin {
             public_key_hex: hex::encode(pubkey_compressed),
             chain: self.ticker().to_string(),
             address_type,
+            network,
+            mnemonic: None,
+            derivation_path: None,
         }
     }
 }
 
+/// Build the 22-byte witness-v0 redeem script `OP_0 <20-byte hash160(pubkey)>`
+/// that nested SegWit (P2SH-P2WPKH) addresses wrap in a P2SH hash.
+pub(crate) fn p2wpkh_redeem_script(pubkey_compressed: &[u8]) -> Vec<u8> {
+    let h160 = hash160(pubkey_compressed);
+    let mut script = Vec::with_capacity(22);
+    script.push(0x00); // OP_0
+    script.push(0x14); // push 20 bytes
+    script.extend_from_slice(&h160);
+    script
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -125,6 +241,83 @@ mod tests {
         assert!(addr.address.starts_with("bc1q"));
     }
 
+    #[test]
+    fn test_btc_bech32_generation() {
+        let btc = Bitcoin;
+        let addr = btc.generate(AddressType::Bech32);
+
+        assert!(addr.address.starts_with("bc1q"));
+        assert!(btc.validate_address(&addr.address, AddressType::Bech32));
+    }
+
+    #[test]
+    fn test_btc_p2sh_p2wpkh_generation() {
+        let btc = Bitcoin;
+        let addr = btc.generate(AddressType::P2shP2wpkh);
+
+        assert!(addr.address.starts_with("3"));
+        assert!(btc.validate_address(&addr.address, AddressType::P2shP2wpkh));
+    }
+
+    #[test]
+    fn test_btc_p2tr_generation() {
+        let btc = Bitcoin;
+        let addr = btc.generate(AddressType::P2tr);
+
+        assert!(addr.address.starts_with("bc1p"));
+        assert!(btc.validate_address(&addr.address, AddressType::P2tr));
+    }
+
+    #[test]
+    fn test_btc_p2tr_is_not_a_bech32_v0_reencode() {
+        // Regression guard for the old placeholder bug: a correct BIP341
+        // key-path tweak does not just hash160 the pubkey and swap the
+        // bech32 HRP of the P2WPKH address - it bech32m-encodes a distinct
+        // 32-byte tweaked x-only point.
+        let btc = Bitcoin;
+        let privkey = hex::decode("0000000000000000000000000000000000000000000000000000000000000001").unwrap();
+        let p2wpkh = btc.generate_from_bytes(&privkey, AddressType::P2wpkh).unwrap();
+        let p2tr = btc.generate_from_bytes(&privkey, AddressType::P2tr).unwrap();
+
+        let naive_placeholder = p2wpkh.address.replacen("bc1q", "bc1p", 1);
+        assert_ne!(p2tr.address, naive_placeholder);
+    }
+
+    #[test]
+    fn test_btc_p2tr_deterministic() {
+        let btc = Bitcoin;
+        let privkey = hex::decode("0000000000000000000000000000000000000000000000000000000000000001").unwrap();
+        let a = btc.generate_from_bytes(&privkey, AddressType::P2tr).unwrap();
+        let b = btc.generate_from_bytes(&privkey, AddressType::P2tr).unwrap();
+        assert_eq!(a.address, b.address);
+    }
+
+    #[test]
+    fn test_decode_address_recovers_p2wpkh_payload() {
+        let btc = Bitcoin;
+        let addr = btc.generate(AddressType::P2wpkh);
+        let decoded = btc.decode_address(&addr.address).expect("generated address should decode");
+        assert_eq!(decoded.address_type, AddressType::P2wpkh);
+        assert_eq!(decoded.payload.len(), 20);
+    }
+
+    #[test]
+    fn test_decode_address_recovers_p2pkh_payload() {
+        let btc = Bitcoin;
+        let addr = btc.generate(AddressType::P2pkh);
+        let decoded = btc.decode_address(&addr.address).expect("generated address should decode");
+        assert_eq!(decoded.address_type, AddressType::P2pkh);
+        assert_eq!(decoded.payload.len(), 20);
+    }
+
+    #[test]
+    fn test_import_native_key_roundtrips_wif() {
+        let btc = Bitcoin;
+        let original = btc.generate(AddressType::P2wpkh);
+        let imported = btc.import_native_key(&original.private_key_native).unwrap();
+        assert_eq!(imported.address, original.address);
+    }
+
     #[test]
     fn test_known_vector() {
         let btc = Bitcoin;
@@ -136,4 +329,34 @@ mod tests {
         assert_eq!(addr.address, "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH");
         assert_eq!(addr.private_key_native, "KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFU73sVHnoWn");
     }
+
+    #[test]
+    fn test_testnet_p2pkh_known_vector() {
+        let btc = Bitcoin;
+        let privkey = hex::decode("0000000000000000000000000000000000000000000000000000000000000001").unwrap();
+        let keypair = Secp256k1Keypair::from_bytes(&privkey.try_into().unwrap()).unwrap();
+        let addr = btc.generate_from_keypair_for_network(&keypair, AddressType::P2pkh, Network::Testnet);
+
+        assert_eq!(addr.address, "mrCDrCybB6J1vRfbwM5hemdJz73FwDBC8r");
+        assert_eq!(addr.network, Network::Testnet);
+        assert_eq!(addr.private_key_native, "cMahea7zqjxrtgAbB7LSGbcQUr1uX1ojuat9jZodMN87JcbXMTcA");
+    }
+
+    #[test]
+    fn test_testnet_and_regtest_p2wpkh_use_distinct_hrps() {
+        let btc = Bitcoin;
+        let testnet = btc.generate_for_network(Network::Testnet, AddressType::P2wpkh);
+        let regtest = btc.generate_for_network(Network::Regtest, AddressType::P2wpkh);
+
+        assert!(testnet.address.starts_with("tb1q"));
+        assert!(regtest.address.starts_with("bcrt1q"));
+        assert_eq!(btc.address_prefix_for_network(AddressType::P2wpkh, Network::Testnet), "tb1q");
+        assert_eq!(btc.address_prefix_for_network(AddressType::P2wpkh, Network::Regtest), "bcrt1q");
+    }
+
+    #[test]
+    fn test_mainnet_address_prefix_for_network_matches_address_prefix() {
+        let btc = Bitcoin;
+        assert_eq!(btc.address_prefix_for_network(AddressType::P2pkh, Network::Mainnet), "1");
+    }
 }
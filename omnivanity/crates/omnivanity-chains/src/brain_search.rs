@@ -0,0 +1,226 @@
+//! Passphrase-seeded "brain wallet" vanity search and recovery
+//!
+//! Builds `brain_secp256k1_bytes`/`brain_ed25519_bytes` (deterministic,
+//! passphrase-derived keys) into two chain-aware tools: `brain_prefix`, which
+//! mutates a base phrase by an appended counter until the derived address
+//! matches a pattern, and `brain_recover`, which brute-forces small edits of
+//! a partially-remembered phrase (typos, appended characters) to find the
+//! one that reproduces a known address.
+
+use omnivanity_crypto::{brain_ed25519_bytes, brain_secp256k1_bytes, generate_mnemonic_words};
+use thiserror::Error;
+
+use crate::traits::{AddressType, Chain, ChainFamily, GeneratedAddress};
+use crate::vanity::{PatternSpec, VanityError};
+
+#[derive(Error, Debug)]
+pub enum BrainError {
+    #[error(transparent)]
+    Pattern(#[from] VanityError),
+    #[error("no match found within {0} attempts")]
+    Exhausted(u64),
+}
+
+/// A brain-wallet match: the address plus the exact phrase that reproduces it.
+pub struct BrainMatch {
+    pub address: GeneratedAddress,
+    pub phrase: String,
+    pub attempts: u64,
+}
+
+fn brain_bytes_for(chain: &dyn Chain, phrase: &str) -> [u8; 32] {
+    match chain.family() {
+        ChainFamily::Ed25519 => brain_ed25519_bytes(phrase),
+        _ => brain_secp256k1_bytes(phrase),
+    }
+}
+
+/// Search `"{base_phrase} {counter}"` for increasing `counter` until the
+/// derived address matches `pattern`, or `max_attempts` is exhausted.
+pub fn brain_prefix(
+    chain: &dyn Chain,
+    address_type: AddressType,
+    pattern: PatternSpec,
+    base_phrase: &str,
+    max_attempts: u64,
+) -> Result<BrainMatch, BrainError> {
+    // Validate the pattern against the chain's alphabet up front, same as VanitySearch.
+    let _ = crate::vanity::VanitySearch::new(chain, address_type, pattern.clone())?;
+
+    let chain_prefix = chain.address_prefix(address_type);
+    for counter in 0..max_attempts {
+        let phrase = format!("{base_phrase} {counter}");
+        let bytes = brain_bytes_for(chain, &phrase);
+        if let Some(addr) = chain.generate_from_bytes(&bytes, address_type) {
+            if pattern.matches(&addr.address, chain_prefix) {
+                return Ok(BrainMatch { address: addr, phrase, attempts: counter + 1 });
+            }
+        }
+    }
+    Err(BrainError::Exhausted(max_attempts))
+}
+
+/// Like [`brain_prefix`], but instead of mutating a fixed base phrase with an
+/// appended counter, each attempt is an independent random BIP39 mnemonic
+/// (`word_count` words, 12 or 24). Slower per-attempt (mnemonic generation
+/// draws fresh entropy each time) but the recovered phrase is a genuinely
+/// memorable, BIP39-style passphrase rather than "base phrase N".
+pub fn brain_prefix_words(
+    chain: &dyn Chain,
+    address_type: AddressType,
+    pattern: PatternSpec,
+    word_count: u32,
+    max_attempts: u64,
+) -> Result<BrainMatch, BrainError> {
+    let _ = crate::vanity::VanitySearch::new(chain, address_type, pattern.clone())?;
+
+    let chain_prefix = chain.address_prefix(address_type);
+    for attempt in 0..max_attempts {
+        let Ok(phrase) = generate_mnemonic_words(word_count) else {
+            return Err(BrainError::Exhausted(max_attempts));
+        };
+        let bytes = brain_bytes_for(chain, &phrase);
+        if let Some(addr) = chain.generate_from_bytes(&bytes, address_type) {
+            if pattern.matches(&addr.address, chain_prefix) {
+                return Ok(BrainMatch { address: addr, phrase, attempts: attempt + 1 });
+            }
+        }
+    }
+    Err(BrainError::Exhausted(max_attempts))
+}
+
+const RECOVERY_CHARSET: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+/// Brute-force small edits of `known_phrase` - single-character
+/// substitutions, adjacent-character transpositions, and up to two appended
+/// characters from a lowercase alphanumeric charset - looking for the
+/// variant whose derived address equals `target_address`. Covers the common
+/// "I mistyped/transposed/forgot the last couple characters" recovery
+/// cases; does not attempt insertions/deletions or multi-character
+/// substitutions.
+pub fn brain_recover(
+    chain: &dyn Chain,
+    address_type: AddressType,
+    known_phrase: &str,
+    target_address: &str,
+) -> Option<BrainMatch> {
+    let mut attempts: u64 = 0;
+    let mut try_phrase = |phrase: String| -> Option<BrainMatch> {
+        attempts += 1;
+        let bytes = brain_bytes_for(chain, &phrase);
+        let addr = chain.generate_from_bytes(&bytes, address_type)?;
+        if addr.address == target_address {
+            Some(BrainMatch { address: addr, phrase, attempts })
+        } else {
+            None
+        }
+    };
+
+    // Exact phrase first.
+    if let Some(found) = try_phrase(known_phrase.to_string()) {
+        return Some(found);
+    }
+
+    // Single-character substitutions (typo fix).
+    let chars: Vec<char> = known_phrase.chars().collect();
+    for i in 0..chars.len() {
+        for &c in RECOVERY_CHARSET {
+            let replacement = c as char;
+            if chars[i] == replacement {
+                continue;
+            }
+            let mut mutated = chars.clone();
+            mutated[i] = replacement;
+            let phrase: String = mutated.into_iter().collect();
+            if let Some(found) = try_phrase(phrase) {
+                return Some(found);
+            }
+        }
+    }
+
+    // Adjacent-character transpositions (fat-fingered swap).
+    for i in 0..chars.len().saturating_sub(1) {
+        if chars[i] == chars[i + 1] {
+            continue;
+        }
+        let mut swapped = chars.clone();
+        swapped.swap(i, i + 1);
+        let phrase: String = swapped.into_iter().collect();
+        if let Some(found) = try_phrase(phrase) {
+            return Some(found);
+        }
+    }
+
+    // Up to two appended characters (forgotten trailing digits/letters).
+    for &c1 in RECOVERY_CHARSET {
+        let phrase = format!("{known_phrase}{}", c1 as char);
+        if let Some(found) = try_phrase(phrase) {
+            return Some(found);
+        }
+        for &c2 in RECOVERY_CHARSET {
+            let phrase = format!("{known_phrase}{}{}", c1 as char, c2 as char);
+            if let Some(found) = try_phrase(phrase) {
+                return Some(found);
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ETH;
+
+    #[test]
+    fn brain_prefix_finds_reproducible_match() {
+        let found = brain_prefix(&ETH, AddressType::Evm, PatternSpec::prefix("0"), "test phrase", 10_000)
+            .expect("some counter should eventually match a single hex nibble");
+        let expected_bytes = brain_bytes_for(&ETH, &found.phrase);
+        let replay = ETH.generate_from_bytes(&expected_bytes, AddressType::Evm).unwrap();
+        assert_eq!(replay.address, found.address.address);
+    }
+
+    #[test]
+    fn brain_prefix_words_finds_reproducible_match() {
+        let found = brain_prefix_words(&ETH, AddressType::Evm, PatternSpec::prefix("0"), 12, 500)
+            .expect("some random mnemonic should eventually match a single hex nibble");
+        let expected_bytes = brain_bytes_for(&ETH, &found.phrase);
+        let replay = ETH.generate_from_bytes(&expected_bytes, AddressType::Evm).unwrap();
+        assert_eq!(replay.address, found.address.address);
+        assert_eq!(found.phrase.split_whitespace().count(), 12);
+    }
+
+    #[test]
+    fn brain_recover_fixes_adjacent_swap() {
+        let correct_phrase = "correct horse battery staple";
+        let bytes = brain_bytes_for(&ETH, correct_phrase);
+        let target = ETH.generate_from_bytes(&bytes, AddressType::Evm).unwrap();
+
+        // Simulate a transposed pair of adjacent characters.
+        let mut swapped: Vec<char> = correct_phrase.chars().collect();
+        swapped.swap(0, 1);
+        let swapped_phrase: String = swapped.into_iter().collect();
+
+        let recovered = brain_recover(&ETH, AddressType::Evm, &swapped_phrase, &target.address)
+            .expect("adjacent swap should recover the phrase");
+        assert_eq!(recovered.phrase, correct_phrase);
+    }
+
+    #[test]
+    fn brain_recover_fixes_single_typo() {
+        let correct_phrase = "correct horse battery staple";
+        let bytes = brain_bytes_for(&ETH, correct_phrase);
+        let target = ETH.generate_from_bytes(&bytes, AddressType::Evm).unwrap();
+
+        // Simulate a one-character typo in the remembered phrase.
+        let mut typo: Vec<char> = correct_phrase.chars().collect();
+        typo[0] = 'k';
+        let typo_phrase: String = typo.into_iter().collect();
+
+        let recovered = brain_recover(&ETH, AddressType::Evm, &typo_phrase, &target.address)
+            .expect("single substitution should recover the phrase");
+        assert_eq!(recovered.phrase, correct_phrase);
+    }
+}
@@ -1,9 +1,9 @@
-//! Cardano chain adapter  
+//! Cardano chain adapter
 //!
-//! Cardano Shelley-era addresses: Bech32 addr1...
+//! Cardano Shelley-era addresses: Bech32 addr1... (mainnet) / addr_test1... (testnet)
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
-use omnivanity_crypto::{Ed25519Keypair, hash::blake2b_224, hex};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
+use omnivanity_crypto::{Ed25519Keypair, hash::{blake2b_224, blake2b_256}, hex};
 
 /// Cardano chain
 pub struct Cardano;
@@ -14,6 +14,48 @@ fn cardano_bech32_encode(hrp: &str, data: &[u8]) -> Result<String, String> {
     bech32::encode::<Bech32>(hrp, data).map_err(|e| e.to_string())
 }
 
+/// A Cardano base address (CIP-19): the minted payment-key `GeneratedAddress`
+/// plus the staking keypair that let it delegate, which `GeneratedAddress`
+/// alone has no field for.
+#[derive(Debug, Clone)]
+pub struct CardanoBaseAddress {
+    pub payment: GeneratedAddress,
+    pub stake_private_key_hex: String,
+    pub stake_public_key_hex: String,
+}
+
+/// Deterministically derive a staking keypair from a payment private key, so
+/// a base address can still be reproduced from the single 32-byte seed that
+/// `Chain::generate_from_bytes` accepts.
+fn derive_stake_keypair(payment_private_key: &[u8; 32]) -> Ed25519Keypair {
+    let mut input = Vec::with_capacity(payment_private_key.len() + 13);
+    input.extend_from_slice(b"cardano-stake");
+    input.extend_from_slice(payment_private_key);
+    let seed = blake2b_256(&input);
+    Ed25519Keypair::from_bytes(&seed).expect("blake2b_256 output is always a valid Ed25519 seed")
+}
+
+fn is_base(address_type: AddressType) -> bool {
+    matches!(address_type, AddressType::CardanoBase | AddressType::CardanoBaseTestnet)
+}
+
+fn is_testnet(address_type: AddressType) -> bool {
+    matches!(address_type, AddressType::CardanoTestnet | AddressType::CardanoBaseTestnet)
+}
+
+fn header_byte(address_type: AddressType) -> u8 {
+    match (is_base(address_type), is_testnet(address_type)) {
+        (false, false) => 0x61, // enterprise, mainnet
+        (false, true) => 0x60,  // enterprise, testnet
+        (true, false) => 0x01,  // base, mainnet
+        (true, true) => 0x00,   // base, testnet
+    }
+}
+
+fn hrp(address_type: AddressType) -> &'static str {
+    if is_testnet(address_type) { "addr_test" } else { "addr" }
+}
+
 impl Chain for Cardano {
     fn ticker(&self) -> &'static str {
         "ADA"
@@ -28,7 +70,12 @@ impl Chain for Cardano {
     }
 
     fn address_types(&self) -> Vec<AddressType> {
-        vec![AddressType::Cardano]
+        vec![
+            AddressType::Cardano,
+            AddressType::CardanoBase,
+            AddressType::CardanoTestnet,
+            AddressType::CardanoBaseTestnet,
+        ]
     }
 
     fn default_address_type(&self) -> AddressType {
@@ -54,34 +101,53 @@ impl Chain for Cardano {
         "023456789acdefghjklmnpqrstuvwxyz"
     }
 
-    fn address_prefix(&self, _address_type: AddressType) -> &'static str {
-        "addr1"
+    fn address_prefix(&self, address_type: AddressType) -> &'static str {
+        if is_testnet(address_type) { "addr_test1" } else { "addr1" }
     }
 }
 
 impl Cardano {
-    fn generate_from_keypair(&self, keypair: &Ed25519Keypair, _address_type: AddressType) -> GeneratedAddress {
+    fn generate_from_keypair(&self, keypair: &Ed25519Keypair, address_type: AddressType) -> GeneratedAddress {
         let private_key = keypair.private_key_bytes();
         let public_key = keypair.public_key_bytes();
-        
-        // Cardano Shelley base address (simplified):
-        // Header byte (0x01 = base address, mainnet) + payment key hash (28 bytes) + stake key hash (28 bytes)
-        // For simplicity, we'll generate an enterprise address (no staking, 0x61 header)
+
         let payment_hash = blake2b_224(&public_key);
-        
-        let mut addr_bytes = Vec::with_capacity(29);
-        addr_bytes.push(0x61); // Enterprise address, mainnet
+
+        let mut addr_bytes = Vec::with_capacity(57);
+        addr_bytes.push(header_byte(address_type));
         addr_bytes.extend_from_slice(&payment_hash);
-        
-        let address = cardano_bech32_encode("addr", &addr_bytes).unwrap_or_default();
-        
+        if is_base(address_type) {
+            let stake_keypair = derive_stake_keypair(&private_key);
+            let stake_hash = blake2b_224(&stake_keypair.public_key_bytes());
+            addr_bytes.extend_from_slice(&stake_hash);
+        }
+
+        let address = cardano_bech32_encode(hrp(address_type), &addr_bytes).unwrap_or_default();
+
         GeneratedAddress {
             address,
             private_key_hex: hex::encode(private_key),
             private_key_native: hex::encode(private_key),
             public_key_hex: hex::encode(public_key),
             chain: "ADA".to_string(),
-            address_type: AddressType::Cardano,
+            address_type,
+            mnemonic: None,
+            derivation_path: None,
+            network: if is_testnet(address_type) { Network::Testnet } else { Network::Mainnet },
+        }
+    }
+
+    /// Generate a base address (CIP-19) along with the derived staking
+    /// keypair, for callers that need the staking credential itself rather
+    /// than just the address it contributed to.
+    pub fn generate_with_stake(&self, address_type: AddressType) -> CardanoBaseAddress {
+        let keypair = Ed25519Keypair::generate();
+        let payment = self.generate_from_keypair(&keypair, address_type);
+        let stake_keypair = derive_stake_keypair(&keypair.private_key_bytes());
+        CardanoBaseAddress {
+            payment,
+            stake_private_key_hex: hex::encode(stake_keypair.private_key_bytes()),
+            stake_public_key_hex: hex::encode(stake_keypair.public_key_bytes()),
         }
     }
 }
@@ -97,4 +163,51 @@ mod tests {
         assert!(addr.address.starts_with("addr1"));
         assert_eq!(addr.chain, "ADA");
     }
+
+    #[test]
+    fn test_cardano_base_generation() {
+        let ada = Cardano;
+        let addr = ada.generate(AddressType::CardanoBase);
+        assert!(addr.address.starts_with("addr1"));
+    }
+
+    #[test]
+    fn test_cardano_testnet_generation() {
+        let ada = Cardano;
+        let addr = ada.generate(AddressType::CardanoTestnet);
+        assert!(addr.address.starts_with("addr_test1"));
+    }
+
+    #[test]
+    fn test_cardano_base_testnet_generation() {
+        let ada = Cardano;
+        let addr = ada.generate(AddressType::CardanoBaseTestnet);
+        assert!(addr.address.starts_with("addr_test1"));
+    }
+
+    #[test]
+    fn test_cardano_base_address_is_longer_than_enterprise() {
+        let privkey = hex::decode("0000000000000000000000000000000000000000000000000000000000000001").unwrap();
+        let ada = Cardano;
+        let enterprise = ada.generate_from_bytes(&privkey, AddressType::Cardano).unwrap();
+        let base = ada.generate_from_bytes(&privkey, AddressType::CardanoBase).unwrap();
+        assert_ne!(enterprise.address, base.address);
+        assert!(base.address.len() > enterprise.address.len());
+    }
+
+    #[test]
+    fn test_generate_from_passphrase_is_deterministic() {
+        let ada = Cardano;
+        let a = ada.generate_from_passphrase("correct horse battery staple", AddressType::Cardano).unwrap();
+        let b = ada.generate_from_passphrase("correct horse battery staple", AddressType::Cardano).unwrap();
+        assert_eq!(a.address, b.address);
+    }
+
+    #[test]
+    fn test_generate_with_stake_surfaces_stake_key() {
+        let ada = Cardano;
+        let result = ada.generate_with_stake(AddressType::CardanoBase);
+        assert!(result.payment.address.starts_with("addr1"));
+        assert_eq!(result.stake_private_key_hex.len(), 64);
+    }
 }
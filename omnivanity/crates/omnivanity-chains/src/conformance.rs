@@ -0,0 +1,94 @@
+//! Known-answer test vectors and a generic cross-chain round-trip harness.
+//!
+//! A silently wrong hash/encoding in one adapter (e.g. an off-by-one in
+//! Zilliqa's `hash[12..32]` slice, or mixing up Kaspa's x-only pubkey bytes)
+//! would otherwise ship undetected, since each adapter only tests itself.
+//! `KNOWN_VECTORS` pins chains to an exact, independently
+//! verifiable fixed-key -> address pair; every other registered chain is
+//! still covered generically below (deterministic from the same key, and
+//! round-trips through its own `validate_address`).
+//!
+//! Exact vectors are only populated for chains we can independently verify
+//! here: `KNOWN_VECTORS` holds Ethereum's, and Bitcoin's P2PKH vector is
+//! checked separately by `canonical_key_matches_bitcoin_known_vector`;
+//! adding a confirmed vector for another chain just means appending a row
+//! to `KNOWN_VECTORS`.
+
+#[cfg(test)]
+mod tests {
+    use crate::{all_chains, AddressType};
+
+    /// `0x00...01` - the same canonical secp256k1/ed25519-seed private key
+    /// used by the individual `test_known_vector` tests in `bitcoin.rs` and
+    /// `ethereum.rs`, so this table's vectors agree with those.
+    const CANONICAL_KEY: [u8; 32] = {
+        let mut k = [0u8; 32];
+        k[31] = 1;
+        k
+    };
+
+    /// (ticker, expected address for `CANONICAL_KEY` at the chain's default address type)
+    const KNOWN_VECTORS: &[(&str, &str)] = &[
+        ("ETH", "0x7e5f4552091a69125d5dfcb7b8c2659029395bdf"),
+    ];
+
+    #[test]
+    fn known_vectors_match_exactly() {
+        for (ticker, expected) in KNOWN_VECTORS {
+            let chain = crate::get_chain(ticker).unwrap_or_else(|| panic!("{ticker} not registered"));
+            let addr = chain
+                .generate_from_bytes(&CANONICAL_KEY, chain.default_address_type())
+                .unwrap_or_else(|| panic!("{ticker} rejected the canonical test key"));
+            assert_eq!(
+                addr.address.to_lowercase(),
+                expected.to_lowercase(),
+                "{ticker} address for the canonical key regressed"
+            );
+        }
+    }
+
+    /// Bitcoin's P2PKH vector isn't in `KNOWN_VECTORS` (it's already pinned
+    /// in `bitcoin.rs::test_known_vector`); this just confirms the two
+    /// canonical keys agree so the table doesn't silently drift from it.
+    #[test]
+    fn canonical_key_matches_bitcoin_known_vector() {
+        let btc = crate::get_chain("BTC").unwrap();
+        let addr = btc.generate_from_bytes(&CANONICAL_KEY, AddressType::P2pkh).unwrap();
+        assert_eq!(addr.address, "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH");
+    }
+
+    /// Every chain in `all_chains()` that accepts the canonical key must:
+    /// reproduce the same address from the same bytes, and have that
+    /// address accepted by its own `validate_address`. This doesn't catch a
+    /// wrong-but-internally-consistent hash the way an exact vector would,
+    /// but it does catch nondeterminism and prefix/charset/checksum
+    /// regressions across every adapter, not just the ones in `KNOWN_VECTORS`.
+    #[test]
+    fn every_chain_round_trips_the_canonical_key() {
+        let mut untested = Vec::new();
+        for chain in all_chains() {
+            let address_type = chain.default_address_type();
+            let Some(a) = chain.generate_from_bytes(&CANONICAL_KEY, address_type) else {
+                untested.push(chain.ticker());
+                continue;
+            };
+            let b = chain
+                .generate_from_bytes(&CANONICAL_KEY, address_type)
+                .expect("already succeeded once above");
+            assert_eq!(a.address, b.address, "{} is not deterministic", chain.ticker());
+            assert!(
+                chain.validate_address(&a.address, address_type),
+                "{} generated an address that fails its own validate_address: {}",
+                chain.ticker(),
+                a.address
+            );
+        }
+        // Not a hard failure - an adapter may reject a bare 32-byte
+        // secp256k1/ed25519 scalar for its default address type - but
+        // surface it instead of silently covering 0 chains.
+        assert!(
+            untested.len() < all_chains().len(),
+            "no registered chain accepted the canonical key - all_chains()/generate_from_bytes likely broken"
+        );
+    }
+}
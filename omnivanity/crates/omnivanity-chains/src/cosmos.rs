@@ -3,42 +3,83 @@
 //! Covers: ATOM, OSMO, INJ, SEI, TIA, JUNO, KAVA, SCRT, RUNE, CRO, etc.
 //! All use: secp256k1 + RIPEMD160(SHA256(pubkey)) + Bech32 with chain-specific HRP
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
 use omnivanity_crypto::{
     Secp256k1Keypair,
-    hash::hash160,
-    encoding::bech32_encode_v0,
+    hash::{hash160, keccak256},
+    encoding::{bech32_encode_v0, wif_encode},
     hex,
 };
 
+/// How a Cosmos-SDK chain derives its account address from a secp256k1 key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CosmosDerivation {
+    /// `bech32(hrp, RIPEMD160(SHA256(compressed_pubkey)))` - the Cosmos-SDK default.
+    Cosmos,
+    /// Ethermint/EVM-compatible chains (Injective, Evmos): derive the account
+    /// exactly like an Ethereum address, then bech32-encode the same 20 bytes.
+    Ethermint,
+}
+
 /// Cosmos-style chain with configurable HRP
 #[derive(Debug, Clone, Copy)]
 pub struct CosmosChain {
     ticker: &'static str,
     name: &'static str,
     hrp: &'static str,
+    derivation: CosmosDerivation,
 }
 
 impl CosmosChain {
     pub const fn new(ticker: &'static str, name: &'static str, hrp: &'static str) -> Self {
-        Self { ticker, name, hrp }
+        Self { ticker, name, hrp, derivation: CosmosDerivation::Cosmos }
+    }
+
+    pub const fn new_with_derivation(
+        ticker: &'static str,
+        name: &'static str,
+        hrp: &'static str,
+        derivation: CosmosDerivation,
+    ) -> Self {
+        Self { ticker, name, hrp, derivation }
     }
 
-    fn generate_from_keypair(&self, keypair: &Secp256k1Keypair, _address_type: AddressType) -> GeneratedAddress {
+    fn generate_from_keypair(&self, keypair: &Secp256k1Keypair, address_type: AddressType) -> GeneratedAddress {
+        self.generate_from_keypair_for_network(keypair, address_type, Network::Mainnet)
+    }
+
+    /// `generate_from_keypair`, tagged with a specific `network`. Public
+    /// Cosmos-SDK testnets (`theta-testnet-*`, `osmo-test-*`, ...) reuse the
+    /// same bech32 HRP as mainnet - the chain is distinguished by chain-id,
+    /// not address format - so this doesn't change the derived bytes, only
+    /// the `GeneratedAddress::network` tag.
+    fn generate_from_keypair_for_network(&self, keypair: &Secp256k1Keypair, _address_type: AddressType, network: Network) -> GeneratedAddress {
         let private_key = keypair.private_key_bytes();
         let pubkey_compressed = keypair.public_key_compressed();
-        
-        // Cosmos address = bech32(hrp, RIPEMD160(SHA256(compressed_pubkey)))
-        let h160 = hash160(&pubkey_compressed);
-        let address = bech32_encode_v0(self.hrp, &h160).unwrap_or_default();
-        
+
+        let account_bytes = match self.derivation {
+            CosmosDerivation::Cosmos => hash160(&pubkey_compressed),
+            CosmosDerivation::Ethermint => {
+                // Ethermint account = last 20 bytes of keccak256(uncompressed X||Y pubkey)
+                let pubkey_xy = keypair.public_key_xy();
+                let hash = keccak256(&pubkey_xy);
+                hash[12..].to_vec()
+            }
+        };
+        let address = bech32_encode_v0(self.hrp, &account_bytes).unwrap_or_default();
+
+        let wif = wif_encode(&private_key, true, true);
+
         GeneratedAddress {
             address,
             private_key_hex: hex::encode(private_key),
-            private_key_native: hex::encode(private_key), // Cosmos typically uses hex
+            private_key_native: wif,
             public_key_hex: hex::encode(pubkey_compressed),
             chain: self.ticker.to_string(),
             address_type: AddressType::Cosmos,
+            mnemonic: None,
+            derivation_path: None,
+            network,
         }
     }
 }
@@ -46,7 +87,10 @@ impl CosmosChain {
 // Pre-defined Cosmos chains
 pub const ATOM: CosmosChain = CosmosChain::new("ATOM", "Cosmos Hub", "cosmos");
 pub const OSMO: CosmosChain = CosmosChain::new("OSMO", "Osmosis", "osmo");
-pub const INJ: CosmosChain = CosmosChain::new("INJ", "Injective", "inj");
+pub const INJ: CosmosChain =
+    CosmosChain::new_with_derivation("INJ", "Injective", "inj", CosmosDerivation::Ethermint);
+pub const EVMOS: CosmosChain =
+    CosmosChain::new_with_derivation("EVMOS", "Evmos", "evmos", CosmosDerivation::Ethermint);
 pub const SEI: CosmosChain = CosmosChain::new("SEI", "Sei", "sei");
 pub const TIA: CosmosChain = CosmosChain::new("TIA", "Celestia", "celestia");
 pub const JUNO: CosmosChain = CosmosChain::new("JUNO", "Juno", "juno");
@@ -81,6 +125,11 @@ impl Chain for CosmosChain {
         self.generate_from_keypair(&keypair, address_type)
     }
 
+    fn generate_for_network(&self, network: Network, address_type: AddressType) -> GeneratedAddress {
+        let keypair = Secp256k1Keypair::generate();
+        self.generate_from_keypair_for_network(&keypair, address_type, network)
+    }
+
     fn generate_from_bytes(&self, private_key: &[u8], address_type: AddressType) -> Option<GeneratedAddress> {
         if private_key.len() != 32 {
             return None;
@@ -110,6 +159,7 @@ mod tests {
         let addr = ATOM.generate(AddressType::Cosmos);
         assert!(addr.address.starts_with("cosmos1"));
         assert_eq!(addr.chain, "ATOM");
+        assert!(addr.private_key_native.starts_with('K') || addr.private_key_native.starts_with('L'));
     }
 
     #[test]
@@ -132,4 +182,62 @@ mod tests {
         assert!(addr.address.starts_with("sei1"));
         assert_eq!(addr.chain, "SEI");
     }
+
+    #[test]
+    fn test_evmos_generation() {
+        let addr = EVMOS.generate(AddressType::Cosmos);
+        assert!(addr.address.starts_with("evmos1"));
+        assert_eq!(addr.chain, "EVMOS");
+    }
+
+    #[test]
+    fn test_generate_from_passphrase_differs_across_chains() {
+        let atom_addr = ATOM.generate_from_passphrase("correct horse battery staple", AddressType::Cosmos).unwrap();
+        let osmo_addr = OSMO.generate_from_passphrase("correct horse battery staple", AddressType::Cosmos).unwrap();
+        assert_ne!(atom_addr.address, osmo_addr.address);
+    }
+
+    #[test]
+    fn test_generate_from_seed_is_deterministic() {
+        let seed = [7u8; 32];
+        let a = ATOM.generate_from_seed(&seed, "m/44'/118'/0'/0/0", AddressType::Cosmos).unwrap();
+        let b = ATOM.generate_from_seed(&seed, "m/44'/118'/0'/0/0", AddressType::Cosmos).unwrap();
+        assert_eq!(a.address.address, b.address.address);
+        assert_eq!(a.path, "m/44'/118'/0'/0/0");
+    }
+
+    #[test]
+    fn test_generate_from_seed_path_changes_address() {
+        let seed = [7u8; 32];
+        let a = ATOM.generate_from_seed(&seed, "m/44'/118'/0'/0/0", AddressType::Cosmos).unwrap();
+        let b = ATOM.generate_from_seed(&seed, "m/44'/118'/0'/0/1", AddressType::Cosmos).unwrap();
+        assert_ne!(a.address.address, b.address.address);
+    }
+
+    #[test]
+    fn test_inj_uses_ethermint_derivation() {
+        let privkey = hex::decode("0000000000000000000000000000000000000000000000000000000000000001").unwrap();
+        let addr = INJ.generate_from_bytes(&privkey, AddressType::Cosmos).unwrap();
+        let atom_style = ATOM.generate_from_bytes(&privkey, AddressType::Cosmos).unwrap();
+        // The Ethermint account bytes (keccak256-derived) differ from the
+        // Cosmos-SDK default (ripemd160(sha256(...))) for the same key.
+        assert_ne!(
+            addr.address.trim_start_matches("inj1"),
+            atom_style.address.trim_start_matches("cosmos1")
+        );
+    }
+
+    #[test]
+    fn test_generate_for_network_tags_but_keeps_hrp() {
+        // Cosmos-SDK testnets reuse the mainnet HRP (chains are told apart
+        // by chain-id, not address format), so the address itself doesn't
+        // change - only the `network` tag does.
+        let privkey = hex::decode("0000000000000000000000000000000000000000000000000000000000000001").unwrap();
+        let mainnet = ATOM.generate_from_bytes(&privkey, AddressType::Cosmos).unwrap();
+        let testnet = ATOM.generate_for_network(Network::Testnet, AddressType::Cosmos);
+
+        assert!(testnet.address.starts_with("cosmos1"));
+        assert_eq!(mainnet.network, Network::Mainnet);
+        assert_eq!(testnet.network, Network::Testnet);
+    }
 }
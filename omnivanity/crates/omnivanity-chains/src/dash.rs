@@ -1,13 +1,16 @@
 //! DASH chain adapter
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
 use omnivanity_crypto::{
     Secp256k1Keypair,
     hash::hash160,
-    encoding::{base58check_encode, wif_encode},
+    encoding::{base58check_encode, wif_encode_versioned},
     hex,
 };
 
+/// Dash's private-key WIF version byte (distinct from Bitcoin's `0x80`).
+const DASH_WIF_VERSION: u8 = 0xCC;
+
 /// Dash chain
 pub struct Dash;
 
@@ -65,9 +68,10 @@ impl Dash {
         let h160 = hash160(&pubkey_compressed);
         let address = base58check_encode(0x4C, &h160);
         
-        // Dash WIF: version byte 0xCC (204)
-        let wif = wif_encode(&private_key, true, true).replace("K", "X").replace("L", "X"); // Simplification
-        let wif = format!("7{}", &wif[1..]); // Dash WIF starts with 7
+        // Dash WIF: version byte 0xCC (204), real checksum over that version
+        // byte rather than a Bitcoin-versioned WIF with the leading
+        // character swapped in (which leaves the checksum mismatched).
+        let wif = wif_encode_versioned(DASH_WIF_VERSION, &private_key, true);
         
         GeneratedAddress {
             address,
@@ -76,6 +80,9 @@ impl Dash {
             public_key_hex: hex::encode(pubkey_compressed),
             chain: "DASH".to_string(),
             address_type: AddressType::P2pkh,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         }
     }
 }
@@ -91,4 +98,22 @@ mod tests {
         assert!(addr.address.starts_with("X"));
         assert_eq!(addr.chain, "DASH");
     }
+
+    #[test]
+    fn test_dash_wif_checksum_is_valid_and_round_trips() {
+        use omnivanity_crypto::encoding::base58check_decode;
+
+        let dash = Dash;
+        let private_key = [9u8; 32];
+        let addr = dash.generate_from_bytes(&private_key, AddressType::P2pkh).unwrap();
+
+        // 0xCC with the compressed-key suffix encodes to a leading 'X'
+        // (without the suffix it would be '7').
+        assert!(addr.private_key_native.starts_with('X'));
+
+        let (version, payload) = base58check_decode(&addr.private_key_native).unwrap();
+        assert_eq!(version, DASH_WIF_VERSION);
+        assert_eq!(&payload[..32], &private_key);
+        assert_eq!(payload[32], 0x01); // compressed-pubkey suffix
+    }
 }
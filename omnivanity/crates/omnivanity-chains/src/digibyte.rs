@@ -1,6 +1,6 @@
 //! DigiByte chain adapter
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
 use omnivanity_crypto::{
     Secp256k1Keypair,
     hash::hash160,
@@ -25,7 +25,7 @@ impl Chain for Digibyte {
     }
 
     fn address_types(&self) -> Vec<AddressType> {
-        vec![AddressType::P2pkh]
+        vec![AddressType::P2pkh, AddressType::P2shP2wpkh]
     }
 
     fn default_address_type(&self) -> AddressType {
@@ -47,33 +47,82 @@ impl Chain for Digibyte {
         Some(self.generate_from_keypair(&keypair, address_type))
     }
 
+    fn generate_batch(&self, address_type: AddressType, count: usize) -> Vec<GeneratedAddress> {
+        if count == 0 {
+            return Vec::new();
+        }
+
+        let base = Secp256k1Keypair::generate();
+        let mut result = Vec::with_capacity(count);
+        result.push(self.generate_from_keypair(&base, address_type));
+
+        // `increment_batch` can return fewer than `count - 1` if it hit an
+        // edge case partway through (see its doc comment) - top up any
+        // shortfall with fresh `generate()` calls so callers always get
+        // exactly `count` addresses back.
+        for keypair in base.increment_batch(count - 1) {
+            result.push(self.generate_from_keypair(&keypair, address_type));
+        }
+        while result.len() < count {
+            result.push(self.generate(address_type));
+        }
+        result
+    }
+
     fn valid_address_chars(&self, _address_type: AddressType) -> &'static str {
         "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"
     }
 
-    fn address_prefix(&self, _address_type: AddressType) -> &'static str {
-        "D"
+    fn address_prefix(&self, address_type: AddressType) -> &'static str {
+        match address_type {
+            AddressType::P2shP2wpkh => "S",
+            _ => "D",
+        }
+    }
+
+    fn address_from_public_key(&self, pubkey: &[u8], address_type: AddressType) -> Option<String> {
+        if pubkey.len() != 33 {
+            return None;
+        }
+        match address_type {
+            AddressType::P2pkh => Some(base58check_encode(0x1E, &hash160(pubkey))),
+            AddressType::P2shP2wpkh => {
+                let redeem_script = crate::bitcoin::p2wpkh_redeem_script(pubkey);
+                Some(base58check_encode(0x3F, &hash160(&redeem_script)))
+            }
+            _ => None,
+        }
     }
 }
 
 impl Digibyte {
-    fn generate_from_keypair(&self, keypair: &Secp256k1Keypair, _address_type: AddressType) -> GeneratedAddress {
+    fn generate_from_keypair(&self, keypair: &Secp256k1Keypair, address_type: AddressType) -> GeneratedAddress {
         let private_key = keypair.private_key_bytes();
         let pubkey_compressed = keypair.public_key_compressed();
-        
-        // DigiByte P2PKH: version byte 0x1E (30)
-        let h160 = hash160(&pubkey_compressed);
-        let address = base58check_encode(0x1E, &h160);
-        
+
+        let address = match address_type {
+            AddressType::P2shP2wpkh => {
+                // Nested SegWit: P2SH(OP_0 <20-byte hash160(pubkey)>), DigiByte's
+                // P2SH version byte 0x3F (63) giving "S..." addresses.
+                let redeem_script = crate::bitcoin::p2wpkh_redeem_script(&pubkey_compressed);
+                base58check_encode(0x3F, &hash160(&redeem_script))
+            }
+            // DigiByte P2PKH: version byte 0x1E (30)
+            _ => base58check_encode(0x1E, &hash160(&pubkey_compressed)),
+        };
+
         let wif = wif_encode(&private_key, true, true);
-        
+
         GeneratedAddress {
             address,
             private_key_hex: hex::encode(private_key),
             private_key_native: wif,
             public_key_hex: hex::encode(pubkey_compressed),
             chain: "DGB".to_string(),
-            address_type: AddressType::P2pkh,
+            address_type,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         }
     }
 }
@@ -89,4 +138,22 @@ mod tests {
         assert!(addr.address.starts_with("D"));
         assert_eq!(addr.chain, "DGB");
     }
+
+    #[test]
+    fn test_dgb_p2sh_p2wpkh_generation() {
+        let dgb = Digibyte;
+        let addr = dgb.generate(AddressType::P2shP2wpkh);
+        assert!(addr.address.starts_with("S"));
+        assert_eq!(addr.address_type, AddressType::P2shP2wpkh);
+    }
+
+    #[test]
+    fn test_dgb_address_from_public_key_matches_generate() {
+        let dgb = Digibyte;
+        let privkey = [9u8; 32];
+        let addr = dgb.generate_from_bytes(&privkey, AddressType::P2shP2wpkh).unwrap();
+        let pubkey = hex::decode(&addr.public_key_hex).unwrap();
+        let from_pubkey = dgb.address_from_public_key(&pubkey, AddressType::P2shP2wpkh).unwrap();
+        assert_eq!(from_pubkey, addr.address);
+    }
 }
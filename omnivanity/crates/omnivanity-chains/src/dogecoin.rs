@@ -1,6 +1,6 @@
 //! Dogecoin chain adapter
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
 use omnivanity_crypto::{
     Secp256k1Keypair,
     hash::hash160,
@@ -52,6 +52,13 @@ impl Chain for Dogecoin {
         Some(self.generate_from_keypair(&keypair, address_type))
     }
 
+    fn address_version_byte(&self, address_type: AddressType) -> Option<u8> {
+        match address_type {
+            AddressType::P2pkh => Some(DOGE_P2PKH_VERSION),
+            _ => None,
+        }
+    }
+
     fn valid_address_chars(&self, _address_type: AddressType) -> &'static str {
         "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"
     }
@@ -79,6 +86,9 @@ impl Dogecoin {
             public_key_hex: hex::encode(pubkey_compressed),
             chain: self.ticker().to_string(),
             address_type: AddressType::P2pkh,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         }
     }
 }
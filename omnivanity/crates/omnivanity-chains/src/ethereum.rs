@@ -3,7 +3,7 @@
 //! Covers: ETH, BNB, MATIC, ARB, OP, AVAX, FTM, GNO, CELO, etc.
 //! All use: secp256k1 + Keccak-256(pubkey[1..65]) last 20 bytes + EIP-55 checksum
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
 use omnivanity_crypto::{Secp256k1Keypair, hash::keccak256, encoding::eip55_checksum, hex};
 
 /// EVM-compatible chain with configurable ticker/name
@@ -36,6 +36,9 @@ impl EvmChain {
             public_key_hex: format!("0x{}", hex::encode(keypair.public_key_uncompressed())),
             chain: self.ticker.to_string(),
             address_type: AddressType::Evm,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         }
     }
 }
@@ -155,6 +158,59 @@ impl Chain for EvmChain {
         Some(self.generate_from_keypair(&keypair, address_type))
     }
 
+    fn generate_next(&self, previous: &GeneratedAddress, address_type: AddressType) -> Option<GeneratedAddress> {
+        // `from_raw_parts` reconstructs the previous keypair from its already-
+        // known private/public key bytes (no scalar multiplication), so the
+        // whole walk only ever pays for one scalar multiply - the very first
+        // `generate()` call that started it.
+        let mut privkey = [0u8; 32];
+        hex::decode_to_slice(previous.private_key_hex.trim_start_matches("0x"), &mut privkey).ok()?;
+        let mut pubkey = [0u8; 65];
+        hex::decode_to_slice(previous.public_key_hex.trim_start_matches("0x"), &mut pubkey).ok()?;
+
+        let keypair = Secp256k1Keypair::from_raw_parts(&privkey, &pubkey).ok()?;
+        let next = keypair.increment()?;
+        Some(self.generate_from_keypair(&next, address_type))
+    }
+
+    fn generate_batch(&self, address_type: AddressType, count: usize) -> Vec<GeneratedAddress> {
+        if count == 0 {
+            return Vec::new();
+        }
+
+        let base = Secp256k1Keypair::generate();
+        let mut result = Vec::with_capacity(count);
+        result.push(self.generate_from_keypair(&base, address_type));
+
+        // `increment_batch` can return fewer than `count - 1` if it hit an
+        // edge case partway through (see its doc comment) - top up any
+        // shortfall with fresh `generate()` calls so callers always get
+        // exactly `count` addresses back.
+        for keypair in base.increment_batch(count - 1) {
+            result.push(self.generate_from_keypair(&keypair, address_type));
+        }
+        while result.len() < count {
+            result.push(self.generate(address_type));
+        }
+        result
+    }
+
+    fn address_from_public_key(&self, pubkey: &[u8], _address_type: AddressType) -> Option<String> {
+        // EVM addresses only need the uncompressed X||Y, which split-key
+        // search only ever hands us as a compressed point.
+        let mut compressed = [0u8; 33];
+        if pubkey.len() != 33 {
+            return None;
+        }
+        compressed.copy_from_slice(pubkey);
+        let uncompressed = omnivanity_crypto::decompress_public_key(&compressed).ok()?;
+
+        let hash = keccak256(&uncompressed[1..65]);
+        let mut address_bytes = [0u8; 20];
+        address_bytes.copy_from_slice(&hash[12..32]);
+        Some(eip55_checksum(&address_bytes))
+    }
+
     fn valid_address_chars(&self, _address_type: AddressType) -> &'static str {
         "0123456789abcdefABCDEF"
     }
@@ -0,0 +1,287 @@
+//! Structured paper-wallet export for generated addresses
+//!
+//! `GeneratedAddress` is convenient for in-memory use but its `private_key_native`
+//! field is a free-form string whose shape depends on the chain (a single WIF for
+//! secp256k1 chains, a `"Spend: ... | View: ..."` pair for Monero, a StrKey
+//! `S...` secret for Stellar). This module normalizes that into a stable,
+//! archivable [`WalletEntry`]/[`PaperWallet`] with explicit per-chain key
+//! fields, plus QR-code matrices and a self-contained printable SVG sheet so
+//! vanity results can be saved offline instead of scraped from stdout.
+
+use crate::traits::GeneratedAddress;
+use serde::{Deserialize, Serialize};
+
+/// Per-chain secret-key material, broken out into explicit named fields
+/// instead of the ad hoc strings stuffed into `private_key_native`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum KeyFields {
+    /// A single WIF-encoded secp256k1 private key (Bitcoin-family chains).
+    Wif { wif: String },
+    /// Monero's dual spend/view secret keys.
+    MoneroKeys { spend: String, view: String },
+    /// Stellar StrKey `S...` secret seed.
+    StellarSecret { secret: String },
+    /// Zcash Sapling's ask/nsk/ovk triple.
+    SaplingKeys { ask: String, nsk: String, ovk: String },
+    /// No chain-specific structure recognized; the native string is kept as-is.
+    Raw { native: String },
+}
+
+impl KeyFields {
+    /// Parse the ad hoc `private_key_native` string produced by a
+    /// [`GeneratedAddress`] into structured key fields, using `chain` (the
+    /// ticker) to pick the right shape.
+    fn from_generated(chain: &str, native: &str) -> Self {
+        match chain {
+            "XMR" => {
+                if let Some((spend_part, view_part)) = native.split_once(" | ") {
+                    let spend = spend_part.trim_start_matches("Spend: ").to_string();
+                    let view = view_part.trim_start_matches("View: ").to_string();
+                    return KeyFields::MoneroKeys { spend, view };
+                }
+                KeyFields::Raw { native: native.to_string() }
+            }
+            "XLM" => KeyFields::StellarSecret { secret: native.to_string() },
+            "ZEC" if native.starts_with("ask: ") => {
+                let mut ask = String::new();
+                let mut nsk = String::new();
+                let mut ovk = String::new();
+                for part in native.split(" | ") {
+                    if let Some(v) = part.strip_prefix("ask: ") {
+                        ask = v.to_string();
+                    } else if let Some(v) = part.strip_prefix("nsk: ") {
+                        nsk = v.to_string();
+                    } else if let Some(v) = part.strip_prefix("ovk: ") {
+                        ovk = v.to_string();
+                    }
+                }
+                KeyFields::SaplingKeys { ask, nsk, ovk }
+            }
+            "BTC" | "LTC" | "DOGE" | "DASH" | "RVN" | "DGB" | "BCH" | "ZEC"
+            | "ATOM" | "OSMO" | "INJ" | "SEI" | "TIA" | "JUNO" | "KAVA" | "SCRT" | "RUNE"
+            | "FIL" | "KAS" | "STX" | "XRP" | "ZIL" => {
+                KeyFields::Wif { wif: native.to_string() }
+            }
+            _ => KeyFields::Raw { native: native.to_string() },
+        }
+    }
+}
+
+/// One archivable paper-wallet entry: an address, its address type, and
+/// structured secret-key material.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletEntry {
+    /// Chain ticker (e.g. "BTC", "ETH").
+    pub chain: String,
+    /// Address type as rendered by `AddressType`'s `Display` impl.
+    pub address_type: String,
+    /// The address string.
+    pub address: String,
+    /// Private key, hex-encoded raw bytes.
+    pub private_key_hex: String,
+    /// Structured, chain-appropriate secret-key fields.
+    pub key_fields: KeyFields,
+    /// Public key in hex format.
+    pub public_key_hex: String,
+}
+
+impl WalletEntry {
+    /// Build a `WalletEntry` from a `GeneratedAddress`, normalizing its
+    /// free-form `private_key_native` into structured `key_fields`.
+    pub fn from_generated(addr: &GeneratedAddress) -> Self {
+        WalletEntry {
+            chain: addr.chain.clone(),
+            address_type: addr.address_type.to_string(),
+            address: addr.address.clone(),
+            private_key_hex: addr.private_key_hex.clone(),
+            key_fields: KeyFields::from_generated(&addr.chain, &addr.private_key_native),
+            public_key_hex: addr.public_key_hex.clone(),
+        }
+    }
+}
+
+/// A JSON-serializable archive of one or more generated addresses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaperWallet {
+    /// Schema version, bumped whenever `WalletEntry`'s shape changes.
+    pub version: u32,
+    /// The archived entries.
+    pub entries: Vec<WalletEntry>,
+}
+
+impl PaperWallet {
+    /// Wrap a single generated address.
+    pub fn single(addr: &GeneratedAddress) -> Self {
+        Self::many(std::slice::from_ref(addr))
+    }
+
+    /// Wrap many generated addresses into one archive.
+    pub fn many(addrs: &[GeneratedAddress]) -> Self {
+        PaperWallet {
+            version: 1,
+            entries: addrs.iter().map(WalletEntry::from_generated).collect(),
+        }
+    }
+
+    /// Serialize to pretty-printed JSON.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Render every entry's address and secret as QR matrices and lay them
+    /// out on one self-contained printable SVG sheet.
+    pub fn to_svg(&self) -> String {
+        const CARD_HEIGHT: u32 = 260;
+        const MODULE_SIZE: u32 = 4;
+
+        let mut body = String::new();
+        for (i, entry) in self.entries.iter().enumerate() {
+            let y_offset = i as u32 * CARD_HEIGHT;
+            let secret = entry.key_fields.primary_secret();
+
+            body.push_str(&format!(
+                r#"<g transform="translate(0,{y_offset})">"#,
+            ));
+            body.push_str(&format!(
+                r#"<text x="20" y="30" font-family="monospace" font-size="16">{} ({})</text>"#,
+                xml_escape(&entry.chain), xml_escape(&entry.address_type),
+            ));
+            body.push_str(&format!(
+                r#"<text x="20" y="52" font-family="monospace" font-size="12">Address: {}</text>"#,
+                xml_escape(&entry.address),
+            ));
+            body.push_str(&qr_to_svg_group(&entry.address, 20, 70, MODULE_SIZE));
+            body.push_str(&qr_to_svg_group(&secret, 320, 70, MODULE_SIZE));
+            body.push_str("</g>");
+        }
+
+        let total_height = (self.entries.len() as u32).max(1) * CARD_HEIGHT;
+        format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="620" height="{total_height}" viewBox="0 0 620 {total_height}">{body}</svg>"#,
+        )
+    }
+}
+
+impl KeyFields {
+    /// The single string best suited for a secret-key QR code (the field a
+    /// wallet app would actually import).
+    fn primary_secret(&self) -> String {
+        match self {
+            KeyFields::Wif { wif } => wif.clone(),
+            KeyFields::MoneroKeys { spend, .. } => spend.clone(),
+            KeyFields::StellarSecret { secret } => secret.clone(),
+            KeyFields::SaplingKeys { ask, .. } => ask.clone(),
+            KeyFields::Raw { native } => native.clone(),
+        }
+    }
+}
+
+/// Encode `data` as a QR code and return its module matrix (`true` = dark).
+pub fn qr_matrix(data: &str) -> Vec<Vec<bool>> {
+    use qrcode::QrCode;
+
+    let code = QrCode::new(data.as_bytes()).expect("QR payload within version 40 capacity");
+    let width = code.width();
+    (0..width)
+        .map(|y| (0..width).map(|x| code[(x, y)] == qrcode::Color::Dark).collect())
+        .collect()
+}
+
+fn qr_to_svg_group(data: &str, x: u32, y: u32, module_size: u32) -> String {
+    let matrix = qr_matrix(data);
+    let mut out = format!(r#"<g transform="translate({x},{y})">"#);
+    for (row, cells) in matrix.iter().enumerate() {
+        for (col, &dark) in cells.iter().enumerate() {
+            if dark {
+                out.push_str(&format!(
+                    r#"<rect x="{}" y="{}" width="{module_size}" height="{module_size}" fill="black"/>"#,
+                    col as u32 * module_size,
+                    row as u32 * module_size,
+                ));
+            }
+        }
+    }
+    out.push_str("</g>");
+    out
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::{AddressType, Network};
+
+    fn sample(chain: &str, native: &str) -> GeneratedAddress {
+        GeneratedAddress {
+            address: "sample-address".to_string(),
+            private_key_hex: "aa".repeat(32),
+            private_key_native: native.to_string(),
+            public_key_hex: "bb".repeat(32),
+            chain: chain.to_string(),
+            address_type: AddressType::P2pkh,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
+        }
+    }
+
+    #[test]
+    fn test_wif_chain_parses_as_wif() {
+        let addr = sample("BTC", "KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFU73sVHnoWn");
+        let entry = WalletEntry::from_generated(&addr);
+        assert!(matches!(entry.key_fields, KeyFields::Wif { .. }));
+    }
+
+    #[test]
+    fn test_monero_keys_split() {
+        let addr = sample("XMR", "Spend: aaaa | View: bbbb");
+        let entry = WalletEntry::from_generated(&addr);
+        match entry.key_fields {
+            KeyFields::MoneroKeys { spend, view } => {
+                assert_eq!(spend, "aaaa");
+                assert_eq!(view, "bbbb");
+            }
+            _ => panic!("expected MoneroKeys"),
+        }
+    }
+
+    #[test]
+    fn test_stellar_secret() {
+        let addr = sample("XLM", "SABCDEF1234");
+        let entry = WalletEntry::from_generated(&addr);
+        match entry.key_fields {
+            KeyFields::StellarSecret { secret } => assert_eq!(secret, "SABCDEF1234"),
+            _ => panic!("expected StellarSecret"),
+        }
+    }
+
+    #[test]
+    fn test_paper_wallet_json_roundtrip() {
+        let addr = sample("BTC", "KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFU73sVHnoWn");
+        let wallet = PaperWallet::single(&addr);
+        let json = wallet.to_json().unwrap();
+        let parsed: PaperWallet = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.entries.len(), 1);
+        assert_eq!(parsed.entries[0].address, "sample-address");
+    }
+
+    #[test]
+    fn test_qr_matrix_nonempty() {
+        let matrix = qr_matrix("bc1qexampleaddress");
+        assert!(!matrix.is_empty());
+        assert!(matrix.iter().any(|row| row.iter().any(|&d| d)));
+    }
+
+    #[test]
+    fn test_svg_contains_entries() {
+        let addr = sample("BTC", "KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFU73sVHnoWn");
+        let wallet = PaperWallet::single(&addr);
+        let svg = wallet.to_svg();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("sample-address"));
+    }
+}
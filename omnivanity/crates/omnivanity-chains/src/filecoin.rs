@@ -2,8 +2,8 @@
 //!
 //! Filecoin f1 addresses: Blake2b-160(pubkey) + Base32 encoding with checksum
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
-use omnivanity_crypto::{Secp256k1Keypair, hash::blake2b_160, hex};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
+use omnivanity_crypto::{Secp256k1Keypair, hash::blake2b_160, hash::keccak256, encoding::wif_encode, hex};
 use blake2::{Blake2b, Digest};
 use blake2::digest::consts::U4;
 
@@ -13,6 +13,39 @@ pub struct Filecoin;
 // Filecoin uses lowercase base32
 const FIL_BASE32_ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz234567";
 
+/// The EAM (Ethereum Address Manager) actor's namespace ID - every f4
+/// delegated address Filecoin's FVM hands out today is `f410f...`.
+const FIL_EAM_NAMESPACE: u64 = 10;
+
+/// The one-character network prefix Filecoin addresses lead with - `f` on
+/// mainnet, `t` on calibnet/testnet (and, by this tool's convention, also
+/// local regtest). Only this leading character changes by network; the
+/// protocol digit, payload, and checksum are all network-independent.
+fn fil_network_prefix_char(network: Network) -> char {
+    match network {
+        Network::Mainnet => 'f',
+        Network::Testnet | Network::Regtest => 't',
+    }
+}
+
+/// Encode `value` as unsigned LEB128, the variable-length integer format
+/// Filecoin's actor addressing uses for namespace/subaddress IDs.
+fn leb128_encode(mut value: u64) -> Vec<u8> {
+    let mut out = Vec::new();
+    loop {
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        out.push(byte);
+        if value == 0 {
+            break;
+        }
+    }
+    out
+}
+
 fn fil_base32_encode(data: &[u8]) -> String {
     let mut result = String::new();
     let mut bits = 0u32;
@@ -34,17 +67,47 @@ fn fil_base32_encode(data: &[u8]) -> String {
     result
 }
 
-fn fil_checksum(protocol: u8, payload: &[u8]) -> [u8; 4] {
+/// Blake2b-32 over an arbitrary byte sequence - `fil_checksum` is this over
+/// `protocol || payload`; f4 addresses checksum a different sequence
+/// (`protocol || leb128(namespace) || subaddress`) so it's factored out here.
+fn fil_checksum_raw(data: &[u8]) -> [u8; 4] {
     type Blake2b32 = Blake2b<U4>;
     let mut hasher = Blake2b32::new();
-    hasher.update(&[protocol]);
-    hasher.update(payload);
+    hasher.update(data);
     let result = hasher.finalize();
     let mut checksum = [0u8; 4];
     checksum.copy_from_slice(&result);
     checksum
 }
 
+/// Inverse of `fil_base32_encode` - decodes a lowercase RFC-4648-no-padding
+/// base32 string back into bytes, or `None` if `s` contains a character
+/// outside `FIL_BASE32_ALPHABET`.
+fn fil_base32_decode(s: &str) -> Option<Vec<u8>> {
+    let mut result = Vec::new();
+    let mut bits = 0u32;
+    let mut value = 0u32;
+
+    for c in s.bytes() {
+        let digit = FIL_BASE32_ALPHABET.iter().position(|&a| a == c)? as u32;
+        value = (value << 5) | digit;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            result.push((value >> bits) as u8);
+        }
+    }
+
+    Some(result)
+}
+
+fn fil_checksum(protocol: u8, payload: &[u8]) -> [u8; 4] {
+    let mut data = Vec::with_capacity(1 + payload.len());
+    data.push(protocol);
+    data.extend_from_slice(payload);
+    fil_checksum_raw(&data)
+}
+
 impl Chain for Filecoin {
     fn ticker(&self) -> &'static str {
         "FIL"
@@ -59,7 +122,10 @@ impl Chain for Filecoin {
     }
 
     fn address_types(&self) -> Vec<AddressType> {
-        vec![AddressType::Filecoin]
+        let mut types = vec![AddressType::Filecoin, AddressType::FilecoinDelegated];
+        #[cfg(feature = "bls12-381")]
+        types.push(AddressType::FilecoinBls);
+        types
     }
 
     fn default_address_type(&self) -> AddressType {
@@ -67,7 +133,16 @@ impl Chain for Filecoin {
     }
 
     fn generate(&self, address_type: AddressType) -> GeneratedAddress {
+        #[cfg(feature = "bls12-381")]
+        if address_type == AddressType::FilecoinBls {
+            let keypair = omnivanity_crypto::Bls12381Keypair::generate();
+            return self.generate_from_bls_keypair(&keypair);
+        }
+
         let keypair = Secp256k1Keypair::generate();
+        if address_type == AddressType::FilecoinDelegated {
+            return self.generate_delegated_from_keypair(&keypair, Network::Mainnet);
+        }
         self.generate_from_keypair(&keypair, address_type)
     }
 
@@ -75,46 +150,188 @@ impl Chain for Filecoin {
         if private_key.len() != 32 {
             return None;
         }
+
+        #[cfg(feature = "bls12-381")]
+        if address_type == AddressType::FilecoinBls {
+            let mut pk = [0u8; 32];
+            pk.copy_from_slice(private_key);
+            let keypair = omnivanity_crypto::Bls12381Keypair::from_bytes(&pk).ok()?;
+            return Some(self.generate_from_bls_keypair(&keypair));
+        }
+
         let mut pk = [0u8; 32];
         pk.copy_from_slice(private_key);
         let keypair = Secp256k1Keypair::from_bytes(&pk).ok()?;
+        if address_type == AddressType::FilecoinDelegated {
+            return Some(self.generate_delegated_from_keypair(&keypair, Network::Mainnet));
+        }
         Some(self.generate_from_keypair(&keypair, address_type))
     }
 
+    fn generate_for_network(&self, network: Network, address_type: AddressType) -> GeneratedAddress {
+        let keypair = Secp256k1Keypair::generate();
+        if address_type == AddressType::FilecoinDelegated {
+            return self.generate_delegated_from_keypair(&keypair, network);
+        }
+        self.generate_from_keypair_for_network(&keypair, address_type, network)
+    }
+
+    fn address_prefix_for_network(&self, address_type: AddressType, network: Network) -> &'static str {
+        match (network, address_type) {
+            (Network::Mainnet, _) => self.address_prefix(address_type),
+            (_, AddressType::FilecoinDelegated) => "t410f",
+            _ => "t1",
+        }
+    }
+
+    fn parse_address(&self, s: &str, address_type: AddressType) -> Option<Vec<u8>> {
+        if address_type != AddressType::Filecoin {
+            return None;
+        }
+        let body = s.strip_prefix("f1").or_else(|| s.strip_prefix("t1"))?;
+        let decoded = fil_base32_decode(body)?;
+        if decoded.len() != 24 {
+            return None;
+        }
+        let (payload, checksum) = decoded.split_at(20);
+        if fil_checksum(1, payload).as_slice() != checksum {
+            return None;
+        }
+        Some(payload.to_vec())
+    }
+
     fn valid_address_chars(&self, _address_type: AddressType) -> &'static str {
         "abcdefghijklmnopqrstuvwxyz234567"
     }
 
-    fn address_prefix(&self, _address_type: AddressType) -> &'static str {
-        "f1"
+    fn address_prefix(&self, address_type: AddressType) -> &'static str {
+        match address_type {
+            AddressType::FilecoinDelegated => "f410f",
+            _ => "f1",
+        }
     }
 }
 
 impl Filecoin {
-    fn generate_from_keypair(&self, keypair: &Secp256k1Keypair, _address_type: AddressType) -> GeneratedAddress {
+    fn generate_from_keypair(&self, keypair: &Secp256k1Keypair, address_type: AddressType) -> GeneratedAddress {
+        self.generate_from_keypair_for_network(keypair, address_type, Network::Mainnet)
+    }
+
+    /// `generate_from_keypair`, but swapping in the `t` testnet/regtest
+    /// network prefix character so the result only ever parses back as an
+    /// address on the requested `network` - the checksum itself doesn't
+    /// depend on network, only the leading character does.
+    fn generate_from_keypair_for_network(
+        &self,
+        keypair: &Secp256k1Keypair,
+        _address_type: AddressType,
+        network: Network,
+    ) -> GeneratedAddress {
         let private_key = keypair.private_key_bytes();
         let pubkey = keypair.public_key_uncompressed();
-        
+
         // Protocol 1 (secp256k1): payload = Blake2b-160(uncompressed_pubkey)
         let payload = blake2b_160(&pubkey);
-        
+
         // Checksum
         let checksum = fil_checksum(1, &payload);
-        
-        // Address = f1 + base32(payload + checksum)
+
+        // Address = <network prefix>1 + base32(payload + checksum)
         let mut data = Vec::with_capacity(24);
         data.extend_from_slice(&payload);
         data.extend_from_slice(&checksum);
-        
-        let address = format!("f1{}", fil_base32_encode(&data));
-        
+
+        let address = format!("{}1{}", fil_network_prefix_char(network), fil_base32_encode(&data));
+
+        // Filecoin's protocol-1 address derives from the uncompressed pubkey
+        let wif = wif_encode(&private_key, false, network == Network::Mainnet);
+
         GeneratedAddress {
             address,
             private_key_hex: hex::encode(private_key),
-            private_key_native: hex::encode(private_key),
+            private_key_native: wif,
             public_key_hex: hex::encode(pubkey),
             chain: "FIL".to_string(),
             address_type: AddressType::Filecoin,
+            mnemonic: None,
+            derivation_path: None,
+            network,
+        }
+    }
+
+    /// Protocol 4 (delegated, via the EAM actor / namespace 10): the
+    /// subaddress is the same 20-byte derivation Ethereum uses -
+    /// `keccak256(uncompressed_pubkey[1..])[12..]` - so an FEVM contract
+    /// deployer's f4 address lines up with its Ethereum-style `0x...`
+    /// counterpart. Unlike f1/f3, the checksum is over `protocol ||
+    /// leb128(namespace) || subaddress`, not just `protocol || payload`.
+    /// Like `generate_from_keypair_for_network`, only the leading network
+    /// character changes by `network`.
+    fn generate_delegated_from_keypair(&self, keypair: &Secp256k1Keypair, network: Network) -> GeneratedAddress {
+        let private_key = keypair.private_key_bytes();
+        let pubkey = keypair.public_key_uncompressed();
+
+        let hash = keccak256(&pubkey[1..]);
+        let subaddress = &hash[12..];
+
+        let namespace = leb128_encode(FIL_EAM_NAMESPACE);
+        let mut checksum_input = Vec::with_capacity(1 + namespace.len() + subaddress.len());
+        checksum_input.push(4u8);
+        checksum_input.extend_from_slice(&namespace);
+        checksum_input.extend_from_slice(subaddress);
+        let checksum = fil_checksum_raw(&checksum_input);
+
+        let mut data = Vec::with_capacity(subaddress.len() + checksum.len());
+        data.extend_from_slice(subaddress);
+        data.extend_from_slice(&checksum);
+
+        let address = format!(
+            "{}4{}f{}",
+            fil_network_prefix_char(network),
+            FIL_EAM_NAMESPACE,
+            fil_base32_encode(&data)
+        );
+        let wif = wif_encode(&private_key, false, network == Network::Mainnet);
+
+        GeneratedAddress {
+            address,
+            private_key_hex: hex::encode(private_key),
+            private_key_native: wif,
+            public_key_hex: hex::encode(pubkey),
+            chain: "FIL".to_string(),
+            address_type: AddressType::FilecoinDelegated,
+            mnemonic: None,
+            derivation_path: None,
+            network,
+        }
+    }
+
+    /// Protocol 3 (BLS): payload is the raw 48-byte compressed G1 public
+    /// key, unlike f1's Blake2b-160 hash of the (secp256k1) pubkey - BLS
+    /// public keys are already address-sized, so Filecoin encodes them
+    /// directly.
+    #[cfg(feature = "bls12-381")]
+    fn generate_from_bls_keypair(&self, keypair: &omnivanity_crypto::Bls12381Keypair) -> GeneratedAddress {
+        let private_key = keypair.private_key_bytes();
+        let payload = keypair.public_key_compressed();
+
+        let checksum = fil_checksum(3, &payload);
+        let mut data = Vec::with_capacity(payload.len() + checksum.len());
+        data.extend_from_slice(&payload);
+        data.extend_from_slice(&checksum);
+
+        let address = format!("f3{}", fil_base32_encode(&data));
+
+        GeneratedAddress {
+            address,
+            private_key_hex: hex::encode(private_key),
+            private_key_native: hex::encode(private_key),
+            public_key_hex: hex::encode(payload),
+            chain: "FIL".to_string(),
+            address_type: AddressType::FilecoinBls,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         }
     }
 }
@@ -129,5 +346,66 @@ mod tests {
         let addr = fil.generate(AddressType::Filecoin);
         assert!(addr.address.starts_with("f1"));
         assert_eq!(addr.chain, "FIL");
+        assert!(addr.private_key_native.starts_with('5')); // uncompressed WIF
+    }
+
+    #[test]
+    fn test_fil_parse_address_round_trips() {
+        let fil = Filecoin;
+        let addr = fil.generate(AddressType::Filecoin);
+        let payload = fil.parse_address(&addr.address, AddressType::Filecoin).unwrap();
+        assert_eq!(payload.len(), 20);
+    }
+
+    #[test]
+    fn test_fil_parse_address_rejects_bad_checksum() {
+        let fil = Filecoin;
+        let mut addr = fil.generate(AddressType::Filecoin).address;
+        // Flip the last character so the trailing checksum no longer matches.
+        let last = addr.pop().unwrap();
+        let replacement = if last == 'a' { 'b' } else { 'a' };
+        addr.push(replacement);
+        assert!(fil.parse_address(&addr, AddressType::Filecoin).is_none());
+    }
+
+    #[test]
+    fn test_fil_generate_for_network_uses_testnet_prefix() {
+        let fil = Filecoin;
+        let testnet = fil.generate_for_network(Network::Testnet, AddressType::Filecoin);
+        assert!(testnet.address.starts_with("t1"));
+        assert_eq!(testnet.network, Network::Testnet);
+
+        let mainnet = fil.generate_for_network(Network::Mainnet, AddressType::Filecoin);
+        assert!(mainnet.address.starts_with("f1"));
+    }
+
+    #[test]
+    fn test_fil_delegated_generate_for_network_uses_testnet_prefix() {
+        let fil = Filecoin;
+        let testnet = fil.generate_for_network(Network::Testnet, AddressType::FilecoinDelegated);
+        assert!(testnet.address.starts_with("t410f"));
+        assert_eq!(
+            fil.address_prefix_for_network(AddressType::FilecoinDelegated, Network::Testnet),
+            "t410f"
+        );
+    }
+
+    #[test]
+    fn test_fil_delegated_generation() {
+        let fil = Filecoin;
+        let addr = fil.generate(AddressType::FilecoinDelegated);
+        assert!(addr.address.starts_with("f410f"));
+        assert_eq!(addr.chain, "FIL");
+        assert_eq!(addr.address_type, AddressType::FilecoinDelegated);
+    }
+
+    #[cfg(feature = "bls12-381")]
+    #[test]
+    fn test_fil_bls_generation() {
+        let fil = Filecoin;
+        let addr = fil.generate(AddressType::FilecoinBls);
+        assert!(addr.address.starts_with("f3"));
+        assert_eq!(addr.chain, "FIL");
+        assert_eq!(addr.address_type, AddressType::FilecoinBls);
     }
 }
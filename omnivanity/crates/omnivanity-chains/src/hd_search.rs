@@ -0,0 +1,241 @@
+//! Vanity search over the child addresses of a single BIP32 master seed
+//!
+//! `VanitySearch` burns a fresh random keypair per attempt and throws it away
+//! the instant it doesn't match. `HdVanitySearch` instead fixes one master
+//! seed and scans the last index of a derivation path like
+//! `m/44'/0'/0'/0/i`, so a match is reproducible from the mnemonic/seed plus
+//! the winning path instead of a one-off throwaway key.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+use rayon::prelude::*;
+use thiserror::Error;
+
+use omnivanity_crypto::hd::{derive_bip32, derive_slip10_ed25519, generate_mnemonic_words, mnemonic_to_seed, HdError};
+
+use crate::traits::{AddressType, Chain, ChainFamily, GeneratedAddress};
+use crate::vanity::{PatternSpec, VanityError, VanityProgress};
+
+#[derive(Error, Debug)]
+pub enum HdVanityError {
+    #[error(transparent)]
+    Pattern(#[from] VanityError),
+    #[error(transparent)]
+    Hd(#[from] HdError),
+    #[error("derived key was not accepted by the chain adapter")]
+    InvalidDerivedKey,
+}
+
+/// Derive a 32-byte child key from `seed` along `path`, picking BIP32 or
+/// SLIP-0010 by `family` - `Ed25519` chains (Solana/IOTA/Aptos/...) use
+/// SLIP-0010's hardened-only derivation, everything else uses BIP32.
+fn derive_child_key(family: ChainFamily, seed: &[u8], path: &str) -> Result<[u8; 32], HdError> {
+    match family {
+        ChainFamily::Ed25519 => derive_slip10_ed25519(seed, path),
+        ChainFamily::UtxoSecp256k1 | ChainFamily::Evm | ChainFamily::Bech32 => derive_bip32(seed, path),
+    }
+}
+
+/// A `GeneratedAddress` recoverable from a BIP39 mnemonic plus the
+/// derivation path that produced it, instead of a bare one-off private key.
+pub struct MnemonicAddress {
+    pub address: GeneratedAddress,
+    pub mnemonic: String,
+    pub path: String,
+}
+
+/// Derive `chain`'s address at `path` from an existing mnemonic/passphrase pair.
+pub fn generate_from_mnemonic(
+    chain: &dyn Chain,
+    address_type: AddressType,
+    mnemonic: &str,
+    passphrase: &str,
+    path: &str,
+) -> Result<MnemonicAddress, HdVanityError> {
+    let seed = mnemonic_to_seed(mnemonic, passphrase)?;
+    let privkey = derive_child_key(chain.family(), &seed, path)?;
+    let mut address = chain
+        .generate_from_bytes(&privkey, address_type)
+        .ok_or(HdVanityError::InvalidDerivedKey)?;
+    address.mnemonic = Some(mnemonic.to_string());
+    address.derivation_path = Some(path.to_string());
+
+    Ok(MnemonicAddress { address, mnemonic: mnemonic.to_string(), path: path.to_string() })
+}
+
+/// Generate a brand-new `word_count`-word mnemonic (12 or 24) and derive
+/// `chain`'s address at `path` from it, so the mnemonic is the recoverable
+/// backup for the returned address.
+pub fn generate_new_mnemonic_address(
+    chain: &dyn Chain,
+    address_type: AddressType,
+    word_count: u32,
+    passphrase: &str,
+    path: &str,
+) -> Result<MnemonicAddress, HdVanityError> {
+    let mnemonic = generate_mnemonic_words(word_count)?;
+    generate_from_mnemonic(chain, address_type, &mnemonic, passphrase, path)
+}
+
+/// Result of a completed HD vanity search: the matched address plus the
+/// derivation path that produced it, so the result is reproducible from the
+/// master seed alone.
+pub struct HdMatch {
+    pub address: GeneratedAddress,
+    pub path: String,
+    pub attempts: u64,
+    pub elapsed_secs: f64,
+}
+
+/// Scans `{base_path}/i` for increasing `i` against one fixed master seed.
+pub struct HdVanitySearch<'a> {
+    chain: &'a dyn Chain,
+    address_type: AddressType,
+    pattern: PatternSpec,
+    seed: Vec<u8>,
+    base_path: String,
+}
+
+impl<'a> HdVanitySearch<'a> {
+    /// Build a search. `base_path` excludes the final scanned index, e.g.
+    /// `"m/44'/0'/0'/0"` to scan `m/44'/0'/0'/0/0`, `.../1`, `.../2`, ...
+    pub fn new(
+        chain: &'a dyn Chain,
+        address_type: AddressType,
+        pattern: PatternSpec,
+        seed: Vec<u8>,
+        base_path: impl Into<String>,
+    ) -> Result<Self, HdVanityError> {
+        // Reuse VanitySearch's alphabet/pattern validation without its
+        // random-keypair search loop.
+        let _ = crate::vanity::VanitySearch::new(chain, address_type, pattern.clone())?;
+
+        Ok(Self { chain, address_type, pattern, seed, base_path: base_path.into() })
+    }
+
+    /// SLIP-0010 Ed25519 derivation is hardened-only, so the scanned index
+    /// needs a trailing `'` for those chains; BIP32 chains scan a plain
+    /// (non-hardened) last step as before.
+    fn path_for(&self, index: u64) -> String {
+        match self.chain.family() {
+            ChainFamily::Ed25519 => format!("{}/{}'", self.base_path, index),
+            ChainFamily::UtxoSecp256k1 | ChainFamily::Evm | ChainFamily::Bech32 => format!("{}/{}", self.base_path, index),
+        }
+    }
+
+    /// Run the scan with a progress callback, blocking until a match is found.
+    pub fn run(&self, on_progress: impl FnMut(VanityProgress) + Send) -> HdMatch {
+        self.run_bounded(u64::MAX, on_progress).expect("unbounded search never exhausts before finding a match")
+    }
+
+    /// Like [`run`](Self::run), but gives up after `gap_limit` child indices
+    /// have been scanned instead of running forever - the same "how far past
+    /// the last used address do I keep looking" knob wallets use when
+    /// rediscovering an account's used addresses, applied here to vanity
+    /// search instead. `None` means the limit was hit with no match.
+    pub fn run_bounded(&self, gap_limit: u64, mut on_progress: impl FnMut(VanityProgress) + Send) -> Option<HdMatch> {
+        let attempts = Arc::new(AtomicU64::new(0));
+        let found = Arc::new(AtomicBool::new(false));
+        let exhausted = Arc::new(AtomicBool::new(false));
+        let next_index = Arc::new(AtomicU64::new(0));
+        let result: std::sync::Mutex<Option<(GeneratedAddress, String)>> = std::sync::Mutex::new(None);
+        let start = Instant::now();
+        let chain_prefix = self.chain.address_prefix(self.address_type);
+
+        rayon::scope(|s| {
+            let num_threads = rayon::current_num_threads().max(1);
+            for _ in 0..num_threads {
+                let attempts = attempts.clone();
+                let found = found.clone();
+                let exhausted = exhausted.clone();
+                let next_index = next_index.clone();
+                let result = &result;
+                s.spawn(move |_| {
+                    while !found.load(Ordering::Relaxed) {
+                        let index = next_index.fetch_add(1, Ordering::Relaxed);
+                        if index >= gap_limit {
+                            exhausted.store(true, Ordering::Relaxed);
+                            return;
+                        }
+                        let path = self.path_for(index);
+                        let Ok(privkey) = derive_child_key(self.chain.family(), &self.seed, &path) else { continue };
+                        let Some(mut addr) = self.chain.generate_from_bytes(&privkey, self.address_type) else { continue };
+                        attempts.fetch_add(1, Ordering::Relaxed);
+
+                        if self.pattern.matches(&addr.address, chain_prefix) {
+                            addr.derivation_path = Some(path.clone());
+                            *result.lock().unwrap() = Some((addr, path));
+                            found.store(true, Ordering::Relaxed);
+                            return;
+                        }
+                    }
+                });
+            }
+
+            while !found.load(Ordering::Relaxed) && !exhausted.load(Ordering::Relaxed) {
+                let done = attempts.load(Ordering::Relaxed);
+                let elapsed = start.elapsed().as_secs_f64().max(1e-9);
+                let rate = done as f64 / elapsed;
+                on_progress(VanityProgress { attempts: done, attempts_per_sec: rate, eta_secs: None });
+                std::thread::sleep(std::time::Duration::from_millis(200));
+            }
+        });
+
+        let (address, path) = result.into_inner().unwrap()?;
+        Some(HdMatch {
+            address,
+            path,
+            attempts: attempts.load(Ordering::Relaxed),
+            elapsed_secs: start.elapsed().as_secs_f64(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ETH;
+
+    #[test]
+    fn mnemonic_address_is_reproducible() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let a = generate_from_mnemonic(&ETH, AddressType::Evm, mnemonic, "", "m/44'/60'/0'/0/0").unwrap();
+        let b = generate_from_mnemonic(&ETH, AddressType::Evm, mnemonic, "", "m/44'/60'/0'/0/0").unwrap();
+        assert_eq!(a.address.address, b.address.address);
+        assert_eq!(a.path, "m/44'/60'/0'/0/0");
+        assert_eq!(a.address.mnemonic.as_deref(), Some(mnemonic));
+        assert_eq!(a.address.derivation_path.as_deref(), Some("m/44'/60'/0'/0/0"));
+    }
+
+    #[test]
+    fn mnemonic_address_works_for_ed25519_chain() {
+        use crate::SOL;
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let result = generate_from_mnemonic(&SOL, AddressType::Solana, mnemonic, "", "m/44'/501'/0'").unwrap();
+        assert_eq!(result.address.mnemonic.as_deref(), Some(mnemonic));
+    }
+
+    #[test]
+    fn new_mnemonic_address_has_requested_word_count() {
+        let result = generate_new_mnemonic_address(&ETH, AddressType::Evm, 24, "", "m/44'/60'/0'/0/0").unwrap();
+        assert_eq!(result.mnemonic.split_whitespace().count(), 24);
+    }
+
+    #[test]
+    fn finds_reproducible_match_under_fixed_seed() {
+        let seed = vec![7u8; 64];
+        let search = HdVanitySearch::new(
+            &ETH,
+            AddressType::Evm,
+            PatternSpec::prefix("0"),
+            seed,
+            "m/44'/60'/0'/0",
+        )
+        .unwrap();
+        let found = search.run(|_| {});
+        assert!(found.path.starts_with("m/44'/60'/0'/0/"));
+        assert!(found.address.address.strip_prefix("0x").unwrap().starts_with('0'));
+    }
+}
@@ -4,7 +4,7 @@
 //! Note: The numeric 0.0.x account ID is assigned by the network at creation time
 //! and cannot be pre-computed. This adapter generates the EVM alias only.
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
 use omnivanity_crypto::{Secp256k1Keypair, hash::keccak256, encoding::eip55_checksum, hex};
 
 /// Hedera Hashgraph chain (EVM alias addresses)
@@ -74,6 +74,9 @@ impl Hedera {
             public_key_hex: format!("0x{}", hex::encode(keypair.public_key_uncompressed())),
             chain: "HBAR".to_string(),
             address_type: AddressType::Evm,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         }
     }
 }
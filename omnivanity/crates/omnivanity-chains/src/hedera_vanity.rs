@@ -0,0 +1,128 @@
+//! Checksum-aware (EIP-55) vanity search for Hedera's EVM alias addresses
+//!
+//! `Hedera::generate` already returns an EIP-55 mixed-case address, so a
+//! case-sensitive match has to land on the exact upper/lowercase pattern the
+//! checksum hash happens to produce for each hex letter - not just the
+//! right nibble value. This module exposes that as a reusable search plus
+//! an accurate difficulty estimate that accounts for the extra case bit.
+
+use thiserror::Error;
+
+use crate::hedera::Hedera;
+use crate::traits::{AddressType, Chain, GeneratedAddress};
+
+#[derive(Error, Debug)]
+pub enum HederaVanityError {
+    #[error("pattern is empty")]
+    EmptyPattern,
+    #[error("character '{0}' is not valid hex")]
+    InvalidCharacter(char),
+    #[error("no match found within {0} attempts")]
+    Exhausted(u64),
+}
+
+/// Result of a completed EIP-55 vanity search.
+pub struct HederaVanityMatch {
+    pub address: GeneratedAddress,
+    pub attempts: u64,
+}
+
+fn validate_pattern(pattern: &str) -> Result<(), HederaVanityError> {
+    if pattern.is_empty() {
+        return Err(HederaVanityError::EmptyPattern);
+    }
+    for c in pattern.chars() {
+        if !c.is_ascii_hexdigit() {
+            return Err(HederaVanityError::InvalidCharacter(c));
+        }
+    }
+    Ok(())
+}
+
+/// Compare `pattern` against the leading characters of `address` (a `0x...`
+/// EIP-55 checksummed address). When `case_sensitive` is true the comparison
+/// is against the mixed-case checksum form directly; otherwise both sides
+/// are lowercased first.
+pub fn eip55_prefix_matches(address: &str, pattern: &str, case_sensitive: bool) -> bool {
+    let body = address.strip_prefix("0x").unwrap_or(address);
+    if case_sensitive {
+        body.starts_with(pattern)
+    } else {
+        body.to_lowercase().starts_with(&pattern.to_lowercase())
+    }
+}
+
+/// Expected attempts before an `n`-character hex prefix match, accounting
+/// for the EIP-55 case bit: a case-insensitive search only has to land the
+/// right nibble (1-in-16 per character), but a case-sensitive search on a
+/// letter position (`a`-`f`) also has to land the checksum-determined case
+/// (1-in-32 combined); digit positions (`0`-`9`) have no case to match, so
+/// they stay 1-in-16 either way.
+pub fn eip55_vanity_difficulty(pattern: &str, case_sensitive: bool) -> f64 {
+    if !case_sensitive {
+        return 16f64.powi(pattern.chars().count() as i32);
+    }
+    let letters = pattern.chars().filter(|c| c.is_ascii_alphabetic()).count();
+    let digits = pattern.chars().count() - letters;
+    16f64.powi(digits as i32) * 32f64.powi(letters as i32)
+}
+
+/// Search fresh keypairs until the EIP-55 checksummed address has `pattern`
+/// as a prefix, or `max_attempts` is exhausted.
+pub fn search_eip55_prefix(
+    pattern: &str,
+    case_sensitive: bool,
+    max_attempts: u64,
+) -> Result<HederaVanityMatch, HederaVanityError> {
+    validate_pattern(pattern)?;
+
+    let hedera = Hedera;
+    for attempt in 0..max_attempts {
+        let address = hedera.generate(AddressType::Evm);
+        if eip55_prefix_matches(&address.address, pattern, case_sensitive) {
+            return Ok(HederaVanityMatch { address, attempts: attempt + 1 });
+        }
+    }
+    Err(HederaVanityError::Exhausted(max_attempts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eip55_prefix_matches_case_sensitive() {
+        // A known EIP-55 checksum vector: mixed-case "5aAe" must match
+        // case-sensitively but not if the case is flipped.
+        let address = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+        assert!(eip55_prefix_matches(address, "5aAe", true));
+        assert!(!eip55_prefix_matches(address, "5aae", true));
+        assert!(eip55_prefix_matches(address, "5aae", false));
+    }
+
+    #[test]
+    fn test_difficulty_case_sensitive_exceeds_case_insensitive() {
+        let insensitive = eip55_vanity_difficulty("dead", false);
+        let sensitive = eip55_vanity_difficulty("dead", true);
+        assert_eq!(insensitive, 65536.0); // 16^4
+        assert!(sensitive > insensitive); // letters cost an extra case bit
+    }
+
+    #[test]
+    fn test_difficulty_digits_only_unaffected_by_case() {
+        let insensitive = eip55_vanity_difficulty("1234", false);
+        let sensitive = eip55_vanity_difficulty("1234", true);
+        assert_eq!(insensitive, sensitive);
+    }
+
+    #[test]
+    fn test_search_finds_reproducible_single_nibble_match() {
+        let found = search_eip55_prefix("0", false, 10_000).expect("a single hex nibble should match quickly");
+        assert!(eip55_prefix_matches(&found.address.address, "0", false));
+    }
+
+    #[test]
+    fn test_search_rejects_invalid_pattern() {
+        assert!(matches!(search_eip55_prefix("xyz", false, 10), Err(HederaVanityError::InvalidCharacter('x'))));
+    }
+}
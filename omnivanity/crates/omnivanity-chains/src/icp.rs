@@ -2,14 +2,16 @@
 //!
 //! ICP Principals are derived from public keys:
 //! 1. Hash the DER-encoded public key with SHA-224
-//! 2. Append suffix byte 0x02 (self-authenticating)
-//! 3. Encode with Base32 without padding, with hyphens for readability
+//! 2. Append suffix byte 0x02 (self-authenticating) -> 29-byte principal
+//! 3. Prepend a 4-byte big-endian CRC-32 of the principal bytes
+//! 4. Encode the 33-byte `crc || principal` buffer with Base32 (no padding),
+//!    then group into hyphen-separated 5-character chunks for readability
 //!
-//! This gives a 29-byte identifier encoded as text like:
+//! This gives a principal encoded as text like:
 //! "aaaaa-aaaaa-aaaaa-aaaaa-aaaaa-aaaaa-aaaaa-a"
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
-use omnivanity_crypto::{Ed25519Keypair, hex};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
+use omnivanity_crypto::{Ed25519Keypair, hex, encoding::crc32_ieee};
 use sha2::{Sha224, Digest};
 
 /// Internet Computer Principal derivation
@@ -43,6 +45,47 @@ fn base32_encode_icp(data: &[u8]) -> String {
     result
 }
 
+/// Inverse of [`base32_encode_icp`]. Returns `None` on any character outside
+/// the ICP alphabet.
+fn base32_decode_icp(input: &str) -> Option<Vec<u8>> {
+    let mut buffer: u64 = 0;
+    let mut bits_left = 0;
+    let mut out = Vec::with_capacity(input.len() * 5 / 8);
+
+    for c in input.chars() {
+        let idx = ICP_BASE32_ALPHABET.iter().position(|&a| a as char == c)? as u64;
+        buffer = (buffer << 5) | idx;
+        bits_left += 5;
+        if bits_left >= 8 {
+            bits_left -= 8;
+            out.push(((buffer >> bits_left) & 0xFF) as u8);
+        }
+    }
+
+    Some(out)
+}
+
+/// Parses hyphenated principal text back into its 29-byte self-authenticating
+/// principal, verifying the leading 4-byte big-endian CRC-32 along the way.
+/// Returns `None` if the text is malformed or the checksum doesn't match.
+fn decode_principal_text(text: &str) -> Option<[u8; 29]> {
+    let stripped: String = text.chars().filter(|&c| c != '-').collect();
+    let data = base32_decode_icp(&stripped)?;
+    if data.len() != 33 {
+        return None;
+    }
+
+    let (crc_bytes, principal_bytes) = data.split_at(4);
+    let expected_crc = u32::from_be_bytes(crc_bytes.try_into().ok()?);
+    if crc32_ieee(principal_bytes) != expected_crc {
+        return None;
+    }
+
+    let mut principal = [0u8; 29];
+    principal.copy_from_slice(principal_bytes);
+    Some(principal)
+}
+
 fn format_principal(encoded: &str) -> String {
     // Insert hyphens every 5 characters for readability
     encoded
@@ -74,9 +117,17 @@ impl Icp {
         let mut principal_bytes = Vec::with_capacity(29);
         principal_bytes.extend_from_slice(&hash);
         principal_bytes.push(0x02);
-        
+
+        // Canonical principal text prepends a 4-byte big-endian CRC-32 of the
+        // principal bytes before Base32-encoding, so tooling can validate a
+        // principal's checksum without needing the original public key.
+        let crc = crc32_ieee(&principal_bytes);
+        let mut crc_and_principal = Vec::with_capacity(4 + 29);
+        crc_and_principal.extend_from_slice(&crc.to_be_bytes());
+        crc_and_principal.extend_from_slice(&principal_bytes);
+
         // Base32 encode and format with hyphens
-        let encoded = base32_encode_icp(&principal_bytes);
+        let encoded = base32_encode_icp(&crc_and_principal);
         let address = format_principal(&encoded);
         
         GeneratedAddress {
@@ -86,6 +137,9 @@ impl Icp {
             public_key_hex: hex::encode(pubkey),
             chain: "ICP".to_string(),
             address_type: AddressType::Icp,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         }
     }
 }
@@ -146,4 +200,39 @@ mod tests {
         assert!(addr.address.contains('-'));
         assert_eq!(addr.chain, "ICP");
     }
+
+    #[test]
+    fn test_icp_principal_text_roundtrips_and_crc_validates() {
+        let icp = Icp;
+        let private_key = [3u8; 32];
+        let addr = icp.generate_from_bytes(&private_key, AddressType::Icp).unwrap();
+
+        let principal = decode_principal_text(&addr.address).expect("principal text should decode");
+
+        // Recompute the expected 29-byte self-authenticating principal
+        // independently and check it matches what the address encodes.
+        let keypair = Ed25519Keypair::from_bytes(&private_key).unwrap();
+        let der_header: [u8; 12] = [0x30, 0x2a, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x03, 0x21, 0x00];
+        let mut der_encoded = Vec::with_capacity(44);
+        der_encoded.extend_from_slice(&der_header);
+        der_encoded.extend_from_slice(&keypair.public_key_bytes());
+        let mut hasher = Sha224::new();
+        Digest::update(&mut hasher, &der_encoded);
+        let hash = hasher.finalize();
+
+        let mut expected_principal = [0u8; 29];
+        expected_principal[..28].copy_from_slice(&hash);
+        expected_principal[28] = 0x02;
+
+        assert_eq!(principal, expected_principal);
+
+        // A corrupted character should fail CRC validation rather than
+        // silently decoding to the wrong principal.
+        let mut corrupted = addr.address.clone();
+        let flip_at = corrupted.find(|c: char| c != '-').unwrap();
+        let original_char = corrupted.as_bytes()[flip_at] as char;
+        let replacement = if original_char == 'a' { 'b' } else { 'a' };
+        corrupted.replace_range(flip_at..flip_at + 1, &replacement.to_string());
+        assert!(decode_principal_text(&corrupted).is_none());
+    }
 }
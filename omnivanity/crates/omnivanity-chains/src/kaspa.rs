@@ -2,25 +2,36 @@
 //!
 //! Kaspa uses Bech32 with kaspa: prefix
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
-use omnivanity_crypto::{Secp256k1Keypair, hex};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
+use omnivanity_crypto::{Secp256k1Keypair, encoding::wif_encode, hex};
 
 /// Kaspa chain
 pub struct Kaspa;
 
-fn kaspa_bech32_encode(pubkey: &[u8]) -> Result<String, String> {
+fn kaspa_bech32_encode(pubkey: &[u8], hrp: &str) -> Result<String, String> {
     use bech32::{Bech32, Hrp};
     // Kaspa schnorr pubkey (32 bytes x-only) with 0x00 prefix for ECDSA
-    let hrp = Hrp::parse("kaspa").map_err(|e| e.to_string())?;
-    
+    let hrp = Hrp::parse(hrp).map_err(|e| e.to_string())?;
+
     // Prepend pubkey type byte (0x00 = ECDSA, 0x01 = Schnorr)
     let mut data = Vec::with_capacity(33);
     data.push(0x00); // ECDSA type
     data.extend_from_slice(&pubkey[1..33]); // Use x-coordinate from compressed pubkey
-    
+
     bech32::encode::<Bech32>(hrp, &data).map_err(|e| e.to_string())
 }
 
+/// Kaspa's bech32 HRP for each network: `kaspa:` mainnet, `kaspatest:`
+/// public testnet-10, `kaspasim:` the simnet used for local development
+/// (the closest equivalent to a "regtest" network Kaspa has).
+fn kaspa_hrp(network: Network) -> &'static str {
+    match network {
+        Network::Mainnet => "kaspa",
+        Network::Testnet => "kaspatest",
+        Network::Regtest => "kaspasim",
+    }
+}
+
 impl Chain for Kaspa {
     fn ticker(&self) -> &'static str {
         "KAS"
@@ -57,6 +68,19 @@ impl Chain for Kaspa {
         Some(self.generate_from_keypair(&keypair, address_type))
     }
 
+    fn generate_for_network(&self, network: Network, address_type: AddressType) -> GeneratedAddress {
+        let keypair = Secp256k1Keypair::generate();
+        self.generate_from_keypair_for_network(&keypair, address_type, network)
+    }
+
+    fn address_prefix_for_network(&self, _address_type: AddressType, network: Network) -> &'static str {
+        match network {
+            Network::Mainnet => "kaspa:",
+            Network::Testnet => "kaspatest:",
+            Network::Regtest => "kaspasim:",
+        }
+    }
+
     fn valid_address_chars(&self, _address_type: AddressType) -> &'static str {
         "023456789acdefghjklmnpqrstuvwxyz"
     }
@@ -67,19 +91,28 @@ impl Chain for Kaspa {
 }
 
 impl Kaspa {
-    fn generate_from_keypair(&self, keypair: &Secp256k1Keypair, _address_type: AddressType) -> GeneratedAddress {
+    fn generate_from_keypair(&self, keypair: &Secp256k1Keypair, address_type: AddressType) -> GeneratedAddress {
+        self.generate_from_keypair_for_network(keypair, address_type, Network::Mainnet)
+    }
+
+    fn generate_from_keypair_for_network(&self, keypair: &Secp256k1Keypair, _address_type: AddressType, network: Network) -> GeneratedAddress {
         let private_key = keypair.private_key_bytes();
         let pubkey = keypair.public_key_compressed();
-        
-        let address = kaspa_bech32_encode(&pubkey).unwrap_or_default();
-        
+
+        let address = kaspa_bech32_encode(&pubkey, kaspa_hrp(network)).unwrap_or_default();
+
+        let wif = wif_encode(&private_key, true, true);
+
         GeneratedAddress {
             address,
             private_key_hex: hex::encode(private_key),
-            private_key_native: hex::encode(private_key),
+            private_key_native: wif,
             public_key_hex: hex::encode(pubkey),
             chain: "KAS".to_string(),
             address_type: AddressType::Kaspa,
+            mnemonic: None,
+            derivation_path: None,
+            network,
         }
     }
 }
@@ -94,5 +127,17 @@ mod tests {
         let addr = kas.generate(AddressType::Kaspa);
         assert!(addr.address.starts_with("kaspa:"));
         assert_eq!(addr.chain, "KAS");
+        assert!(addr.private_key_native.starts_with('K') || addr.private_key_native.starts_with('L'));
+    }
+
+    #[test]
+    fn test_kaspa_testnet_and_simnet_use_distinct_hrps() {
+        let kas = Kaspa;
+        let testnet = kas.generate_for_network(Network::Testnet, AddressType::Kaspa);
+        let regtest = kas.generate_for_network(Network::Regtest, AddressType::Kaspa);
+
+        assert!(testnet.address.starts_with("kaspatest:"));
+        assert!(regtest.address.starts_with("kaspasim:"));
+        assert_eq!(kas.address_prefix_for_network(AddressType::Kaspa, Network::Testnet), "kaspatest:");
     }
 }
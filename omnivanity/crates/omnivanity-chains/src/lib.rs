@@ -4,6 +4,15 @@
 //! Supports 110+ chains/tokens across multiple address families.
 
 pub mod traits;
+pub mod vanity;
+pub mod hd_search;
+pub mod split_search;
+pub mod brain_search;
+pub mod export;
+pub mod sapling_search;
+pub mod penumbra_search;
+#[cfg(test)]
+mod conformance;
 
 // Chain adapter modules
 pub mod ethereum;
@@ -38,10 +47,22 @@ pub mod bch;
 pub mod cardano;
 pub mod monero;
 pub mod hedera;
+pub mod hedera_vanity;
 pub mod icp;
+pub mod penumbra;
+pub mod pactus;
 
 // Re-exports
-pub use traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
+pub use traits::{
+    Chain, ChainFamily, AddressType, GeneratedAddress, DecodedAddress, SeedAddress, Network, MessageSigningError,
+};
+pub use vanity::{VanitySearch, PatternSpec, VanityMatch, VanityProgress, VanityError};
+pub use hd_search::{HdVanitySearch, HdMatch, HdVanityError, MnemonicAddress, generate_from_mnemonic, generate_new_mnemonic_address};
+pub use split_search::{SplitVanitySearch, SplitMatch, SplitVanityError};
+pub use brain_search::{BrainMatch, BrainError, brain_prefix, brain_prefix_words, brain_recover};
+pub use export::{PaperWallet, WalletEntry, KeyFields, qr_matrix};
+pub use sapling_search::{SaplingDiversifierSearch, SaplingMatch};
+pub use penumbra_search::{PenumbraDiversifierSearch, PenumbraMatch};
 
 // EVM chains and tokens (60+)
 pub use ethereum::{
@@ -70,7 +91,7 @@ pub use digibyte::Digibyte;
 pub use bch::BitcoinCash;
 
 // Cosmos chains
-pub use cosmos::{CosmosChain, ATOM, OSMO, INJ, SEI, TIA, JUNO, KAVA, SCRT, RUNE};
+pub use cosmos::{CosmosChain, CosmosDerivation, ATOM, OSMO, INJ, EVMOS, SEI, TIA, JUNO, KAVA, SCRT, RUNE};
 
 // Other chains
 pub use xrp::Xrp;
@@ -89,10 +110,13 @@ pub use xdc::Xdc;
 pub use midnight::Midnight;
 pub use kaspa::Kaspa;
 pub use tezos::Tezos;
-pub use cardano::Cardano;
+pub use cardano::{Cardano, CardanoBaseAddress};
 pub use monero::Monero;
 pub use hedera::Hedera;
+pub use hedera_vanity::{eip55_prefix_matches, eip55_vanity_difficulty, search_eip55_prefix, HederaVanityError, HederaVanityMatch};
 pub use icp::Icp;
+pub use penumbra::Penumbra;
+pub use pactus::Pactus;
 
 // SS58/Polkadot chains
 pub use polkadot::{Ss58Chain, DOT, KSM, ACA, CFG, HDX};
@@ -132,12 +156,33 @@ pub fn all_chains() -> Vec<Box<dyn Chain>> {
         Box::new(Near), Box::new(Iota), Box::new(Algorand), Box::new(Filecoin),
         Box::new(Zilliqa), Box::new(Nano), Box::new(Ton), Box::new(Stacks), Box::new(Xdc),
         Box::new(Midnight), Box::new(Kaspa), Box::new(Tezos), Box::new(Cardano), Box::new(Monero),
-        Box::new(Hedera), Box::new(Icp),
+        Box::new(Hedera), Box::new(Icp), Box::new(Penumbra), Box::new(Pactus),
         // SS58/Polkadot chains
         Box::new(DOT), Box::new(KSM), Box::new(ACA), Box::new(CFG), Box::new(HDX),
     ]
 }
 
+/// Get a chain plus the network tier it should generate for, from a ticker
+/// optionally suffixed with `:testnet`/`:regtest` (e.g. `"BTC:testnet"`,
+/// case-insensitive; a bare ticker defaults to `Network::Mainnet`). Doesn't
+/// replace `get_chain` - callers that don't care about non-mainnet addresses
+/// can keep using the plain ticker form.
+pub fn get_chain_and_network(spec: &str) -> Option<(Box<dyn Chain>, Network)> {
+    let (ticker, network) = match spec.split_once(':') {
+        Some((ticker, suffix)) => {
+            let network = match suffix.to_lowercase().as_str() {
+                "mainnet" => Network::Mainnet,
+                "testnet" => Network::Testnet,
+                "regtest" => Network::Regtest,
+                _ => return None,
+            };
+            (ticker, network)
+        }
+        None => (spec, Network::Mainnet),
+    };
+    get_chain(ticker).map(|chain| (chain, network))
+}
+
 /// Get a chain by ticker
 pub fn get_chain(ticker: &str) -> Option<Box<dyn Chain>> {
     match ticker.to_uppercase().as_str() {
@@ -262,6 +307,8 @@ pub fn get_chain(ticker: &str) -> Option<Box<dyn Chain>> {
         "XMR" => Some(Box::new(Monero)),
         "HBAR" => Some(Box::new(Hedera)),
         "ICP" => Some(Box::new(Icp)),
+        "UM" | "PENUMBRA" => Some(Box::new(Penumbra)),
+        "PAC" => Some(Box::new(Pactus)),
         // SS58/Polkadot chains
         "DOT" => Some(Box::new(DOT)),
         "KSM" => Some(Box::new(KSM)),
@@ -273,3 +320,31 @@ pub fn get_chain(ticker: &str) -> Option<Box<dyn Chain>> {
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_chain_and_network_defaults_to_mainnet() {
+        let (chain, network) = get_chain_and_network("BTC").unwrap();
+        assert_eq!(chain.ticker(), "BTC");
+        assert_eq!(network, Network::Mainnet);
+    }
+
+    #[test]
+    fn get_chain_and_network_parses_suffix_case_insensitively() {
+        let (_, network) = get_chain_and_network("btc:TestNet").unwrap();
+        assert_eq!(network, Network::Testnet);
+    }
+
+    #[test]
+    fn get_chain_and_network_rejects_unknown_network_suffix() {
+        assert!(get_chain_and_network("BTC:mainwhoops").is_none());
+    }
+
+    #[test]
+    fn get_chain_and_network_rejects_unknown_ticker() {
+        assert!(get_chain_and_network("NOT_A_CHAIN:testnet").is_none());
+    }
+}
@@ -1,10 +1,10 @@
 //! Litecoin chain adapter
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
 use omnivanity_crypto::{
     Secp256k1Keypair,
     hash::hash160,
-    encoding::{base58check_encode, bech32_encode_v0},
+    encoding::{base58check_encode, bech32_encode_v0, bech32},
     hex,
 };
 
@@ -16,6 +16,11 @@ const LTC_P2PKH_VERSION: u8 = 0x30; // L prefix
 const LTC_P2SH_VERSION: u8 = 0x32;  // M prefix (or 0x05 for 3 prefix)
 const LTC_WIF_VERSION: u8 = 0xB0;
 
+// Litecoin testnet/regtest share Bitcoin's version bytes.
+const LTC_TESTNET_P2PKH_VERSION: u8 = 0x6f;
+const LTC_TESTNET_P2SH_VERSION: u8 = 0xc4;
+const LTC_TESTNET_WIF_VERSION: u8 = 0xef;
+
 impl Chain for Litecoin {
     fn ticker(&self) -> &'static str {
         "LTC"
@@ -30,7 +35,7 @@ impl Chain for Litecoin {
     }
 
     fn address_types(&self) -> Vec<AddressType> {
-        vec![AddressType::P2pkh, AddressType::P2wpkh]
+        vec![AddressType::P2pkh, AddressType::P2wpkh, AddressType::P2tr, AddressType::P2shP2wpkh]
     }
 
     fn default_address_type(&self) -> AddressType {
@@ -52,10 +57,36 @@ impl Chain for Litecoin {
         Some(self.generate_from_keypair(&keypair, address_type))
     }
 
+    fn generate_for_network(&self, network: Network, address_type: AddressType) -> GeneratedAddress {
+        let keypair = Secp256k1Keypair::generate();
+        self.generate_from_keypair_for_network(&keypair, address_type, network)
+    }
+
+    fn address_prefix_for_network(&self, address_type: AddressType, network: Network) -> &'static str {
+        match (network, address_type) {
+            (Network::Mainnet, _) => self.address_prefix(address_type),
+            (_, AddressType::P2pkh) => "m",
+            (_, AddressType::P2shP2wpkh) => "2",
+            (Network::Testnet, AddressType::P2wpkh) => "tltc1q",
+            (Network::Testnet, AddressType::P2tr) => "tltc1p",
+            (Network::Regtest, AddressType::P2wpkh) => "rltc1q",
+            (Network::Regtest, AddressType::P2tr) => "rltc1p",
+            _ => "",
+        }
+    }
+
+    fn address_version_byte(&self, address_type: AddressType) -> Option<u8> {
+        match address_type {
+            AddressType::P2pkh => Some(LTC_P2PKH_VERSION),
+            AddressType::P2shP2wpkh => Some(LTC_P2SH_VERSION),
+            _ => None,
+        }
+    }
+
     fn valid_address_chars(&self, address_type: AddressType) -> &'static str {
         match address_type {
-            AddressType::P2pkh | AddressType::P2sh => "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
-            AddressType::P2wpkh => "023456789acdefghjklmnpqrstuvwxyz",
+            AddressType::P2pkh | AddressType::P2sh | AddressType::P2shP2wpkh => "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
+            AddressType::P2wpkh | AddressType::P2tr => "023456789acdefghjklmnpqrstuvwxyz",
             _ => "",
         }
     }
@@ -63,8 +94,9 @@ impl Chain for Litecoin {
     fn address_prefix(&self, address_type: AddressType) -> &'static str {
         match address_type {
             AddressType::P2pkh => "L",
-            AddressType::P2sh => "M",
+            AddressType::P2sh | AddressType::P2shP2wpkh => "M",
             AddressType::P2wpkh => "ltc1q",
+            AddressType::P2tr => "ltc1p",
             _ => "",
         }
     }
@@ -72,23 +104,44 @@ impl Chain for Litecoin {
 
 impl Litecoin {
     fn generate_from_keypair(&self, keypair: &Secp256k1Keypair, address_type: AddressType) -> GeneratedAddress {
+        self.generate_from_keypair_for_network(keypair, address_type, Network::Mainnet)
+    }
+
+    /// `generate_from_keypair`, but swapping in testnet/regtest version
+    /// bytes, bech32 HRPs, and WIF version.
+    fn generate_from_keypair_for_network(&self, keypair: &Secp256k1Keypair, address_type: AddressType, network: Network) -> GeneratedAddress {
         let private_key = keypair.private_key_bytes();
         let pubkey_compressed = keypair.public_key_compressed();
-        
+        let (p2pkh_version, p2sh_version, wif_version, hrp) = match network {
+            Network::Mainnet => (LTC_P2PKH_VERSION, LTC_P2SH_VERSION, LTC_WIF_VERSION, "ltc"),
+            Network::Testnet => (LTC_TESTNET_P2PKH_VERSION, LTC_TESTNET_P2SH_VERSION, LTC_TESTNET_WIF_VERSION, "tltc"),
+            Network::Regtest => (LTC_TESTNET_P2PKH_VERSION, LTC_TESTNET_P2SH_VERSION, LTC_TESTNET_WIF_VERSION, "rltc"),
+        };
+
         let address = match address_type {
             AddressType::P2pkh => {
                 let h160 = hash160(&pubkey_compressed);
-                base58check_encode(LTC_P2PKH_VERSION, &h160)
+                base58check_encode(p2pkh_version, &h160)
             }
             AddressType::P2wpkh => {
                 let h160 = hash160(&pubkey_compressed);
-                bech32_encode_v0("ltc", &h160).unwrap_or_default()
+                bech32_encode_v0(hrp, &h160).unwrap_or_default()
+            }
+            AddressType::P2tr => {
+                let output_key = keypair.taproot_output_key();
+                bech32::encode(hrp, 1, &output_key).unwrap_or_default()
+            }
+            AddressType::P2shP2wpkh => {
+                // Nested SegWit: P2SH(OP_0 <20-byte hash160(pubkey)>)
+                let redeem_script = crate::bitcoin::p2wpkh_redeem_script(&pubkey_compressed);
+                let script_hash = hash160(&redeem_script);
+                base58check_encode(p2sh_version, &script_hash)
             }
             _ => String::new(),
         };
 
         // LTC WIF
-        let wif = ltc_wif_encode(&private_key, true);
+        let wif = ltc_wif_encode(&private_key, true, wif_version);
 
         GeneratedAddress {
             address,
@@ -97,20 +150,23 @@ impl Litecoin {
             public_key_hex: hex::encode(pubkey_compressed),
             chain: self.ticker().to_string(),
             address_type,
+            mnemonic: None,
+            derivation_path: None,
+            network,
         }
     }
 }
 
-fn ltc_wif_encode(private_key: &[u8; 32], compressed: bool) -> String {
+fn ltc_wif_encode(private_key: &[u8; 32], compressed: bool, version: u8) -> String {
     use omnivanity_crypto::encoding::base58check_encode;
-    
+
     if compressed {
         let mut payload = Vec::with_capacity(33);
         payload.extend_from_slice(private_key);
         payload.push(0x01);
-        base58check_encode(LTC_WIF_VERSION, &payload)
+        base58check_encode(version, &payload)
     } else {
-        base58check_encode(LTC_WIF_VERSION, private_key)
+        base58check_encode(version, private_key)
     }
 }
 
@@ -131,4 +187,37 @@ mod tests {
         let addr = ltc.generate(AddressType::P2wpkh);
         assert!(addr.address.starts_with("ltc1q"));
     }
+
+    #[test]
+    fn test_ltc_p2tr() {
+        let ltc = Litecoin;
+        let addr = ltc.generate(AddressType::P2tr);
+        assert!(addr.address.starts_with("ltc1p"));
+    }
+
+    #[test]
+    fn test_ltc_p2sh_p2wpkh() {
+        let ltc = Litecoin;
+        let addr = ltc.generate(AddressType::P2shP2wpkh);
+        assert!(addr.address.starts_with("M"));
+    }
+
+    #[test]
+    fn test_ltc_testnet_and_regtest_use_distinct_hrps() {
+        let ltc = Litecoin;
+        let testnet = ltc.generate_for_network(Network::Testnet, AddressType::P2wpkh);
+        let regtest = ltc.generate_for_network(Network::Regtest, AddressType::P2wpkh);
+
+        assert!(testnet.address.starts_with("tltc1q"));
+        assert!(regtest.address.starts_with("rltc1q"));
+        assert_eq!(testnet.network, Network::Testnet);
+        assert_eq!(regtest.network, Network::Regtest);
+    }
+
+    #[test]
+    fn test_ltc_testnet_p2pkh_uses_btc_testnet_version_byte() {
+        let ltc = Litecoin;
+        let addr = ltc.generate_for_network(Network::Testnet, AddressType::P2pkh);
+        assert!(addr.address.starts_with('m') || addr.address.starts_with('n'));
+    }
 }
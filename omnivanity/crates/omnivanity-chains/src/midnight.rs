@@ -2,7 +2,7 @@
 //!
 //! Midnight uses Bech32m addresses
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
 use omnivanity_crypto::{Ed25519Keypair, hash::blake2b_256, hex};
 
 /// Midnight chain
@@ -75,6 +75,9 @@ impl Midnight {
             public_key_hex: hex::encode(public_key),
             chain: "NIGHT".to_string(),
             address_type: AddressType::Midnight,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         }
     }
 }
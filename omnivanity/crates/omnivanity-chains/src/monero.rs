@@ -3,10 +3,10 @@
 //! Monero requires dual keypairs (spend/view) and uses unique Base58 encoding.
 //! Address: prefix (18) + spend_pub + view_pub + checksum
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
 use omnivanity_crypto::{
     hex,
-    monero::{sc_reduce32, generate_key_image, base58_monero},
+    monero::{sc_reduce32, generate_key_image, decompress_point, subaddress_keys, base58_monero},
     hash::keccak256,
 };
 use rand::RngCore;
@@ -30,7 +30,7 @@ impl Chain for Monero {
     }
 
     fn address_types(&self) -> Vec<AddressType> {
-        vec![AddressType::Monero] // Will add this type next
+        vec![AddressType::Monero, AddressType::MoneroIntegrated, AddressType::MoneroSubaddress]
     }
 
     fn default_address_type(&self) -> AddressType {
@@ -45,7 +45,7 @@ impl Chain for Monero {
         self.generate_from_bytes(&seed, address_type).unwrap()
     }
 
-    fn generate_from_bytes(&self, private_key: &[u8], _address_type: AddressType) -> Option<GeneratedAddress> {
+    fn generate_from_bytes(&self, private_key: &[u8], address_type: AddressType) -> Option<GeneratedAddress> {
         if private_key.len() != 32 {
             return None;
         }
@@ -55,7 +55,7 @@ impl Chain for Monero {
         // 2. Reduce seed to get Spend Secret Key
         let spend_secret_scalar = sc_reduce32(&seed);
         let spend_public = generate_key_image(&spend_secret_scalar);
-        
+
         // 3. Hash Spend Secret to get View Secret Key (deterministically)
         // Note: Canonical Monero wallets use keccak256(spend_secret) -> reduced scalar
         let spend_secret_bytes = spend_secret_scalar.to_bytes();
@@ -63,35 +63,39 @@ impl Chain for Monero {
         let view_secret_scalar = sc_reduce32(&view_secret_hash);
         let view_public = generate_key_image(&view_secret_scalar);
 
-        // 4. Construct Address
-        // Prefix: 18 (0x12) for primary address
-        let network_byte = 18u8;
-        
-        let mut data = Vec::with_capacity(69); // 1 + 32 + 32 + 4
-        data.push(network_byte);
-        data.extend_from_slice(&spend_public);
-        data.extend_from_slice(&view_public);
-        
-        // Checksum: First 4 bytes of Keccak256(prefix + spend + view)
-        let checksum = keccak256(&data);
-        data.extend_from_slice(&checksum[..4]);
-        
-        // 5. Encode with Monero-specific Base58
-        let address = base58_monero::encode(&data);
+        let address = match address_type {
+            AddressType::Monero => {
+                Self::encode_address(18, &spend_public, &view_public, None)
+            }
+            AddressType::MoneroIntegrated => {
+                let mut payment_id = [0u8; 8];
+                rand::thread_rng().fill_bytes(&mut payment_id);
+                Self::encode_address(19, &spend_public, &view_public, Some(&payment_id))
+            }
+            AddressType::MoneroSubaddress => {
+                let spend_point = decompress_point(&spend_public)?;
+                let (sub_spend, sub_view) = subaddress_keys(&spend_point, &view_secret_scalar, 0, 1);
+                Self::encode_address(42, &sub_spend, &sub_view, None)
+            }
+            _ => return None,
+        };
 
         Some(GeneratedAddress {
             address,
             private_key_hex: hex::encode(spend_secret_bytes), // Standard seed format
-            private_key_native: format!("Spend: {} | View: {}", 
-                hex::encode(spend_secret_bytes), 
+            private_key_native: format!("Spend: {} | View: {}",
+                hex::encode(spend_secret_bytes),
                 hex::encode(view_secret_scalar.to_bytes())
             ),
-            public_key_hex: format!("Spend: {} | View: {}", 
-                hex::encode(spend_public), 
+            public_key_hex: format!("Spend: {} | View: {}",
+                hex::encode(spend_public),
                 hex::encode(view_public)
             ),
             chain: "XMR".to_string(),
-            address_type: AddressType::Monero,
+            address_type,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         })
     }
 
@@ -99,8 +103,32 @@ impl Chain for Monero {
         "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"
     }
 
-    fn address_prefix(&self, _address_type: AddressType) -> &'static str {
-        "4" // Mainnet addresses usually start with 4
+    fn address_prefix(&self, address_type: AddressType) -> &'static str {
+        match address_type {
+            AddressType::MoneroIntegrated => "4", // integrated addresses (byte 19) still Base58-lead with '4'
+            AddressType::MoneroSubaddress => "8", // subaddresses (byte 42) lead with '8'
+            _ => "4", // standard mainnet addresses (byte 18)
+        }
+    }
+}
+
+impl Monero {
+    /// Encode `prefix || spend_pub || view_pub [|| payment_id] || checksum` as
+    /// Monero Base58. `payment_id`, when given, is inserted between the view
+    /// public key and the checksum (integrated-address form).
+    fn encode_address(network_byte: u8, spend_public: &[u8; 32], view_public: &[u8; 32], payment_id: Option<&[u8; 8]>) -> String {
+        let mut data = Vec::with_capacity(1 + 32 + 32 + 8 + 4);
+        data.push(network_byte);
+        data.extend_from_slice(spend_public);
+        data.extend_from_slice(view_public);
+        if let Some(id) = payment_id {
+            data.extend_from_slice(id);
+        }
+
+        let checksum = keccak256(&data);
+        data.extend_from_slice(&checksum[..4]);
+
+        base58_monero::encode(&data)
     }
 }
 
@@ -112,12 +140,69 @@ mod tests {
     fn test_xmr_structure() {
         let xmr = Monero;
         let addr = xmr.generate(AddressType::Monero);
-        
+
         // Standard XMR address is 95 chars
         assert_eq!(addr.address.len(), 95);
         // Starts with 4
         assert!(addr.address.starts_with('4'));
-        
+
         assert_eq!(addr.chain, "XMR");
     }
+
+    #[test]
+    fn test_xmr_integrated_address() {
+        let xmr = Monero;
+        let addr = xmr.generate(AddressType::MoneroIntegrated);
+
+        // Primary address + 8-byte payment ID => 106 Base58 chars
+        assert_eq!(addr.address.len(), 106);
+    }
+
+    #[test]
+    fn test_xmr_subaddress() {
+        let xmr = Monero;
+        let addr = xmr.generate(AddressType::MoneroSubaddress);
+
+        assert_eq!(addr.address.len(), 95);
+        assert!(addr.address.starts_with('8'));
+    }
+
+    /// Cross-checks `A = a*G` and `B = b*G` by re-deriving both public keys
+    /// from the secret scalars packed into `private_key_native` and
+    /// comparing against `public_key_hex`, rather than trusting
+    /// `generate_from_bytes`'s internal derivation alone.
+    #[test]
+    fn test_xmr_keys_round_trip_from_seed() {
+        let xmr = Monero;
+        let seed = [7u8; 32];
+        let addr = xmr.generate_from_bytes(&seed, AddressType::Monero).unwrap();
+
+        let (spend_secret_hex, view_secret_hex) = {
+            let mut parts = addr.private_key_native.split(" | ");
+            let spend = parts.next().unwrap().trim_start_matches("Spend: ");
+            let view = parts.next().unwrap().trim_start_matches("View: ");
+            (spend.to_string(), view.to_string())
+        };
+        let (spend_public_hex, view_public_hex) = {
+            let mut parts = addr.public_key_hex.split(" | ");
+            let spend = parts.next().unwrap().trim_start_matches("Spend: ");
+            let view = parts.next().unwrap().trim_start_matches("View: ");
+            (spend.to_string(), view.to_string())
+        };
+
+        let mut spend_secret_bytes = [0u8; 32];
+        spend_secret_bytes.copy_from_slice(&hex::decode(&spend_secret_hex).unwrap());
+        let mut view_secret_bytes = [0u8; 32];
+        view_secret_bytes.copy_from_slice(&hex::decode(&view_secret_hex).unwrap());
+
+        let spend_public = generate_key_image(&sc_reduce32(&spend_secret_bytes));
+        let view_public = generate_key_image(&sc_reduce32(&view_secret_bytes));
+
+        assert_eq!(hex::encode(spend_public), spend_public_hex);
+        assert_eq!(hex::encode(view_public), view_public_hex);
+
+        // `b = sc_reduce32(Keccak256(a_bytes))`
+        let expected_view_secret = sc_reduce32(&keccak256(&spend_secret_bytes));
+        assert_eq!(hex::encode(expected_view_secret.to_bytes()), view_secret_hex);
+    }
 }
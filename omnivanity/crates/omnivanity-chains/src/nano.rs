@@ -2,7 +2,7 @@
 //!
 //! Nano: Base32 encoded Ed25519 pubkey + Blake2b checksum
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
 use omnivanity_crypto::{Ed25519Keypair, hash::blake2b_256, hex};
 
 /// Nano chain
@@ -101,6 +101,9 @@ impl Nano {
             public_key_hex: hex::encode(public_key),
             chain: "XNO".to_string(),
             address_type: AddressType::Nano,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         }
     }
 }
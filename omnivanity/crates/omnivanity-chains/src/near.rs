@@ -2,7 +2,7 @@
 //!
 //! NEAR implicit accounts: 64-character lowercase hex of Ed25519 pubkey
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
 use omnivanity_crypto::{Ed25519Keypair, hex};
 
 /// NEAR Protocol chain
@@ -51,6 +51,18 @@ impl Chain for Near {
     fn address_prefix(&self, _address_type: AddressType) -> &'static str {
         ""
     }
+
+    /// NEAR's native key format is `ed25519:<base58 secret>`, not a WIF, so
+    /// strip the curve prefix and Base58-decode (no checksum) instead of
+    /// going through the default `Chain::import_native_key`'s Base58Check path.
+    fn import_native_key(&self, native_key: &str) -> Option<GeneratedAddress> {
+        let encoded = native_key.strip_prefix("ed25519:")?;
+        let secret = bs58::decode(encoded).into_vec().ok()?;
+        if secret.len() != 32 {
+            return None;
+        }
+        self.generate_from_bytes(&secret, AddressType::Near)
+    }
 }
 
 impl Near {
@@ -68,6 +80,9 @@ impl Near {
             public_key_hex: hex::encode(public_key),
             chain: "NEAR".to_string(),
             address_type: AddressType::Near,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         }
     }
 }
@@ -83,4 +98,12 @@ mod tests {
         assert_eq!(addr.address.len(), 64); // 64 hex chars
         assert_eq!(addr.chain, "NEAR");
     }
+
+    #[test]
+    fn test_import_native_key_roundtrips() {
+        let near = Near;
+        let original = near.generate(AddressType::Near);
+        let imported = near.import_native_key(&original.private_key_native).unwrap();
+        assert_eq!(imported.address, original.address);
+    }
 }
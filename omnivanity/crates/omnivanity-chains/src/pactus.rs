@@ -0,0 +1,160 @@
+//! Pactus chain adapter
+//!
+//! Pactus address: RIPEMD-160(BLAKE2b-256(Ed25519 pubkey)) -> 20-byte hash,
+//! prefixed with a single address-type byte (3 = account, 1 = validator)
+//! and bech32m-encoded with HRP "pc".
+
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
+use omnivanity_crypto::{
+    Ed25519Keypair,
+    hash::{blake2b_256, ripemd160},
+    encoding::bech32,
+    hex,
+};
+
+/// An Ed25519 account address (type byte 3) - the default, everyday Pactus address.
+const PACTUS_ACCOUNT_TYPE: u8 = 3;
+/// A validator address (type byte 1), identifying a block-producing node.
+const PACTUS_VALIDATOR_TYPE: u8 = 1;
+
+/// Pactus chain
+pub struct Pactus;
+
+impl Chain for Pactus {
+    fn ticker(&self) -> &'static str {
+        "PAC"
+    }
+
+    fn name(&self) -> &'static str {
+        "Pactus"
+    }
+
+    fn family(&self) -> ChainFamily {
+        ChainFamily::Ed25519
+    }
+
+    fn address_types(&self) -> Vec<AddressType> {
+        vec![AddressType::Pactus, AddressType::PactusValidator]
+    }
+
+    fn default_address_type(&self) -> AddressType {
+        AddressType::Pactus
+    }
+
+    fn generate(&self, address_type: AddressType) -> GeneratedAddress {
+        let keypair = Ed25519Keypair::generate();
+        self.generate_from_keypair(&keypair, address_type)
+    }
+
+    fn generate_from_bytes(&self, private_key: &[u8], address_type: AddressType) -> Option<GeneratedAddress> {
+        if private_key.len() != 32 {
+            return None;
+        }
+        let mut pk = [0u8; 32];
+        pk.copy_from_slice(private_key);
+        let keypair = Ed25519Keypair::from_bytes(&pk).ok()?;
+        Some(self.generate_from_keypair(&keypair, address_type))
+    }
+
+    fn parse_address(&self, s: &str, _address_type: AddressType) -> Option<Vec<u8>> {
+        // `generate_from_keypair` builds the address with the self-contained
+        // `bech32::encode` (type byte as the first 5-bit symbol, SegWit-style
+        // bit packing), so it has to be decoded with `bech32::decode` too -
+        // the crate-backed `bech32_decode` assumes a different byte-payload
+        // model and would reject these addresses.
+        let (hrp, type_byte, payload) = bech32::decode(s).ok()?;
+        if hrp != "pc" {
+            return None;
+        }
+        if type_byte != PACTUS_ACCOUNT_TYPE && type_byte != PACTUS_VALIDATOR_TYPE {
+            return None;
+        }
+        if payload.len() != 20 {
+            return None;
+        }
+        Some(payload)
+    }
+
+    fn valid_address_chars(&self, _address_type: AddressType) -> &'static str {
+        "qpzry9x8gf2tvdw0s3jn54khce6mua7l"
+    }
+
+    fn address_prefix(&self, _address_type: AddressType) -> &'static str {
+        "pc1"
+    }
+}
+
+impl Pactus {
+    fn generate_from_keypair(&self, keypair: &Ed25519Keypair, address_type: AddressType) -> GeneratedAddress {
+        let private_key = keypair.private_key_bytes();
+        let public_key = keypair.public_key_bytes();
+
+        // BLAKE2b-256 then RIPEMD-160, unlike Bitcoin's SHA256-then-RIPEMD160 `hash160`.
+        let pubkey_hash = ripemd160(&blake2b_256(&public_key));
+
+        let type_byte = match address_type {
+            AddressType::PactusValidator => PACTUS_VALIDATOR_TYPE,
+            _ => PACTUS_ACCOUNT_TYPE,
+        };
+        let address = bech32::encode("pc", type_byte, &pubkey_hash).unwrap_or_default();
+
+        GeneratedAddress {
+            address,
+            private_key_hex: hex::encode(private_key),
+            private_key_native: hex::encode(private_key),
+            public_key_hex: hex::encode(public_key),
+            chain: "PAC".to_string(),
+            address_type,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pactus_account_generation() {
+        let addr = Pactus.generate(AddressType::Pactus);
+        assert!(addr.address.starts_with("pc1"));
+        assert_eq!(addr.chain, "PAC");
+    }
+
+    #[test]
+    fn test_pactus_validator_generation() {
+        let addr = Pactus.generate(AddressType::PactusValidator);
+        assert!(addr.address.starts_with("pc1"));
+    }
+
+    #[test]
+    fn test_account_and_validator_addresses_differ_for_same_key() {
+        let privkey = [6u8; 32];
+        let account = Pactus.generate_from_bytes(&privkey, AddressType::Pactus).unwrap();
+        let validator = Pactus.generate_from_bytes(&privkey, AddressType::PactusValidator).unwrap();
+        assert_ne!(account.address, validator.address);
+    }
+
+    #[test]
+    fn test_parse_address_round_trips() {
+        let addr = Pactus.generate(AddressType::Pactus);
+        let payload = Pactus.parse_address(&addr.address, AddressType::Pactus).unwrap();
+        assert_eq!(payload.len(), 20);
+    }
+
+    #[test]
+    fn test_parse_address_rejects_unknown_type_byte() {
+        // Type byte 0 isn't a valid Pactus address type (account = 3, validator = 1).
+        let bogus = bech32::encode("pc", 0, &[0u8; 20]).unwrap();
+        assert!(Pactus.parse_address(&bogus, AddressType::Pactus).is_none());
+    }
+
+    #[test]
+    fn test_parse_address_rejects_wrong_hrp() {
+        let addr = Pactus.generate(AddressType::Pactus);
+        let wrong_hrp = addr.address.replacen("pc1", "xx1", 1);
+        assert!(Pactus.parse_address(&wrong_hrp, AddressType::Pactus).is_none());
+    }
+}
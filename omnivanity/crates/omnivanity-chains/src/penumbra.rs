@@ -0,0 +1,185 @@
+//! Penumbra chain adapter (shielded bech32m addresses)
+
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
+use omnivanity_crypto::{
+    hash::blake2b_var,
+    encoding::bech32m_encode_raw,
+    hex,
+};
+use rand::RngCore;
+
+/// Penumbra chain
+pub struct Penumbra;
+
+impl Chain for Penumbra {
+    fn ticker(&self) -> &'static str {
+        "UM"
+    }
+
+    fn name(&self) -> &'static str {
+        "Penumbra"
+    }
+
+    fn family(&self) -> ChainFamily {
+        ChainFamily::Ed25519
+    }
+
+    fn address_types(&self) -> Vec<AddressType> {
+        vec![AddressType::Penumbra]
+    }
+
+    fn default_address_type(&self) -> AddressType {
+        AddressType::Penumbra
+    }
+
+    fn generate(&self, _address_type: AddressType) -> GeneratedAddress {
+        let mut seed = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut seed);
+        self.generate_diversified(&seed, 0)
+    }
+
+    fn generate_from_bytes(&self, private_key: &[u8], _address_type: AddressType) -> Option<GeneratedAddress> {
+        if private_key.len() != 32 {
+            return None;
+        }
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(private_key);
+        Some(self.generate_diversified(&seed, 0))
+    }
+
+    fn valid_address_chars(&self, _address_type: AddressType) -> &'static str {
+        "023456789acdefghjklmnpqrstuvwxyzqpzry9x8gf2tvdw0s3jn54khce6mua7l"
+    }
+
+    fn address_prefix(&self, _address_type: AddressType) -> &'static str {
+        "penumbra1"
+    }
+}
+
+impl Penumbra {
+    /// Derive the Penumbra shielded address for diversifier index `index`
+    /// from a 32-byte spending seed.
+    ///
+    /// This crate has no decaf377 curve or FF1 format-preserving-encryption
+    /// implementation, so the diversifier key `dk`, the per-index diversifier
+    /// `d`, and the transmission/clue keys below are all BLAKE2b-
+    /// domain-separated hash chains rather than the real decaf377 scalar
+    /// multiplications and FF1 diversifier encryption - the same kind of
+    /// structural stand-in `zcash.rs` uses for Sapling. The resulting
+    /// `[d || transmission_key || clue_key]` payload is still passed through
+    /// a real (if simplified) [`f4jumble`] before bech32m encoding, since
+    /// F4Jumble's unbalanced-Feistel control flow doesn't depend on the
+    /// underlying curve. `pub(crate)` so [`crate::penumbra_search`] can sweep
+    /// indices against a fixed spending key.
+    pub(crate) fn generate_diversified(&self, seed: &[u8; 32], index: u64) -> GeneratedAddress {
+        let dk = blake2b_var(&[b"Penumbra_dk".as_slice(), seed].concat(), 16);
+        let ik = blake2b_var(&[b"Penumbra_ik".as_slice(), seed].concat(), 32);
+
+        let mut d_input = Vec::with_capacity(14 + 16 + 8);
+        d_input.extend_from_slice(b"Penumbra_d");
+        d_input.extend_from_slice(&dk);
+        d_input.extend_from_slice(&index.to_le_bytes());
+        let d = blake2b_var(&d_input, 16);
+
+        let mut pkd_input = Vec::with_capacity(13 + 16 + 32);
+        pkd_input.extend_from_slice(b"Penumbra_pkd");
+        pkd_input.extend_from_slice(&d);
+        pkd_input.extend_from_slice(&ik);
+        let transmission_key = blake2b_var(&pkd_input, 32);
+
+        let mut clue_input = Vec::with_capacity(14 + 16 + 32);
+        clue_input.extend_from_slice(b"Penumbra_clue");
+        clue_input.extend_from_slice(&d);
+        clue_input.extend_from_slice(&ik);
+        let clue_key = blake2b_var(&clue_input, 32);
+
+        let mut payload = [0u8; 80];
+        payload[..16].copy_from_slice(&d);
+        payload[16..48].copy_from_slice(&transmission_key);
+        payload[48..80].copy_from_slice(&clue_key);
+
+        let jumbled = f4jumble(&payload);
+        let address = bech32m_encode_raw("penumbra", &jumbled)
+            .expect("80-byte Penumbra payload always encodes");
+
+        GeneratedAddress {
+            address,
+            private_key_hex: hex::encode(seed),
+            private_key_native: format!("dk: {} | ik: {}", hex::encode(&dk), hex::encode(&ik)),
+            public_key_hex: format!("d: {} | pk_d: {} | clue: {}", hex::encode(&d), hex::encode(&transmission_key), hex::encode(&clue_key)),
+            chain: self.ticker().to_string(),
+            address_type: AddressType::Penumbra,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
+        }
+    }
+}
+
+/// Simplified F4Jumble over an 80-byte `[d || transmission_key || clue_key]`
+/// payload: a real 4-round unbalanced Feistel network (even 40/40 split of
+/// this crate's fixed 80-byte payload, rather than the hash-length-dependent
+/// split real F4Jumble uses for arbitrary-length messages), whose round
+/// function is BLAKE2b tagged with the round index in place of BLAKE2b's
+/// native personalization parameter (not exposed by this crate's
+/// `blake2b_var` helper). Scrambles the whole payload byte-for-byte so a
+/// single-character vanity match on the encoded address can't be traced back
+/// to the diversifier or key bytes it came from, matching F4Jumble's role in
+/// the real protocol.
+fn f4jumble(payload: &[u8; 80]) -> [u8; 80] {
+    let mut left = payload[..40].to_vec();
+    let mut right = payload[40..].to_vec();
+
+    for round in 0u8..4 {
+        let mask = blake2b_var(&[b"Penumbra_F4Jumble".as_slice(), &[round], &right].concat(), 40);
+        let new_right: Vec<u8> = left.iter().zip(mask.iter()).map(|(a, b)| a ^ b).collect();
+        left = right;
+        right = new_right;
+    }
+
+    let mut out = [0u8; 80];
+    out[..40].copy_from_slice(&left);
+    out[40..].copy_from_slice(&right);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_penumbra_generation() {
+        let penumbra = Penumbra;
+        let addr = penumbra.generate(AddressType::Penumbra);
+        assert!(addr.address.starts_with("penumbra1"));
+        assert_eq!(addr.chain, "UM");
+    }
+
+    #[test]
+    fn test_penumbra_deterministic() {
+        let penumbra = Penumbra;
+        let seed = [5u8; 32];
+        let a = penumbra.generate_from_bytes(&seed, AddressType::Penumbra).unwrap();
+        let b = penumbra.generate_from_bytes(&seed, AddressType::Penumbra).unwrap();
+        assert_eq!(a.address, b.address);
+    }
+
+    #[test]
+    fn test_penumbra_diversified_addresses_differ() {
+        let penumbra = Penumbra;
+        let seed = [11u8; 32];
+        let addr0 = penumbra.generate_diversified(&seed, 0);
+        let addr1 = penumbra.generate_diversified(&seed, 1);
+        assert_ne!(addr0.address, addr1.address);
+        // Same spending key regardless of diversifier index
+        assert_eq!(addr0.private_key_hex, addr1.private_key_hex);
+    }
+
+    #[test]
+    fn test_f4jumble_changes_an_all_zero_payload() {
+        let payload = [0u8; 80];
+        let jumbled = f4jumble(&payload);
+        // An all-zero payload should not jumble back to all zeroes.
+        assert_ne!(jumbled, payload);
+    }
+}
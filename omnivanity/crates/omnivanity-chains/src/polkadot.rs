@@ -2,7 +2,7 @@
 //!
 //! SS58: network prefix + pubkey + checksum, Base58 encoded
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
 use omnivanity_crypto::{Ed25519Keypair, hex};
 use blake2::{Blake2b512, Digest};
 
@@ -122,6 +122,9 @@ impl Ss58Chain {
             public_key_hex: hex::encode(public_key),
             chain: self.ticker.to_string(),
             address_type: AddressType::Ss58,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         }
     }
 }
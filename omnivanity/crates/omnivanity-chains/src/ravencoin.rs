@@ -1,6 +1,6 @@
 //! Ravencoin chain adapter
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
 use omnivanity_crypto::{
     Secp256k1Keypair,
     hash::hash160,
@@ -8,6 +8,9 @@ use omnivanity_crypto::{
     hex,
 };
 
+/// Ravencoin P2PKH Base58Check version byte ("R..." addresses)
+const RVN_P2PKH_VERSION: u8 = 0x3C;
+
 /// Ravencoin chain
 pub struct Ravencoin;
 
@@ -47,6 +50,13 @@ impl Chain for Ravencoin {
         Some(self.generate_from_keypair(&keypair, address_type))
     }
 
+    fn address_version_byte(&self, address_type: AddressType) -> Option<u8> {
+        match address_type {
+            AddressType::P2pkh => Some(RVN_P2PKH_VERSION),
+            _ => None,
+        }
+    }
+
     fn valid_address_chars(&self, _address_type: AddressType) -> &'static str {
         "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"
     }
@@ -61,9 +71,8 @@ impl Ravencoin {
         let private_key = keypair.private_key_bytes();
         let pubkey_compressed = keypair.public_key_compressed();
         
-        // Ravencoin P2PKH: version byte 0x3C (60)
         let h160 = hash160(&pubkey_compressed);
-        let address = base58check_encode(0x3C, &h160);
+        let address = base58check_encode(RVN_P2PKH_VERSION, &h160);
         
         let wif = wif_encode(&private_key, true, true);
         
@@ -74,6 +83,9 @@ impl Ravencoin {
             public_key_hex: hex::encode(pubkey_compressed),
             chain: "RVN".to_string(),
             address_type: AddressType::P2pkh,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         }
     }
 }
@@ -0,0 +1,111 @@
+//! Diversifier-index vanity search for Zcash Sapling shielded addresses
+//!
+//! `VanitySearch` burns a fresh keypair per attempt and throws it away the
+//! instant it doesn't match, which is wasteful for Sapling: one spending key
+//! yields roughly 2^88 distinct diversified addresses, so a vanity hit can
+//! be found by sweeping the diversifier index alone. `SaplingDiversifierSearch`
+//! fixes one spending seed and scans `index = 0, 1, 2, ...`, so a match is
+//! reproducible from the seed plus the winning index instead of a one-off key.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+use rayon::prelude::*;
+
+use crate::traits::{AddressType, Chain, GeneratedAddress};
+use crate::vanity::{PatternSpec, VanityError, VanityProgress};
+use crate::zcash::Zcash;
+
+/// Result of a completed Sapling diversifier search: the matched address
+/// plus the diversifier index that produced it, reproducible from the
+/// spending seed alone.
+pub struct SaplingMatch {
+    pub address: GeneratedAddress,
+    pub index: u64,
+    pub attempts: u64,
+    pub elapsed_secs: f64,
+}
+
+/// Scans diversifier indices against one fixed 32-byte Sapling spending seed.
+pub struct SaplingDiversifierSearch {
+    seed: [u8; 32],
+    pattern: PatternSpec,
+}
+
+impl SaplingDiversifierSearch {
+    /// Build a search, validating the pattern against Sapling's bech32
+    /// alphabet up front (same check `VanitySearch::new` does).
+    pub fn new(seed: [u8; 32], pattern: PatternSpec) -> Result<Self, VanityError> {
+        let _ = crate::vanity::VanitySearch::new(&Zcash, AddressType::Sapling, pattern.clone())?;
+        Ok(Self { seed, pattern })
+    }
+
+    /// Run the search with a progress callback, blocking until a diversifier
+    /// index produces a matching address.
+    pub fn run(&self, mut on_progress: impl FnMut(VanityProgress) + Send) -> SaplingMatch {
+        let zcash = Zcash;
+        let chain_prefix = zcash.address_prefix(AddressType::Sapling);
+        let next_index = Arc::new(AtomicU64::new(0));
+        let found = Arc::new(AtomicBool::new(false));
+        let result: std::sync::Mutex<Option<(GeneratedAddress, u64)>> = std::sync::Mutex::new(None);
+        let start = Instant::now();
+
+        rayon::scope(|s| {
+            let num_threads = rayon::current_num_threads().max(1);
+            for _ in 0..num_threads {
+                let next_index = next_index.clone();
+                let found = found.clone();
+                let result = &result;
+                s.spawn(move |_| {
+                    while !found.load(Ordering::Relaxed) {
+                        let index = next_index.fetch_add(1, Ordering::Relaxed);
+                        let addr = zcash.generate_sapling_diversified(&self.seed, index);
+                        if self.pattern.matches(&addr.address, chain_prefix) {
+                            *result.lock().unwrap() = Some((addr, index));
+                            found.store(true, Ordering::Relaxed);
+                            return;
+                        }
+                    }
+                });
+            }
+
+            while !found.load(Ordering::Relaxed) {
+                let done = next_index.load(Ordering::Relaxed);
+                let elapsed = start.elapsed().as_secs_f64().max(1e-9);
+                let rate = done as f64 / elapsed;
+                on_progress(VanityProgress { attempts: done, attempts_per_sec: rate, eta_secs: None });
+                std::thread::sleep(std::time::Duration::from_millis(200));
+            }
+        });
+
+        let attempts = next_index.load(Ordering::Relaxed);
+        let (address, index) = result.into_inner().unwrap().expect("found flag set implies a result");
+        SaplingMatch { address, index, attempts, elapsed_secs: start.elapsed().as_secs_f64() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_reproducible_match_under_fixed_seed() {
+        let seed = [9u8; 32];
+        // Single-char prefix right after the "zs1" hrp/separator, expected
+        // to match within a handful of diversifier indices on average.
+        let search = SaplingDiversifierSearch::new(seed, PatternSpec::prefix("q")).unwrap();
+        let found = search.run(|_| {});
+
+        let zcash = Zcash;
+        let replay = zcash.generate_sapling_diversified(&seed, found.index);
+        assert_eq!(replay.address, found.address.address);
+    }
+
+    #[test]
+    fn rejects_character_outside_sapling_alphabet() {
+        // 'b' is not in the bech32 charset the Sapling alphabet is built from
+        let err = SaplingDiversifierSearch::new([1u8; 32], PatternSpec::prefix("b")).unwrap_err();
+        assert!(matches!(err, VanityError::InvalidCharacter('b', _)));
+    }
+}
@@ -3,7 +3,7 @@
 //! Covers: SOL and Solana-based tokens (TRUMP, BONK, PENGU, JUP, PUMP)
 //! All use: Ed25519 pubkey as base58 address
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
 use omnivanity_crypto::{Ed25519Keypair, encoding::base58_encode, hex};
 
 /// Solana-style chain with configurable ticker/name
@@ -38,6 +38,9 @@ impl SolanaChain {
             public_key_hex: hex::encode(pubkey),
             chain: self.ticker.to_string(),
             address_type: AddressType::Solana,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         }
     }
 }
@@ -0,0 +1,173 @@
+//! Split-key ("delegated") vanity grinding
+//!
+//! An owner hands a worker only their compressed public point
+//! `P = p·G` - never the private scalar `p`. The worker searches offsets
+//! `d = 1, 2, …`, checking `P + d·G` against the pattern with
+//! `Chain::address_from_public_key`, and a hit reports back just the
+//! offset and stats. The owner alone recovers the real private key as
+//! `(p + d) mod n` via `omnivanity_crypto::combine_split_key`. This is the
+//! classic vanitygen split-key workflow, letting an owner safely outsource
+//! grinding to a faster (and untrusted) machine.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use thiserror::Error;
+
+use omnivanity_crypto::{PublicPointWalker, Secp256k1Error};
+
+use crate::traits::{AddressType, Chain, ChainFamily};
+use crate::vanity::{PatternSpec, VanityError};
+
+#[derive(Error, Debug)]
+pub enum SplitVanityError {
+    #[error(transparent)]
+    Pattern(#[from] VanityError),
+    #[error("chain/address type can't derive an address from a public key alone")]
+    UnsupportedAddressType,
+    #[error(transparent)]
+    InvalidPublicKey(#[from] Secp256k1Error),
+    #[error("split-key search is only implemented for secp256k1 chains (EVM, UTXO)")]
+    UnsupportedFamily,
+}
+
+/// How many sequential offsets a worker thread claims - and walks with one
+/// batched-inversion pass - before checking in for more work or stopping.
+const CHUNK_SIZE: u64 = 1 << 16;
+
+/// Result of a completed split-key search: the matched address plus the
+/// offset that produced it from the owner's public point - never a private
+/// key, since the worker never had one to begin with.
+pub struct SplitMatch {
+    pub address: String,
+    pub offset: [u8; 32],
+    pub attempts: u64,
+    pub elapsed_secs: f64,
+}
+
+/// Scans `P + 1·G, P + 2·G, …` against one fixed owner public point.
+pub struct SplitVanitySearch<'a> {
+    chain: &'a dyn Chain,
+    address_type: AddressType,
+    pattern: PatternSpec,
+    base_point: [u8; 33],
+}
+
+impl<'a> SplitVanitySearch<'a> {
+    /// Build a search against `owner_pubkey` (compressed or uncompressed
+    /// SEC1 bytes - normalized to compressed here, the form
+    /// `PublicPointWalker` and `Chain::address_from_public_key` both expect).
+    pub fn new(
+        chain: &'a dyn Chain,
+        address_type: AddressType,
+        pattern: PatternSpec,
+        owner_pubkey: &[u8],
+    ) -> Result<Self, SplitVanityError> {
+        if pattern.prefix.is_none() && pattern.suffix.is_none() && pattern.regex.is_none() {
+            return Err(VanityError::EmptyPattern.into());
+        }
+        if !matches!(chain.family(), ChainFamily::Evm | ChainFamily::UtxoSecp256k1) {
+            return Err(SplitVanityError::UnsupportedFamily);
+        }
+
+        let base_point = PublicPointWalker::from_sec1_bytes(owner_pubkey)?.to_sec1_bytes();
+        // Fail fast instead of burning CPU time walking offsets a chain
+        // adapter can't even turn into an address without the private key.
+        chain
+            .address_from_public_key(&base_point, address_type)
+            .ok_or(SplitVanityError::UnsupportedAddressType)?;
+
+        Ok(Self { chain, address_type, pattern, base_point })
+    }
+
+    /// Run the scan with a progress callback, blocking until a match is found.
+    pub fn run(&self, mut on_progress: impl FnMut(u64, f64) + Send) -> SplitMatch {
+        let attempts = Arc::new(AtomicU64::new(0));
+        let found = Arc::new(AtomicBool::new(false));
+        let next_chunk = Arc::new(AtomicU64::new(0));
+        let result: Mutex<Option<(String, u64)>> = Mutex::new(None);
+        let start = Instant::now();
+        let chain_prefix = self.chain.address_prefix(self.address_type);
+        let base_point = self.base_point;
+
+        rayon::scope(|s| {
+            let num_threads = rayon::current_num_threads().max(1);
+            for _ in 0..num_threads {
+                let attempts = attempts.clone();
+                let found = found.clone();
+                let next_chunk = next_chunk.clone();
+                let result = &result;
+                s.spawn(move |_| {
+                    let walker = PublicPointWalker::from_sec1_bytes(&base_point)
+                        .expect("base_point was already validated in new");
+
+                    while !found.load(Ordering::Relaxed) {
+                        let chunk_index = next_chunk.fetch_add(1, Ordering::Relaxed);
+                        let chunk_start = chunk_index * CHUNK_SIZE + 1; // offsets are 1-based
+                        let chunk_base = walker.offset_by(chunk_start - 1);
+
+                        for (step, point) in chunk_base.increment_batch(CHUNK_SIZE as usize).into_iter().enumerate() {
+                            if found.load(Ordering::Relaxed) {
+                                return;
+                            }
+                            attempts.fetch_add(1, Ordering::Relaxed);
+
+                            let Some(address) = self.chain.address_from_public_key(&point, self.address_type) else {
+                                continue;
+                            };
+                            if self.pattern.matches(&address, chain_prefix) {
+                                let offset = chunk_start + step as u64;
+                                *result.lock().unwrap() = Some((address, offset));
+                                found.store(true, Ordering::Relaxed);
+                                return;
+                            }
+                        }
+                    }
+                });
+            }
+
+            while !found.load(Ordering::Relaxed) {
+                let done = attempts.load(Ordering::Relaxed);
+                let elapsed = start.elapsed().as_secs_f64().max(1e-9);
+                on_progress(done, done as f64 / elapsed);
+                std::thread::sleep(std::time::Duration::from_millis(200));
+            }
+        });
+
+        let (address, offset) = result.into_inner().unwrap().expect("found flag set implies a result");
+        let mut offset_bytes = [0u8; 32];
+        offset_bytes[24..].copy_from_slice(&offset.to_be_bytes());
+
+        SplitMatch {
+            address,
+            offset: offset_bytes,
+            attempts: attempts.load(Ordering::Relaxed),
+            elapsed_secs: start.elapsed().as_secs_f64(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use omnivanity_crypto::{combine_split_key, Secp256k1Keypair};
+
+    #[test]
+    fn split_search_finds_the_same_offset_the_owner_would_land_on() {
+        let owner = Secp256k1Keypair::from_bytes(&[3u8; 32]).unwrap();
+        // An easy pattern guaranteed to hit within a handful of offsets:
+        // match whatever the third walked address's first hex digit is.
+        let third_walked = owner.increment_batch(3)[2].clone();
+        let target = crate::ETH.generate_from_bytes(&third_walked.private_key_bytes(), AddressType::Evm).unwrap();
+        let pattern = PatternSpec::prefix(target.address[2..3].to_string());
+
+        let search = SplitVanitySearch::new(&crate::ETH, AddressType::Evm, pattern, &owner.public_key_compressed())
+            .unwrap();
+        let found = search.run(|_, _| {});
+
+        let recovered = combine_split_key(&owner.private_key_bytes(), &found.offset).unwrap();
+        let recovered_address = crate::ETH.generate_from_bytes(&recovered, AddressType::Evm).unwrap();
+        assert_eq!(recovered_address.address, found.address);
+    }
+}
@@ -2,8 +2,8 @@
 //!
 //! Stacks uses c32check encoding (Crockford base32 variant with checksum)
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
-use omnivanity_crypto::{Secp256k1Keypair, hash::{sha256, hash160}, hex};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
+use omnivanity_crypto::{Secp256k1Keypair, hash::{sha256, hash160}, encoding::wif_encode, hex};
 
 /// Stacks chain
 pub struct Stacks;
@@ -107,14 +107,19 @@ impl Stacks {
         // Stacks address: c32check(version, hash160(pubkey))
         let h160 = hash160(&pubkey_compressed);
         let address = c32check_encode(22, &h160); // 22 = mainnet single-sig
-        
+
+        let wif = wif_encode(&private_key, true, true);
+
         GeneratedAddress {
             address,
             private_key_hex: hex::encode(private_key),
-            private_key_native: hex::encode(private_key),
+            private_key_native: wif,
             public_key_hex: hex::encode(pubkey_compressed),
             chain: "STX".to_string(),
             address_type: AddressType::Stacks,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         }
     }
 }
@@ -129,5 +134,6 @@ mod tests {
         let addr = stx.generate(AddressType::Stacks);
         assert!(addr.address.starts_with("SP"));
         assert_eq!(addr.chain, "STX");
+        assert!(addr.private_key_native.starts_with('K') || addr.private_key_native.starts_with('L'));
     }
 }
@@ -2,8 +2,8 @@
 //!
 //! Stellar StrKey: Ed25519 pubkey + version byte + CRC16 checksum, Base32 encoded
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
-use omnivanity_crypto::{Ed25519Keypair, hex};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
+use omnivanity_crypto::{Ed25519Keypair, hex, encoding::stellar_strkey_decode};
 
 /// Stellar chain
 pub struct Stellar;
@@ -103,6 +103,11 @@ impl Chain for Stellar {
     fn address_prefix(&self, _address_type: AddressType) -> &'static str {
         "G"
     }
+
+    fn validate_address(&self, address: &str, _address_type: AddressType) -> bool {
+        // Account ID StrKey version byte is 6 << 3 (see generate_from_keypair)
+        matches!(stellar_strkey_decode(address), Ok((version, payload)) if version == 6 << 3 && payload.len() == 32)
+    }
 }
 
 impl Stellar {
@@ -123,6 +128,9 @@ impl Stellar {
             public_key_hex: hex::encode(public_key),
             chain: "XLM".to_string(),
             address_type: AddressType::Stellar,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         }
     }
 }
@@ -2,7 +2,7 @@
 //!
 //! Sui address: Blake2b-256(flag || pubkey) = 32 bytes, hex encoded
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
 use omnivanity_crypto::{Ed25519Keypair, hash::blake2b_256, hex};
 
 /// Sui chain
@@ -73,6 +73,9 @@ impl Sui {
             public_key_hex: hex::encode(public_key),
             chain: "SUI".to_string(),
             address_type: AddressType::Sui,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         }
     }
 }
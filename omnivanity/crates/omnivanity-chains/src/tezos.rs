@@ -2,8 +2,8 @@
 //!
 //! Tezos uses Base58Check with prefixes: tz1 (Ed25519), tz2 (secp256k1), tz3 (P256)
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
-use omnivanity_crypto::{Ed25519Keypair, hash::blake2b_160, hex};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
+use omnivanity_crypto::{Ed25519Keypair, P256Keypair, Secp256k1Keypair, hash::blake2b_160, hex};
 
 /// Tezos chain
 pub struct Tezos;
@@ -11,14 +11,14 @@ pub struct Tezos;
 // Tezos-specific Base58Check
 fn tezos_base58check_encode(prefix: &[u8], payload: &[u8]) -> String {
     use omnivanity_crypto::hash::double_sha256;
-    
+
     let mut data = Vec::with_capacity(prefix.len() + payload.len() + 4);
     data.extend_from_slice(prefix);
     data.extend_from_slice(payload);
-    
+
     let checksum = double_sha256(&data);
     data.extend_from_slice(&checksum[..4]);
-    
+
     bs58::encode(data).into_string()
 }
 
@@ -36,7 +36,7 @@ impl Chain for Tezos {
     }
 
     fn address_types(&self) -> Vec<AddressType> {
-        vec![AddressType::Tezos]
+        vec![AddressType::Tezos, AddressType::TezosSecp256k1, AddressType::TezosP256]
     }
 
     fn default_address_type(&self) -> AddressType {
@@ -44,8 +44,20 @@ impl Chain for Tezos {
     }
 
     fn generate(&self, address_type: AddressType) -> GeneratedAddress {
-        let keypair = Ed25519Keypair::generate();
-        self.generate_from_keypair(&keypair, address_type)
+        match address_type {
+            AddressType::TezosSecp256k1 => {
+                let keypair = Secp256k1Keypair::generate();
+                self.generate_tz2(&keypair)
+            }
+            AddressType::TezosP256 => {
+                let keypair = P256Keypair::generate();
+                self.generate_tz3(&keypair)
+            }
+            _ => {
+                let keypair = Ed25519Keypair::generate();
+                self.generate_tz1(&keypair)
+            }
+        }
     }
 
     fn generate_from_bytes(&self, private_key: &[u8], address_type: AddressType) -> Option<GeneratedAddress> {
@@ -54,32 +66,82 @@ impl Chain for Tezos {
         }
         let mut pk = [0u8; 32];
         pk.copy_from_slice(private_key);
-        let keypair = Ed25519Keypair::from_bytes(&pk).ok()?;
-        Some(self.generate_from_keypair(&keypair, address_type))
+
+        match address_type {
+            AddressType::TezosSecp256k1 => {
+                let keypair = Secp256k1Keypair::from_bytes(&pk).ok()?;
+                Some(self.generate_tz2(&keypair))
+            }
+            AddressType::TezosP256 => {
+                let keypair = P256Keypair::from_bytes(&pk).ok()?;
+                Some(self.generate_tz3(&keypair))
+            }
+            _ => {
+                let keypair = Ed25519Keypair::from_bytes(&pk).ok()?;
+                Some(self.generate_tz1(&keypair))
+            }
+        }
     }
 
     fn valid_address_chars(&self, _address_type: AddressType) -> &'static str {
         "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"
     }
 
-    fn address_prefix(&self, _address_type: AddressType) -> &'static str {
-        "tz1"
+    fn address_prefix(&self, address_type: AddressType) -> &'static str {
+        match address_type {
+            AddressType::TezosSecp256k1 => "tz2",
+            AddressType::TezosP256 => "tz3",
+            _ => "tz1",
+        }
+    }
+
+    /// Tezos secret keys use multi-byte Base58Check prefixes (`edsk`, `spsk`,
+    /// `p2sk`) rather than a single WIF version byte, so the default
+    /// `Chain::import_native_key` can't strip them - decode raw Base58Check
+    /// and peel off whichever prefix matches instead.
+    fn import_native_key(&self, native_key: &str) -> Option<GeneratedAddress> {
+        let data = bs58::decode(native_key).into_vec().ok()?;
+        if data.len() < 4 {
+            return None;
+        }
+        let (payload_with_prefix, checksum) = data.split_at(data.len() - 4);
+        let computed = &omnivanity_crypto::hash::double_sha256(payload_with_prefix)[..4];
+        if checksum != computed {
+            return None;
+        }
+
+        const EDSK: &[u8] = &[43, 246, 78, 7];
+        const SPSK: &[u8] = &[17, 162, 224, 201];
+        const P2SK: &[u8] = &[16, 81, 238, 189];
+
+        for (prefix, address_type) in [
+            (EDSK, AddressType::Tezos),
+            (SPSK, AddressType::TezosSecp256k1),
+            (P2SK, AddressType::TezosP256),
+        ] {
+            if let Some(secret) = payload_with_prefix.strip_prefix(prefix) {
+                if secret.len() == 32 {
+                    return self.generate_from_bytes(secret, address_type);
+                }
+            }
+        }
+        None
     }
 }
 
 impl Tezos {
-    fn generate_from_keypair(&self, keypair: &Ed25519Keypair, _address_type: AddressType) -> GeneratedAddress {
+    fn generate_tz1(&self, keypair: &Ed25519Keypair) -> GeneratedAddress {
         let private_key = keypair.private_key_bytes();
         let public_key = keypair.public_key_bytes();
-        
+
         // Tezos tz1 address: prefix [6, 161, 159] + Blake2b-160(pubkey)
         let hash = blake2b_160(&public_key);
         let address = tezos_base58check_encode(&[6, 161, 159], &hash);
-        
+
         // Tezos secret key: prefix [43, 246, 78, 7] for edsk (encrypted secret key)
         // but we'll just provide hex for simplicity
         let secret = tezos_base58check_encode(&[43, 246, 78, 7], &private_key);
-        
+
         GeneratedAddress {
             address,
             private_key_hex: hex::encode(private_key),
@@ -87,6 +149,55 @@ impl Tezos {
             public_key_hex: hex::encode(public_key),
             chain: "XTZ".to_string(),
             address_type: AddressType::Tezos,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
+        }
+    }
+
+    /// tz2 address: prefix `[6, 161, 161]` + Blake2b-160(compressed secp256k1
+    /// pubkey), secret key encoded with the `spsk` prefix `[17, 162, 224, 201]`.
+    fn generate_tz2(&self, keypair: &Secp256k1Keypair) -> GeneratedAddress {
+        let private_key = keypair.private_key_bytes();
+        let public_key = keypair.public_key_compressed();
+
+        let hash = blake2b_160(&public_key);
+        let address = tezos_base58check_encode(&[6, 161, 161], &hash);
+        let secret = tezos_base58check_encode(&[17, 162, 224, 201], &private_key);
+
+        GeneratedAddress {
+            address,
+            private_key_hex: hex::encode(private_key),
+            private_key_native: secret,
+            public_key_hex: hex::encode(public_key),
+            chain: "XTZ".to_string(),
+            address_type: AddressType::TezosSecp256k1,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
+        }
+    }
+
+    /// tz3 address: prefix `[6, 161, 164]` + Blake2b-160(compressed P-256
+    /// pubkey), secret key encoded with the `p2sk` prefix `[16, 81, 238, 189]`.
+    fn generate_tz3(&self, keypair: &P256Keypair) -> GeneratedAddress {
+        let private_key = keypair.private_key_bytes();
+        let public_key = keypair.public_key_compressed();
+
+        let hash = blake2b_160(&public_key);
+        let address = tezos_base58check_encode(&[6, 161, 164], &hash);
+        let secret = tezos_base58check_encode(&[16, 81, 238, 189], &private_key);
+
+        GeneratedAddress {
+            address,
+            private_key_hex: hex::encode(private_key),
+            private_key_native: secret,
+            public_key_hex: hex::encode(public_key),
+            chain: "XTZ".to_string(),
+            address_type: AddressType::TezosP256,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         }
     }
 }
@@ -102,4 +213,36 @@ mod tests {
         assert!(addr.address.starts_with("tz1"));
         assert_eq!(addr.chain, "XTZ");
     }
+
+    #[test]
+    fn test_tezos_tz2_generation() {
+        let xtz = Tezos;
+        let addr = xtz.generate(AddressType::TezosSecp256k1);
+        assert!(addr.address.starts_with("tz2"));
+        assert!(addr.private_key_native.starts_with("sp"));
+    }
+
+    #[test]
+    fn test_tezos_tz3_generation() {
+        let xtz = Tezos;
+        let addr = xtz.generate(AddressType::TezosP256);
+        assert!(addr.address.starts_with("tz3"));
+        assert!(addr.private_key_native.starts_with("p2sk"));
+    }
+
+    #[test]
+    fn test_import_native_key_roundtrips_tz1() {
+        let xtz = Tezos;
+        let original = xtz.generate(AddressType::Tezos);
+        let imported = xtz.import_native_key(&original.private_key_native).unwrap();
+        assert_eq!(imported.address, original.address);
+    }
+
+    #[test]
+    fn test_import_native_key_roundtrips_tz2() {
+        let xtz = Tezos;
+        let original = xtz.generate(AddressType::TezosSecp256k1);
+        let imported = xtz.import_native_key(&original.private_key_native).unwrap();
+        assert_eq!(imported.address, original.address);
+    }
 }
@@ -1,13 +1,83 @@
 //! TON (The Open Network) chain adapter
 //!
-//! TON address: base64url encoded with CRC16 checksum
+//! TON address: base64url encoded with CRC16 checksum over
+//! `[flags][workchain][account_id][crc16]`, where `account_id` is the
+//! representation hash of a standard wallet's StateInit cell (not the raw
+//! public key - that would never match the address a real wallet deploys to).
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
-use omnivanity_crypto::{Ed25519Keypair, hex};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
+use omnivanity_crypto::{Ed25519Keypair, hash::sha256, hex};
 
 /// TON chain
 pub struct Ton;
 
+/// Depth of the compiled wallet v3R2 code cell: it has no child references
+/// (pure bytecode bits), so its depth is 0 regardless of bit length.
+const WALLET_V3R2_CODE_DEPTH: u16 = 0;
+
+/// Default subwallet ID used by TON wallet v3 contracts.
+const DEFAULT_SUBWALLET_ID: u32 = 698_983_191; // 0x29A9A317
+
+/// Representation hash of the compiled wallet v3R2 code cell - a fixed
+/// constant across every v3R2 wallet; only the data cell differs per account.
+fn wallet_v3r2_code_hash() -> [u8; 32] {
+    let bytes = hex::decode("84dafa449f98a6987789ba232358072bc0f76dc4524002a5d0918b9a75d2d599").unwrap();
+    let mut out = [0u8; 32];
+    out.copy_from_slice(&bytes);
+    out
+}
+
+/// Representation hash of a leaf TVM cell (no references): `SHA256(d1 || d2
+/// || data)`, where `d1` encodes the reference count (0 here) and `d2`
+/// encodes the bit length, per the standard BoC cell-hash algorithm.
+fn leaf_cell_hash(data: &[u8], bit_len: usize) -> [u8; 32] {
+    let d1 = 0u8; // 0 refs, non-exotic, level 0
+    let full_bytes = bit_len / 8;
+    let d2 = ((bit_len + 7) / 8 + full_bytes) as u8;
+    let mut repr = Vec::with_capacity(2 + data.len());
+    repr.push(d1);
+    repr.push(d2);
+    repr.extend_from_slice(data);
+    sha256(&repr)
+}
+
+/// Build the 40-byte data cell for a wallet v3 contract: `seqno(32) ||
+/// subwallet_id(32) || public_key(256)`, all bit-aligned so no completion
+/// tag is needed.
+fn wallet_v3_data_cell_hash(public_key: &[u8; 32], subwallet_id: u32) -> [u8; 32] {
+    let mut data = Vec::with_capacity(40);
+    data.extend_from_slice(&0u32.to_be_bytes()); // seqno = 0
+    data.extend_from_slice(&subwallet_id.to_be_bytes());
+    data.extend_from_slice(public_key);
+    leaf_cell_hash(&data, 320)
+}
+
+/// Representation hash of the StateInit cell: `split_depth=none,
+/// special=none, code=Some(ref), data=Some(ref), library=none` (5 header
+/// bits: `00110`), referencing the code and data cells.
+fn state_init_hash(code_hash: [u8; 32], code_depth: u16, data_hash: [u8; 32], data_depth: u16) -> [u8; 32] {
+    let d1 = 2u8; // 2 refs, non-exotic, level 0
+    let d2 = 1u8; // ceil(5/8) + floor(5/8) = 1 + 0
+    let header_byte = 0b0011_0100u8; // "00110" + completion tag "1", zero-padded
+
+    let mut repr = Vec::with_capacity(2 + 1 + 4 + 64);
+    repr.push(d1);
+    repr.push(d2);
+    repr.push(header_byte);
+    repr.extend_from_slice(&code_depth.to_be_bytes());
+    repr.extend_from_slice(&data_depth.to_be_bytes());
+    repr.extend_from_slice(&code_hash);
+    repr.extend_from_slice(&data_hash);
+    sha256(&repr)
+}
+
+/// Compute the wallet v3R2 `account_id` (StateInit representation hash) for
+/// `public_key` under `subwallet_id`.
+fn wallet_account_id(public_key: &[u8; 32], subwallet_id: u32) -> [u8; 32] {
+    let data_hash = wallet_v3_data_cell_hash(public_key, subwallet_id);
+    state_init_hash(wallet_v3r2_code_hash(), WALLET_V3R2_CODE_DEPTH, data_hash, 0)
+}
+
 // CRC16-CCITT for TON
 fn crc16_ccitt(data: &[u8]) -> u16 {
     let mut crc: u16 = 0x0000;
@@ -26,13 +96,12 @@ fn crc16_ccitt(data: &[u8]) -> u16 {
 
 // Base64url encode
 fn base64url_encode(data: &[u8]) -> String {
-    use std::collections::HashMap;
     const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
-    
+
     let mut result = String::new();
     let mut bits = 0u32;
     let mut value = 0u32;
-    
+
     for &byte in data {
         value = (value << 8) | (byte as u32);
         bits += 8;
@@ -41,14 +110,30 @@ fn base64url_encode(data: &[u8]) -> String {
             result.push(ALPHABET[((value >> bits) & 0x3F) as usize] as char);
         }
     }
-    
+
     if bits > 0 {
         result.push(ALPHABET[((value << (6 - bits)) & 0x3F) as usize] as char);
     }
-    
+
     result
 }
 
+fn is_testnet(address_type: AddressType) -> bool {
+    matches!(address_type, AddressType::TonTestnet | AddressType::TonTestnetNonBounceable)
+}
+
+fn is_bounceable(address_type: AddressType) -> bool {
+    matches!(address_type, AddressType::Ton | AddressType::TonTestnet)
+}
+
+fn flags_byte(address_type: AddressType) -> u8 {
+    let mut flags = if is_bounceable(address_type) { 0x11 } else { 0x51 };
+    if is_testnet(address_type) {
+        flags |= 0x80;
+    }
+    flags
+}
+
 impl Chain for Ton {
     fn ticker(&self) -> &'static str {
         "TON"
@@ -63,7 +148,12 @@ impl Chain for Ton {
     }
 
     fn address_types(&self) -> Vec<AddressType> {
-        vec![AddressType::Ton]
+        vec![
+            AddressType::Ton,
+            AddressType::TonNonBounceable,
+            AddressType::TonTestnet,
+            AddressType::TonTestnetNonBounceable,
+        ]
     }
 
     fn default_address_type(&self) -> AddressType {
@@ -72,7 +162,7 @@ impl Chain for Ton {
 
     fn generate(&self, address_type: AddressType) -> GeneratedAddress {
         let keypair = Ed25519Keypair::generate();
-        self.generate_from_keypair(&keypair, address_type)
+        self.generate_from_keypair(&keypair, 0, address_type)
     }
 
     fn generate_from_bytes(&self, private_key: &[u8], address_type: AddressType) -> Option<GeneratedAddress> {
@@ -82,46 +172,60 @@ impl Chain for Ton {
         let mut pk = [0u8; 32];
         pk.copy_from_slice(private_key);
         let keypair = Ed25519Keypair::from_bytes(&pk).ok()?;
-        Some(self.generate_from_keypair(&keypair, address_type))
+        Some(self.generate_from_keypair(&keypair, 0, address_type))
     }
 
     fn valid_address_chars(&self, _address_type: AddressType) -> &'static str {
         "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_"
     }
 
-    fn address_prefix(&self, _address_type: AddressType) -> &'static str {
-        "EQ"
+    fn address_prefix(&self, address_type: AddressType) -> &'static str {
+        match (is_bounceable(address_type), is_testnet(address_type)) {
+            (true, false) => "EQ",
+            (false, false) => "UQ",
+            (true, true) => "kQ",
+            (false, true) => "0Q",
+        }
     }
 }
 
 impl Ton {
-    fn generate_from_keypair(&self, keypair: &Ed25519Keypair, _address_type: AddressType) -> GeneratedAddress {
+    fn generate_from_keypair(&self, keypair: &Ed25519Keypair, workchain: i8, address_type: AddressType) -> GeneratedAddress {
         let private_key = keypair.private_key_bytes();
         let public_key = keypair.public_key_bytes();
-        
-        // TON user-friendly address format:
-        // [flags(1)] [workchain(1)] [account_id(32)] [crc16(2)]
-        // For bounceable mainnet: flags = 0x11, workchain = 0x00
+
+        let account_id = wallet_account_id(&public_key, DEFAULT_SUBWALLET_ID);
+
         let mut data = Vec::with_capacity(36);
-        data.push(0x11); // Bounceable, mainnet
-        data.push(0x00); // Workchain 0
-        data.extend_from_slice(&public_key); // Account ID (simplified: using pubkey directly)
-        
+        data.push(flags_byte(address_type));
+        data.push(workchain as u8);
+        data.extend_from_slice(&account_id);
+
         let crc = crc16_ccitt(&data);
         data.push((crc >> 8) as u8);
         data.push((crc & 0xFF) as u8);
-        
+
         let address = base64url_encode(&data);
-        
+
         GeneratedAddress {
             address,
             private_key_hex: hex::encode(private_key),
             private_key_native: hex::encode(private_key),
             public_key_hex: hex::encode(public_key),
             chain: "TON".to_string(),
-            address_type: AddressType::Ton,
+            address_type,
+            mnemonic: None,
+            derivation_path: None,
+            network: if is_testnet(address_type) { Network::Testnet } else { Network::Mainnet },
         }
     }
+
+    /// Generate a wallet v3R2 address on a workchain other than the default
+    /// basechain (`0`) - e.g. `-1` for the masterchain.
+    pub fn generate_on_workchain(&self, address_type: AddressType, workchain: i8) -> GeneratedAddress {
+        let keypair = Ed25519Keypair::generate();
+        self.generate_from_keypair(&keypair, workchain, address_type)
+    }
 }
 
 #[cfg(test)]
@@ -135,4 +239,58 @@ mod tests {
         assert!(addr.address.starts_with("EQ"));
         assert_eq!(addr.chain, "TON");
     }
+
+    #[test]
+    fn test_ton_non_bounceable_flag_differs() {
+        let privkey = hex::decode("0000000000000000000000000000000000000000000000000000000000000001").unwrap();
+        let ton = Ton;
+        let bounceable = ton.generate_from_bytes(&privkey, AddressType::Ton).unwrap();
+        let non_bounceable = ton.generate_from_bytes(&privkey, AddressType::TonNonBounceable).unwrap();
+        assert!(bounceable.address.starts_with("EQ"));
+        assert!(non_bounceable.address.starts_with("UQ"));
+        assert_ne!(bounceable.address, non_bounceable.address);
+    }
+
+    #[test]
+    fn test_ton_testnet_flag_differs() {
+        let privkey = hex::decode("0000000000000000000000000000000000000000000000000000000000000001").unwrap();
+        let ton = Ton;
+        let mainnet = ton.generate_from_bytes(&privkey, AddressType::Ton).unwrap();
+        let testnet = ton.generate_from_bytes(&privkey, AddressType::TonTestnet).unwrap();
+        assert!(testnet.address.starts_with("kQ"));
+        assert_ne!(mainnet.address, testnet.address);
+    }
+
+    #[test]
+    fn test_ton_account_id_is_deterministic() {
+        let pubkey = [7u8; 32];
+        let a = wallet_account_id(&pubkey, DEFAULT_SUBWALLET_ID);
+        let b = wallet_account_id(&pubkey, DEFAULT_SUBWALLET_ID);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_generate_from_seed_is_deterministic() {
+        let seed = [9u8; 32];
+        let ton = Ton;
+        let a = ton.generate_from_seed(&seed, "m/44'/607'/0'", AddressType::Ton).unwrap();
+        let b = ton.generate_from_seed(&seed, "m/44'/607'/0'", AddressType::Ton).unwrap();
+        assert_eq!(a.address.address, b.address.address);
+    }
+
+    #[test]
+    fn test_generate_from_seed_rejects_non_hardened_path() {
+        let seed = [9u8; 32];
+        let ton = Ton;
+        assert!(ton.generate_from_seed(&seed, "m/44'/607'/0", AddressType::Ton).is_err());
+    }
+
+    #[test]
+    fn test_ton_workchain_changes_address() {
+        let ton = Ton;
+        let keypair = Ed25519Keypair::from_bytes(&[1u8; 32]).unwrap();
+        let basechain = ton.generate_from_keypair(&keypair, 0, AddressType::Ton);
+        let masterchain = ton.generate_from_keypair(&keypair, -1, AddressType::Ton);
+        assert_ne!(basechain.address, masterchain.address);
+    }
 }
@@ -1,7 +1,14 @@
 //! Chain trait and types
 
+use omnivanity_crypto::encoding::{
+    base58_decode, base58check_decode, base58check_encode, base64_decode, base64_encode, bech32_decode,
+};
+use omnivanity_crypto::hash::{double_sha256, hash160, keccak256};
+use omnivanity_crypto::hd::{derive_bip32, derive_slip10_ed25519, HdError};
+use omnivanity_crypto::{ed25519, passphrase_ed25519_bytes, passphrase_secp256k1_bytes, secp256k1, Secp256k1Keypair};
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use thiserror::Error;
 
 /// Chain family categorization
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -12,6 +19,37 @@ pub enum ChainFamily {
     UtxoSecp256k1,
     /// Ed25519-based chains (Solana)
     Ed25519,
+    /// HRP-prefixed bech32/bech32m chains built on secp256k1 (Cosmos-SDK
+    /// accounts, SegWit v0/v1) - same key derivation as `UtxoSecp256k1`, but
+    /// tagged separately so address-format-specific logic (e.g. a future
+    /// bech32-aware GPU pattern matcher) can key off it without also
+    /// matching Base58Check chains.
+    Bech32,
+}
+
+/// Which network tier a generated address targets, mirroring rust-bitcoin's
+/// `Network` (as threaded through e.g. `require_network(Network::Regtest)`).
+/// Only chains with a `Chain::generate_for_network` override actually vary
+/// their output by this; everything else treats every variant as `Mainnet`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Network {
+    /// The chain's production network.
+    #[default]
+    Mainnet,
+    /// The chain's public test network (e.g. Bitcoin testnet3/testnet4).
+    Testnet,
+    /// A local, typically single-node development network.
+    Regtest,
+}
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Network::Mainnet => write!(f, "mainnet"),
+            Network::Testnet => write!(f, "testnet"),
+            Network::Regtest => write!(f, "regtest"),
+        }
+    }
 }
 
 /// Address type for a chain
@@ -33,6 +71,9 @@ pub enum AddressType {
     Cosmos,
     /// TRON address (T...)
     Tron,
+    /// TRON hex address (`41` + 20-byte payload) - the node-RPC/contract-facing
+    /// encoding of the same payload `AddressType::Tron`'s Base58Check string carries.
+    TronHex,
     /// XRP Ledger address (r...)
     Xrpl,
     /// Stellar StrKey (G...)
@@ -51,10 +92,62 @@ pub enum AddressType {
     Ss58,
     /// Filecoin address (f1...)
     Filecoin,
+    /// Filecoin protocol-3 BLS address (f3...), gated behind the
+    /// `bls12-381` feature (see `omnivanity_crypto::Bls12381Keypair`).
+    FilecoinBls,
+    /// Filecoin protocol-4 delegated (FEVM) address (f410f...)
+    FilecoinDelegated,
     /// Zilliqa Bech32 (zil1...)
     Zilliqa,
     /// Nano address (nano_...)
     Nano,
+    /// Monero standard address (Base58, network byte 18)
+    Monero,
+    /// Monero integrated address (standard address + 8-byte payment ID, network byte 19)
+    MoneroIntegrated,
+    /// Monero subaddress (network byte 42)
+    MoneroSubaddress,
+    /// Zcash Sapling shielded address (bech32 "zs...")
+    Sapling,
+    /// Native SegWit address via the self-contained bech32/bech32m encoder
+    /// (witness v0 P2WPKH or v1 P2TR, selected by program length/version).
+    Bech32,
+    /// Tezos tz1 address (Ed25519, "tz1...")
+    Tezos,
+    /// Tezos tz2 address (secp256k1, "tz2...")
+    TezosSecp256k1,
+    /// Tezos tz3 address (P-256, "tz3...")
+    TezosP256,
+    /// Nested SegWit: P2SH-wrapped P2WPKH ("3..." on BTC, "M..." on LTC)
+    P2shP2wpkh,
+    /// Bitcoin Cash CashAddr P2PKH ("bitcoincash:q...")
+    CashAddr,
+    /// Bitcoin Cash CashAddr P2SH ("bitcoincash:p...")
+    CashAddrP2sh,
+    /// Cardano Shelley enterprise address, mainnet (no staking component, "addr1...")
+    Cardano,
+    /// Cardano Shelley base address, mainnet (payment + staking key, "addr1...")
+    CardanoBase,
+    /// Cardano Shelley enterprise address, testnet ("addr_test1...")
+    CardanoTestnet,
+    /// Cardano Shelley base address, testnet ("addr_test1...")
+    CardanoBaseTestnet,
+    /// TON wallet v3R2 address, bounceable, mainnet ("EQ...")
+    Ton,
+    /// TON wallet v3R2 address, non-bounceable, mainnet ("UQ...")
+    TonNonBounceable,
+    /// TON wallet v3R2 address, bounceable, testnet ("kQ...")
+    TonTestnet,
+    /// TON wallet v3R2 address, non-bounceable, testnet ("0Q...")
+    TonTestnetNonBounceable,
+    /// Internet Computer self-authenticating principal (hyphenated Base32)
+    Icp,
+    /// Penumbra shielded address (bech32m "penumbra1...")
+    Penumbra,
+    /// Pactus Ed25519 account address (bech32m "pc1...", type byte 3)
+    Pactus,
+    /// Pactus Ed25519 validator address (bech32m "pc1...", type byte 1)
+    PactusValidator,
 }
 
 impl fmt::Display for AddressType {
@@ -68,6 +161,7 @@ impl fmt::Display for AddressType {
             AddressType::Solana => write!(f, "Solana"),
             AddressType::Cosmos => write!(f, "Cosmos Bech32"),
             AddressType::Tron => write!(f, "TRON"),
+            AddressType::TronHex => write!(f, "TRON (Hex)"),
             AddressType::Xrpl => write!(f, "XRP Ledger"),
             AddressType::Stellar => write!(f, "Stellar StrKey"),
             AddressType::Aptos => write!(f, "Aptos"),
@@ -77,12 +171,56 @@ impl fmt::Display for AddressType {
             AddressType::Algorand => write!(f, "Algorand"),
             AddressType::Ss58 => write!(f, "SS58"),
             AddressType::Filecoin => write!(f, "Filecoin"),
+            AddressType::FilecoinBls => write!(f, "Filecoin (BLS)"),
+            AddressType::FilecoinDelegated => write!(f, "Filecoin (Delegated)"),
             AddressType::Zilliqa => write!(f, "Zilliqa"),
             AddressType::Nano => write!(f, "Nano"),
+            AddressType::Monero => write!(f, "Monero"),
+            AddressType::MoneroIntegrated => write!(f, "Monero Integrated"),
+            AddressType::MoneroSubaddress => write!(f, "Monero Subaddress"),
+            AddressType::Sapling => write!(f, "Zcash Sapling (shielded)"),
+            AddressType::Bech32 => write!(f, "Native SegWit (Bech32)"),
+            AddressType::Tezos => write!(f, "Tezos tz1 (Ed25519)"),
+            AddressType::TezosSecp256k1 => write!(f, "Tezos tz2 (secp256k1)"),
+            AddressType::TezosP256 => write!(f, "Tezos tz3 (P-256)"),
+            AddressType::P2shP2wpkh => write!(f, "P2SH-P2WPKH (Nested SegWit)"),
+            AddressType::CashAddr => write!(f, "CashAddr (P2PKH)"),
+            AddressType::CashAddrP2sh => write!(f, "CashAddr (P2SH)"),
+            AddressType::Cardano => write!(f, "Cardano Enterprise"),
+            AddressType::CardanoBase => write!(f, "Cardano Base (+ staking)"),
+            AddressType::CardanoTestnet => write!(f, "Cardano Enterprise (Testnet)"),
+            AddressType::CardanoBaseTestnet => write!(f, "Cardano Base (Testnet, + staking)"),
+            AddressType::Ton => write!(f, "TON (Bounceable)"),
+            AddressType::TonNonBounceable => write!(f, "TON (Non-bounceable)"),
+            AddressType::TonTestnet => write!(f, "TON Testnet (Bounceable)"),
+            AddressType::TonTestnetNonBounceable => write!(f, "TON Testnet (Non-bounceable)"),
+            AddressType::Icp => write!(f, "ICP Principal"),
+            AddressType::Penumbra => write!(f, "Penumbra (shielded)"),
+            AddressType::Pactus => write!(f, "Pactus Account"),
+            AddressType::PactusValidator => write!(f, "Pactus Validator"),
         }
     }
 }
 
+/// An address decoded back into its detected type and recovered payload
+/// (e.g. the 20-byte hash160, the x-only Taproot key, or a raw pubkey),
+/// for callers that need to verify a user-supplied address rather than mint
+/// a new one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedAddress {
+    pub address_type: AddressType,
+    pub payload: Vec<u8>,
+}
+
+/// A `GeneratedAddress` derived along a hierarchical (BIP32/SLIP-0010)
+/// derivation path from a raw seed, so the result can be re-derived by any
+/// standard HD wallet that knows the same seed and path.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeedAddress {
+    pub address: GeneratedAddress,
+    pub path: String,
+}
+
 /// A generated address with its keypair
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GeneratedAddress {
@@ -98,6 +236,120 @@ pub struct GeneratedAddress {
     pub chain: String,
     /// Address type used
     pub address_type: AddressType,
+    /// BIP39 recovery phrase, if this address was derived via
+    /// `hd_search`'s mnemonic-based generation instead of a raw random key.
+    /// `None` for every other generation path.
+    #[serde(default)]
+    pub mnemonic: Option<String>,
+    /// The BIP32/SLIP-0010 path the key was derived along (e.g.
+    /// `"m/44'/60'/0'/0/0"`), present whenever `mnemonic` is.
+    #[serde(default)]
+    pub derivation_path: Option<String>,
+    /// Which network this address targets. Chains without a
+    /// `generate_for_network` override ignore the requested network, so
+    /// this is the actual network the bytes are valid for, not
+    /// necessarily the one a caller asked `generate_for_network` for.
+    #[serde(default)]
+    pub network: Network,
+}
+
+/// Error produced by [`Chain::sign_message`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum MessageSigningError {
+    #[error("private key is the wrong length for this chain family")]
+    InvalidPrivateKey,
+    #[error("signing failed")]
+    SigningFailed,
+}
+
+/// Encode `len` as a Bitcoin-style `CompactSize`/`varint` - used only to
+/// build the length-prefixed message buffer [`bitcoin_signed_message_prehash`]
+/// hashes; every message this tool signs is short enough that in practice
+/// this only ever emits the single-byte form, but the multi-byte forms are
+/// included so a deliberately huge `msg` still hashes correctly.
+fn bitcoin_varint(len: usize) -> Vec<u8> {
+    if len < 0xfd {
+        vec![len as u8]
+    } else if len <= 0xffff {
+        let mut out = vec![0xfd];
+        out.extend_from_slice(&(len as u16).to_le_bytes());
+        out
+    } else {
+        let mut out = vec![0xfe];
+        out.extend_from_slice(&(len as u32).to_le_bytes());
+        out
+    }
+}
+
+/// Hash a message the way Bitcoin Core's `signmessage`/`verifymessage` RPCs
+/// do: double-SHA256 of `varint(len(magic)) || magic || varint(len(msg)) ||
+/// msg`. `magic` is usually `"Bitcoin Signed Message:\n"`; chains that use a
+/// different magic string override [`Chain::message_magic`].
+fn bitcoin_signed_message_prehash(magic: &str, msg: &[u8]) -> [u8; 32] {
+    let mut data = Vec::with_capacity(magic.len() + msg.len() + 10);
+    data.extend_from_slice(&bitcoin_varint(magic.len()));
+    data.extend_from_slice(magic.as_bytes());
+    data.extend_from_slice(&bitcoin_varint(msg.len()));
+    data.extend_from_slice(msg);
+    double_sha256(&data)
+}
+
+/// Hash a message per EIP-191 (`personal_sign`): Keccak256 of
+/// `"\x19Ethereum Signed Message:\n" || len(msg) as decimal ASCII || msg`.
+fn ethereum_signed_message_prehash(msg: &[u8]) -> [u8; 32] {
+    let mut data = Vec::with_capacity(26 + msg.len());
+    data.extend_from_slice(b"\x19Ethereum Signed Message:\n");
+    data.extend_from_slice(msg.len().to_string().as_bytes());
+    data.extend_from_slice(msg);
+    keccak256(&data)
+}
+
+/// Sign `prehash` with `private_key`, returning the signature bytes in
+/// either Bitcoin Core's header-byte-first layout (`header || r || s`,
+/// `header = 27 + 4 + recovery_id` - the `+4` marks a compressed pubkey,
+/// which is all this tool ever generates) or Ethereum's `r || s || v`
+/// layout (`v = recovery_id + 27`), selected by `header_first`.
+fn secp256k1_sign_prehash(
+    private_key: &[u8],
+    prehash: &[u8; 32],
+    header_first: bool,
+) -> Result<Vec<u8>, MessageSigningError> {
+    if private_key.len() != 32 {
+        return Err(MessageSigningError::InvalidPrivateKey);
+    }
+    let mut pk = [0u8; 32];
+    pk.copy_from_slice(private_key);
+    let keypair = Secp256k1Keypair::from_bytes(&pk).map_err(|_| MessageSigningError::InvalidPrivateKey)?;
+    let (signature, recovery_id) = keypair
+        .sign_prehash_recoverable(prehash)
+        .map_err(|_| MessageSigningError::SigningFailed)?;
+
+    let mut out = Vec::with_capacity(65);
+    if header_first {
+        out.push(27 + 4 + recovery_id);
+        out.extend_from_slice(&signature);
+    } else {
+        out.extend_from_slice(&signature);
+        out.push(recovery_id + 27);
+    }
+    Ok(out)
+}
+
+/// Recover the secp256k1 public key from a signature produced by
+/// [`secp256k1_sign_prehash`], undoing whichever of the two layouts
+/// `header_first` selects.
+fn secp256k1_recover_from_signature(prehash: &[u8; 32], signature: &[u8], header_first: bool) -> Option<[u8; 65]> {
+    if signature.len() != 65 {
+        return None;
+    }
+    let (recovery_id, compact) = if header_first {
+        (signature[0].checked_sub(27)? & 0x03, &signature[1..65])
+    } else {
+        (signature[64].checked_sub(27)?, &signature[..64])
+    };
+    let mut sig = [0u8; 64];
+    sig.copy_from_slice(compact);
+    secp256k1::recover_public_key_from_prehash(prehash, &sig, recovery_id).ok()
 }
 
 /// Trait for chain implementations
@@ -122,10 +374,342 @@ pub trait Chain: Send + Sync {
     
     /// Generate from specific private key bytes
     fn generate_from_bytes(&self, private_key: &[u8], address_type: AddressType) -> Option<GeneratedAddress>;
-    
+
+    /// Generate a random address for a specific `network` tier instead of
+    /// always mainnet, so e.g. testnet vanity addresses can be ground
+    /// without risking real funds. Follows rust-bitcoin's `require_network`
+    /// pattern. Default implementation ignores `network` and falls back to
+    /// `generate()` - override this for chains that actually carry distinct
+    /// version bytes or HRPs per network (see `Bitcoin`, `Litecoin`,
+    /// `Kaspa`, `Zilliqa`); every other chain is either network-agnostic
+    /// already (EVM) or hasn't opted in yet.
+    fn generate_for_network(&self, network: Network, address_type: AddressType) -> GeneratedAddress {
+        let _ = network;
+        self.generate(address_type)
+    }
+
+    /// Derive the next candidate after `previous` via this chain's cheapest
+    /// incremental key derivation, if it has one, instead of a fresh
+    /// `generate()` (e.g. secp256k1 chains can add the generator point once
+    /// instead of redoing a full scalar multiplication - see
+    /// `EvmChain::generate_next`). Returns `None` when the chain has no
+    /// such fast path, or when the step hit an edge case and the caller
+    /// should fall back to `generate()` for a new base instead. Default
+    /// implementation has no fast path.
+    fn generate_next(&self, _previous: &GeneratedAddress, _address_type: AddressType) -> Option<GeneratedAddress> {
+        None
+    }
+
+    /// Generate `count` candidates at once, using whatever batched fast path
+    /// this chain has (see `EvmChain::generate_batch`'s Montgomery-batched
+    /// point-addition walk) instead of `count` individual `generate()`
+    /// calls. Default implementation just chains `generate_next` off of one
+    /// `generate()` call, falling back to a fresh `generate()` whenever the
+    /// former hits an edge case - the same strategy `VanitySearch::run_cpu`'s
+    /// `walk_from` loop already uses by hand.
+    fn generate_batch(&self, address_type: AddressType, count: usize) -> Vec<GeneratedAddress> {
+        let mut result = Vec::with_capacity(count);
+        let mut previous: Option<GeneratedAddress> = None;
+        for _ in 0..count {
+            let addr = previous
+                .as_ref()
+                .and_then(|prev| self.generate_next(prev, address_type))
+                .unwrap_or_else(|| self.generate(address_type));
+            previous = Some(addr.clone());
+            result.push(addr);
+        }
+        result
+    }
+
+    /// Derive `address_type`'s address string from a public key alone, with
+    /// no private scalar in hand - `pubkey` is a compressed (33-byte)
+    /// secp256k1 point for `ChainFamily::Evm`/`UtxoSecp256k1` chains. This
+    /// backs split-key ("delegated") vanity search (see
+    /// `omnivanity_chains::split_search`), where a worker only ever holds
+    /// the owner's public point `P = p·G` plus a walked offset and must
+    /// still check candidate addresses against the pattern without ever
+    /// learning `p`. Returns `None` for chains/address types that need the
+    /// private scalar to derive (e.g. Taproot's key-path tweak) or haven't
+    /// opted in yet.
+    fn address_from_public_key(&self, _pubkey: &[u8], _address_type: AddressType) -> Option<String> {
+        None
+    }
+
+    /// The single-byte Base58Check version prefix this chain encodes
+    /// `address_type` with (e.g. `0x00` for Bitcoin P2PKH, `0x1E` for
+    /// Dogecoin P2PKH), if `address_type` uses Base58Check at all. This is
+    /// the one piece of chain-specific state a `ChainFamily::UtxoSecp256k1`
+    /// GPU search needs up front - the version byte and the `HASH160`
+    /// routine are otherwise identical across every chain in the family
+    /// (see `omnivanity-gpu`'s `UtxoCudaEngine`, which reads this to build
+    /// each candidate's payload on-device instead of hard-coding Bitcoin's
+    /// `0x00`). Default implementation returns `None`, meaning either this
+    /// chain doesn't use Base58Check for `address_type` (e.g. Bech32/Taproot)
+    /// or hasn't opted into GPU search yet.
+    fn address_version_byte(&self, _address_type: AddressType) -> Option<u8> {
+        None
+    }
+
+    /// Decode `s` back into its raw payload, verifying any embedded
+    /// checksum along the way - the inverse of `generate`/`generate_batch`'s
+    /// encoding step. Returns `None` if `s` isn't well-formed for
+    /// `address_type` or its checksum doesn't match. Lets the vanity engine
+    /// confirm its own output round-trips, and lets a user confirm an
+    /// imported key maps to an expected address. Default implementation
+    /// returns `None`; override for chains that want to support it.
+    fn parse_address(&self, _s: &str, _address_type: AddressType) -> Option<Vec<u8>> {
+        None
+    }
+
     /// Get valid characters for addresses (for pattern validation)
     fn valid_address_chars(&self, address_type: AddressType) -> &'static str;
     
     /// Get the address prefix (e.g., "0x", "1", "bc1q")
     fn address_prefix(&self, address_type: AddressType) -> &'static str;
+
+    /// `address_prefix`, but for a specific `network` tier - so the pattern
+    /// matcher validates a requested prefix against e.g. Bitcoin testnet's
+    /// `"tb1q"`/`"m"`/`"n"` instead of always mainnet's `"bc1q"`/`"1"`.
+    /// Default implementation ignores `network` and defers to
+    /// `address_prefix`; override alongside `generate_for_network`.
+    fn address_prefix_for_network(&self, address_type: AddressType, network: Network) -> &'static str {
+        let _ = network;
+        self.address_prefix(address_type)
+    }
+
+    /// `valid_address_chars`, but for a specific `network` tier. Default
+    /// implementation ignores `network` and defers to `valid_address_chars`;
+    /// override alongside `generate_for_network` for chains whose charset
+    /// actually differs by network.
+    fn valid_address_chars_for_network(&self, address_type: AddressType, network: Network) -> &'static str {
+        let _ = network;
+        self.valid_address_chars(address_type)
+    }
+
+    /// Validate that `address` is well-formed for `address_type` (correct
+    /// prefix/charset and, where applicable, a correct checksum). Chains
+    /// that don't yet implement real validation fall back to a prefix check.
+    fn validate_address(&self, address: &str, address_type: AddressType) -> bool {
+        address.starts_with(self.address_prefix(address_type))
+    }
+
+    /// Deterministically derive a `GeneratedAddress` from a user-chosen
+    /// passphrase, so the same phrase reproduces the same address on any
+    /// machine (the "brain wallet" workflow). Stretches `phrase` with
+    /// PBKDF2-HMAC-SHA256 (domain-separated by this chain's ticker, so the
+    /// same phrase differs across chains) and feeds the result into
+    /// `generate_from_bytes`: `Ed25519` chains use the stretched bytes
+    /// directly as a seed, everything else treats them as a secp256k1
+    /// scalar candidate (re-stretching with an incremented counter until
+    /// one is valid). Returns `None` if `address_type` isn't one this chain's
+    /// `generate_from_bytes` accepts, instead of panicking.
+    fn generate_from_passphrase(&self, phrase: &str, address_type: AddressType) -> Option<GeneratedAddress> {
+        let key = match self.family() {
+            ChainFamily::Ed25519 => passphrase_ed25519_bytes(phrase, self.ticker()),
+            _ => passphrase_secp256k1_bytes(phrase, self.ticker()),
+        };
+        self.generate_from_bytes(&key, address_type)
+    }
+
+    /// Re-derive a `GeneratedAddress` from an existing native-format private
+    /// key string (e.g. a Bitcoin-family WIF) rather than raw secret bytes,
+    /// so a user re-importing a key doesn't have to hex-decode it first.
+    /// Default implementation assumes a standard WIF: Base58Check-decode,
+    /// then treat a 33-byte payload ending in `0x01` as a compressed key
+    /// (stripping the flag) and a bare 32-byte payload as uncompressed.
+    /// Chains whose native key format isn't WIF (Tezos's `edsk`/`spsk`/`p2sk`,
+    /// NEAR's `ed25519:...`) should override this.
+    fn import_native_key(&self, native_key: &str) -> Option<GeneratedAddress> {
+        let (_version, payload) = base58check_decode(native_key).ok()?;
+        let secret = match payload.len() {
+            33 if payload[32] == 0x01 => &payload[..32],
+            32 => &payload[..],
+            _ => return None,
+        };
+        self.generate_from_bytes(secret, self.default_address_type())
+    }
+
+    /// Parse `address` back into its detected `AddressType` plus recovered
+    /// payload bytes, trying each of this chain's `address_types` in turn.
+    /// Default implementation only knows the two encodings shared by every
+    /// adapter so far - Base58Check (version byte + payload + checksum) and
+    /// bech32/bech32m (hrp + witness version + payload) - so chains whose
+    /// address format is neither (raw hex, CashAddr, SS58, ...) should
+    /// override this with a real decoder.
+    fn decode_address(&self, address: &str) -> Option<DecodedAddress> {
+        for address_type in self.address_types() {
+            if !self.validate_address(address, address_type) {
+                continue;
+            }
+            // `payload` here excludes the leading witness-version byte -
+            // `bech32_decode` returns it separately as `_witver`.
+            if let Ok((_hrp, _witver, payload)) = bech32_decode(address) {
+                return Some(DecodedAddress { address_type, payload });
+            }
+            if let Ok((_version, payload)) = base58check_decode(address) {
+                return Some(DecodedAddress { address_type, payload });
+            }
+        }
+        None
+    }
+
+    /// Derive a `GeneratedAddress` along a hierarchical derivation path from
+    /// a raw seed (e.g. a BIP39 seed, or any other 16+ byte secret), so the
+    /// result is importable into a standard HD wallet that knows the same
+    /// seed and path. `Ed25519` chains use SLIP-0010 (hardened steps only);
+    /// everything else uses BIP32. The derived 32-byte key is fed into
+    /// `generate_from_bytes` exactly as a raw seed would be.
+    fn generate_from_seed(&self, seed: &[u8], path: &str, address_type: AddressType) -> Result<SeedAddress, HdError> {
+        let key = match self.family() {
+            ChainFamily::Ed25519 => derive_slip10_ed25519(seed, path)?,
+            _ => derive_bip32(seed, path)?,
+        };
+        let address = self
+            .generate_from_bytes(&key, address_type)
+            .ok_or(HdError::InvalidKey)?;
+        Ok(SeedAddress { address, path: path.to_string() })
+    }
+
+    /// The magic string a `ChainFamily::UtxoSecp256k1`/`Bech32` chain's
+    /// signed messages are prefixed with before hashing (see
+    /// `bitcoin_signed_message_prehash`). Every chain in these families
+    /// currently shares Bitcoin's own magic; override this if a fork
+    /// (Litecoin, Dogecoin, ...) ever needs its own.
+    fn message_magic(&self) -> &'static str {
+        "Bitcoin Signed Message:\n"
+    }
+
+    /// Sign `msg` with `private_key`, in whichever format this chain's
+    /// wallets/RPCs expect: `UtxoSecp256k1`/`Bech32` chains use a Bitcoin
+    /// Signed Message (double-SHA256 of a magic-prefixed buffer, recoverable
+    /// ECDSA, base64 output - matching `bitcoind`'s `signmessage`); `Evm`
+    /// chains use EIP-191 `personal_sign` (Keccak256, recoverable ECDSA,
+    /// `0x`-prefixed hex `r || s || v`); `Ed25519` chains (Solana) produce
+    /// a detached 64-byte signature with no prefix or recovery scheme,
+    /// hex-encoded.
+    fn sign_message(&self, private_key: &[u8], msg: &[u8]) -> Result<String, MessageSigningError> {
+        match self.family() {
+            ChainFamily::Ed25519 => {
+                if private_key.len() != 32 {
+                    return Err(MessageSigningError::InvalidPrivateKey);
+                }
+                let mut seed = [0u8; 32];
+                seed.copy_from_slice(private_key);
+                let keypair =
+                    ed25519::Ed25519Keypair::from_bytes(&seed).map_err(|_| MessageSigningError::InvalidPrivateKey)?;
+                Ok(omnivanity_crypto::hex::encode(keypair.sign(msg)))
+            }
+            ChainFamily::Evm => {
+                let prehash = ethereum_signed_message_prehash(msg);
+                let signature = secp256k1_sign_prehash(private_key, &prehash, false)?;
+                Ok(format!("0x{}", omnivanity_crypto::hex::encode(signature)))
+            }
+            ChainFamily::UtxoSecp256k1 | ChainFamily::Bech32 => {
+                let prehash = bitcoin_signed_message_prehash(self.message_magic(), msg);
+                let signature = secp256k1_sign_prehash(private_key, &prehash, true)?;
+                Ok(base64_encode(&signature))
+            }
+        }
+    }
+
+    /// Verify that `sig` (in the format [`Chain::sign_message`] produces for
+    /// this family) is a valid signature over `msg` by the owner of
+    /// `address`. `Evm`/`UtxoSecp256k1`/`Bech32` chains recover the signer's public
+    /// key from the signature itself and re-derive the address from it
+    /// (so the signer's public key never has to be supplied separately);
+    /// `Ed25519` chains decode `address` as a base58 raw 32-byte public key
+    /// (Solana's format), so `Ed25519` chains whose address is a hash or
+    /// other encoding of the key (Tezos, TON, Cardano, Stellar, ...) always
+    /// verify as `false`.
+    /// Returns `false`, rather than an error, for any malformed input -
+    /// callers only ever care whether the signature checks out.
+    fn verify_message(&self, address: &str, msg: &[u8], sig: &str) -> bool {
+        match self.family() {
+            ChainFamily::Ed25519 => {
+                let Ok(public_key_bytes) = base58_decode(address) else { return false };
+                let Ok(public_key): Result<[u8; 32], _> = public_key_bytes.try_into() else { return false };
+                let Ok(signature_bytes) = omnivanity_crypto::hex::decode(sig) else { return false };
+                let Ok(signature): Result<[u8; 64], _> = signature_bytes.try_into() else { return false };
+                ed25519::verify(&public_key, msg, &signature)
+            }
+            ChainFamily::Evm => {
+                let Ok(signature) = omnivanity_crypto::hex::decode(sig.trim_start_matches("0x")) else {
+                    return false;
+                };
+                let prehash = ethereum_signed_message_prehash(msg);
+                let Some(pubkey) = secp256k1_recover_from_signature(&prehash, &signature, false) else {
+                    return false;
+                };
+                let hash = keccak256(&pubkey[1..]);
+                let recovered = format!("0x{}", omnivanity_crypto::hex::encode(&hash[12..]));
+                recovered.eq_ignore_ascii_case(address)
+            }
+            ChainFamily::UtxoSecp256k1 | ChainFamily::Bech32 => {
+                let Ok(signature) = base64_decode(sig) else { return false };
+                let prehash = bitcoin_signed_message_prehash(self.message_magic(), msg);
+                let Some(pubkey) = secp256k1_recover_from_signature(&prehash, &signature, true) else {
+                    return false;
+                };
+
+                let mut compressed = [0u8; 33];
+                compressed[0] = if pubkey[64] % 2 == 0 { 0x02 } else { 0x03 };
+                compressed[1..].copy_from_slice(&pubkey[1..33]);
+                let pubkey_hash = hash160(&compressed);
+
+                // The signed address might be any Base58Check address type
+                // this chain supports (P2PKH, P2SH-P2WPKH, ...), not just
+                // its `default_address_type`, so check every one that has a
+                // version byte rather than assuming which was used.
+                self.address_types().into_iter().any(|address_type| {
+                    self.address_version_byte(address_type)
+                        .map(|version| base58check_encode(version, &pubkey_hash) == address)
+                        .unwrap_or(false)
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod message_signing_tests {
+    use super::*;
+    use crate::bitcoin::Bitcoin;
+    use crate::ethereum::ETH;
+    use crate::solana::SOL;
+
+    #[test]
+    fn test_bitcoin_sign_message_round_trips() {
+        let privkey = [5u8; 32];
+        let addr = Bitcoin.generate_from_bytes(&privkey, AddressType::P2pkh).unwrap();
+
+        let sig = Bitcoin.sign_message(&privkey, b"hello from omnivanity").unwrap();
+        assert!(Bitcoin.verify_message(&addr.address, b"hello from omnivanity", &sig));
+    }
+
+    #[test]
+    fn test_bitcoin_verify_message_rejects_tampered_message() {
+        let privkey = [5u8; 32];
+        let addr = Bitcoin.generate_from_bytes(&privkey, AddressType::P2pkh).unwrap();
+        let sig = Bitcoin.sign_message(&privkey, b"hello from omnivanity").unwrap();
+        assert!(!Bitcoin.verify_message(&addr.address, b"goodbye omnivanity", &sig));
+    }
+
+    #[test]
+    fn test_ethereum_sign_message_round_trips() {
+        let privkey = [9u8; 32];
+        let addr = ETH.generate_from_bytes(&privkey, AddressType::Evm).unwrap();
+
+        let sig = ETH.sign_message(&privkey, b"personal_sign test").unwrap();
+        assert!(sig.starts_with("0x"));
+        assert!(ETH.verify_message(&addr.address, b"personal_sign test", &sig));
+    }
+
+    #[test]
+    fn test_solana_sign_message_round_trips() {
+        let privkey = [3u8; 32];
+        let addr = SOL.generate_from_bytes(&privkey, AddressType::Solana).unwrap();
+
+        let sig = SOL.sign_message(&privkey, b"solana detached signature").unwrap();
+        assert!(SOL.verify_message(&addr.address, b"solana detached signature", &sig));
+    }
 }
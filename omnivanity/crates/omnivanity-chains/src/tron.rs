@@ -2,11 +2,11 @@
 //!
 //! TRON address: Keccak256(pubkey) last 20 bytes + 0x41 prefix + Base58Check
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
 use omnivanity_crypto::{
     Secp256k1Keypair,
     hash::keccak256,
-    encoding::base58check_encode,
+    encoding::{base58check_decode, base58check_encode},
     hex,
 };
 
@@ -27,7 +27,7 @@ impl Chain for Tron {
     }
 
     fn address_types(&self) -> Vec<AddressType> {
-        vec![AddressType::Tron]
+        vec![AddressType::Tron, AddressType::TronHex]
     }
 
     fn default_address_type(&self) -> AddressType {
@@ -49,36 +49,111 @@ impl Chain for Tron {
         Some(self.generate_from_keypair(&keypair, address_type))
     }
 
-    fn valid_address_chars(&self, _address_type: AddressType) -> &'static str {
-        "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"
+    fn generate_next(&self, previous: &GeneratedAddress, address_type: AddressType) -> Option<GeneratedAddress> {
+        // Same incremental walk `EvmChain` uses: rebuild the previous
+        // keypair from its already-known bytes (no scalar multiplication),
+        // then add the generator once instead of redoing a full scalar
+        // multiply for the next candidate.
+        let mut privkey = [0u8; 32];
+        hex::decode_to_slice(&previous.private_key_hex, &mut privkey).ok()?;
+        let mut pubkey = [0u8; 65];
+        hex::decode_to_slice(&previous.public_key_hex, &mut pubkey).ok()?;
+
+        let keypair = Secp256k1Keypair::from_raw_parts(&privkey, &pubkey).ok()?;
+        let next = keypair.increment()?;
+        Some(self.generate_from_keypair(&next, address_type))
+    }
+
+    fn generate_batch(&self, address_type: AddressType, count: usize) -> Vec<GeneratedAddress> {
+        if count == 0 {
+            return Vec::new();
+        }
+
+        let base = Secp256k1Keypair::generate();
+        let mut result = Vec::with_capacity(count);
+        result.push(self.generate_from_keypair(&base, address_type));
+
+        // `increment_batch` can return fewer than `count - 1` if it hit an
+        // edge case partway through (see its doc comment) - top up any
+        // shortfall with fresh `generate()` calls so callers always get
+        // exactly `count` addresses back.
+        for keypair in base.increment_batch(count - 1) {
+            result.push(self.generate_from_keypair(&keypair, address_type));
+        }
+        while result.len() < count {
+            result.push(self.generate(address_type));
+        }
+        result
+    }
+
+    fn valid_address_chars(&self, address_type: AddressType) -> &'static str {
+        match address_type {
+            AddressType::TronHex => "0123456789abcdef",
+            _ => "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
+        }
+    }
+
+    fn address_prefix(&self, address_type: AddressType) -> &'static str {
+        match address_type {
+            AddressType::TronHex => "41",
+            _ => "T",
+        }
     }
 
-    fn address_prefix(&self, _address_type: AddressType) -> &'static str {
-        "T"
+    /// Accepts both of TRON's canonical encodings: the Base58Check `T...`
+    /// string (verifying its 4-byte double-SHA256 checksum and `0x41`
+    /// version byte) and the raw `41...` hex form the node RPC and many
+    /// contracts use, returning the shared 20-byte payload either way.
+    fn parse_address(&self, s: &str, _address_type: AddressType) -> Option<Vec<u8>> {
+        if s.len() == 42 && s.starts_with("41") {
+            if let Ok(bytes) = hex::decode(s) {
+                if bytes.len() == 21 && bytes[0] == 0x41 {
+                    return Some(bytes[1..].to_vec());
+                }
+            }
+        }
+
+        let (version, payload) = base58check_decode(s).ok()?;
+        if version == 0x41 && payload.len() == 20 {
+            Some(payload)
+        } else {
+            None
+        }
     }
 }
 
 impl Tron {
-    fn generate_from_keypair(&self, keypair: &Secp256k1Keypair, _address_type: AddressType) -> GeneratedAddress {
+    fn generate_from_keypair(&self, keypair: &Secp256k1Keypair, address_type: AddressType) -> GeneratedAddress {
         let private_key = keypair.private_key_bytes();
-        
+
         // TRON: Keccak256(uncompressed_pubkey[1..65]) last 20 bytes
         let pubkey_xy = keypair.public_key_xy();
         let hash = keccak256(&pubkey_xy);
-        
+
         let mut address_bytes = [0u8; 20];
         address_bytes.copy_from_slice(&hash[12..32]);
-        
-        // TRON address: version byte 0x41 (65) + 20-byte payload, Base58Check encoded
-        let address = base58check_encode(0x41, &address_bytes);
-        
+
+        // TRON address: version byte 0x41 (65) + 20-byte payload, either
+        // Base58Check-encoded (the wallet-facing "T..." form) or left as
+        // raw hex (the node-RPC/contract-facing "41..." form).
+        let address = match address_type {
+            AddressType::TronHex => format!("41{}", hex::encode(address_bytes)),
+            _ => base58check_encode(0x41, &address_bytes),
+        };
+
         GeneratedAddress {
             address,
             private_key_hex: hex::encode(private_key),
-            private_key_native: hex::encode(private_key),
+            // TronLink/TronWeb import a bare `0x`-prefixed hex private key,
+            // the same convention `EvmChain`'s native-key field uses - not
+            // Base58Check WIF, which TRON wallets don't recognize at all.
+            private_key_native: format!("0x{}", hex::encode(private_key)),
             public_key_hex: hex::encode(keypair.public_key_uncompressed()),
             chain: "TRX".to_string(),
-            address_type: AddressType::Tron,
+            address_type,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         }
     }
 }
@@ -94,4 +169,85 @@ mod tests {
         assert!(addr.address.starts_with("T"));
         assert_eq!(addr.chain, "TRX");
     }
+
+    #[test]
+    fn test_tron_hex_generation_matches_base58_payload() {
+        let privkey = [9u8; 32];
+        let base58_addr = Tron.generate_from_bytes(&privkey, AddressType::Tron).unwrap();
+        let hex_addr = Tron.generate_from_bytes(&privkey, AddressType::TronHex).unwrap();
+
+        assert!(hex_addr.address.starts_with("41"));
+        assert_eq!(hex_addr.address.len(), 42);
+        assert_eq!(
+            Tron.parse_address(&base58_addr.address, AddressType::Tron),
+            Tron.parse_address(&hex_addr.address, AddressType::TronHex)
+        );
+    }
+
+    #[test]
+    fn test_parse_address_round_trips_both_forms() {
+        let privkey = [3u8; 32];
+        let base58_addr = Tron.generate_from_bytes(&privkey, AddressType::Tron).unwrap();
+        let hex_addr = Tron.generate_from_bytes(&privkey, AddressType::TronHex).unwrap();
+
+        let payload = Tron.parse_address(&base58_addr.address, AddressType::Tron).unwrap();
+        assert_eq!(payload.len(), 20);
+        assert_eq!(Tron.parse_address(&hex_addr.address, AddressType::TronHex).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_parse_address_rejects_bad_checksum_and_version() {
+        // Flip the last character of a valid address to break its checksum.
+        let addr = Tron.generate(AddressType::Tron);
+        let mut tampered = addr.address.clone();
+        let last = tampered.pop().unwrap();
+        tampered.push(if last == 'a' { 'b' } else { 'a' });
+        assert!(Tron.parse_address(&tampered, AddressType::Tron).is_none());
+
+        // Right length, wrong version byte (0x00 instead of 0x41).
+        let wrong_version = base58check_encode(0x00, &[0u8; 20]);
+        assert!(Tron.parse_address(&wrong_version, AddressType::Tron).is_none());
+
+        assert!(Tron.parse_address("41deadbeef", AddressType::TronHex).is_none());
+    }
+
+    // TRON's BIP44 coin type is 195 (SLIP-44), so a TRON HD wallet derives
+    // its first account's external address along "m/44'/195'/0'/0/0".
+    const TRON_HD_PATH: &str = "m/44'/195'/0'/0/0";
+
+    #[test]
+    fn test_generate_from_seed_is_deterministic() {
+        let seed = [7u8; 32];
+        let a = Tron.generate_from_seed(&seed, TRON_HD_PATH, AddressType::Tron).unwrap();
+        let b = Tron.generate_from_seed(&seed, TRON_HD_PATH, AddressType::Tron).unwrap();
+        assert_eq!(a.address.address, b.address.address);
+        assert_eq!(a.path, TRON_HD_PATH);
+    }
+
+    #[test]
+    fn test_generate_from_seed_path_changes_address() {
+        let seed = [7u8; 32];
+        let a = Tron.generate_from_seed(&seed, TRON_HD_PATH, AddressType::Tron).unwrap();
+        let b = Tron.generate_from_seed(&seed, "m/44'/195'/0'/0/1", AddressType::Tron).unwrap();
+        assert_ne!(a.address.address, b.address.address);
+    }
+
+    #[test]
+    fn test_mnemonic_to_tron_address_round_trip() {
+        use omnivanity_crypto::{generate_mnemonic, mnemonic_to_seed};
+
+        let mnemonic = generate_mnemonic();
+        let seed = mnemonic_to_seed(&mnemonic, "").unwrap();
+        let derived = Tron.generate_from_seed(&seed, TRON_HD_PATH, AddressType::Tron).unwrap();
+
+        // `generate_from_seed` itself only carries the path - the mnemonic
+        // phrase is the caller's to attach, same as `VanitySearch::run_hd`
+        // does on a match.
+        assert_eq!(derived.path, TRON_HD_PATH);
+        assert!(derived.address.address.starts_with("T"));
+
+        let seed_again = mnemonic_to_seed(&mnemonic, "").unwrap();
+        let rederived = Tron.generate_from_seed(&seed_again, TRON_HD_PATH, AddressType::Tron).unwrap();
+        assert_eq!(derived.address.address, rederived.address.address);
+    }
 }
@@ -0,0 +1,216 @@
+//! Cross-chain vanity pattern search driven directly by the `Chain` trait
+//!
+//! Ties `Chain::valid_address_chars`/`address_prefix`/`generate` together into
+//! an actual search loop instead of leaving every adapter as an isolated
+//! address-format implementation.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+use rayon::prelude::*;
+use thiserror::Error;
+
+use crate::traits::{AddressType, Chain, GeneratedAddress};
+
+#[derive(Error, Debug)]
+pub enum VanityError {
+    #[error("pattern is empty")]
+    EmptyPattern,
+    #[error("character '{0}' is not valid for this chain/address type (valid: {1})")]
+    InvalidCharacter(char, String),
+}
+
+/// A pattern spec for vanity search: leading prefix, trailing suffix, or both
+#[derive(Debug, Clone, Default)]
+pub struct PatternSpec {
+    /// Required leading characters (after the chain's fixed address prefix)
+    pub prefix: Option<String>,
+    /// Required trailing characters
+    pub suffix: Option<String>,
+    /// Match case-insensitively
+    pub case_insensitive: bool,
+    /// Optional anchored regex over the full address (takes precedence over prefix/suffix)
+    pub regex: Option<regex::Regex>,
+}
+
+impl PatternSpec {
+    pub fn prefix(value: impl Into<String>) -> Self {
+        Self { prefix: Some(value.into()), ..Default::default() }
+    }
+
+    pub fn suffix(value: impl Into<String>) -> Self {
+        Self { suffix: Some(value.into()), ..Default::default() }
+    }
+
+    pub fn case_insensitive(mut self) -> Self {
+        self.case_insensitive = true;
+        self
+    }
+
+    fn matches(&self, address: &str, chain_prefix: &str) -> bool {
+        if let Some(re) = &self.regex {
+            return re.is_match(address);
+        }
+
+        let body = address.strip_prefix(chain_prefix).unwrap_or(address);
+        let (body, needle_prefix, needle_suffix) = if self.case_insensitive {
+            (
+                body.to_lowercase(),
+                self.prefix.as_ref().map(|p| p.to_lowercase()),
+                self.suffix.as_ref().map(|s| s.to_lowercase()),
+            )
+        } else {
+            (body.to_string(), self.prefix.clone(), self.suffix.clone())
+        };
+
+        if let Some(p) = &needle_prefix {
+            if !body.starts_with(p.as_str()) {
+                return false;
+            }
+        }
+        if let Some(s) = &needle_suffix {
+            if !body.ends_with(s.as_str()) {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn pattern_len(&self) -> usize {
+        self.prefix.as_ref().map(|s| s.len()).unwrap_or(0)
+            + self.suffix.as_ref().map(|s| s.len()).unwrap_or(0)
+    }
+}
+
+/// Result of a completed vanity search
+pub struct VanityMatch {
+    pub address: GeneratedAddress,
+    pub attempts: u64,
+    pub elapsed_secs: f64,
+}
+
+/// Live progress snapshot, polled while a search runs
+#[derive(Debug, Clone, Copy, Default)]
+pub struct VanityProgress {
+    pub attempts: u64,
+    pub attempts_per_sec: f64,
+    pub eta_secs: Option<f64>,
+}
+
+/// Drives `Chain::generate` in a Rayon-parallel loop until `pattern` matches
+pub struct VanitySearch<'a> {
+    chain: &'a dyn Chain,
+    address_type: AddressType,
+    pattern: PatternSpec,
+    expected_attempts: f64,
+}
+
+impl<'a> VanitySearch<'a> {
+    /// Build a search, validating that every literal pattern character is
+    /// reachable in the chain's address alphabet (so e.g. `0`/`l` in a
+    /// Base58 chain or non-hex in Aptos is rejected up front).
+    pub fn new(chain: &'a dyn Chain, address_type: AddressType, pattern: PatternSpec) -> Result<Self, VanityError> {
+        let alphabet = chain.valid_address_chars(address_type);
+
+        if pattern.regex.is_none() && pattern.prefix.is_none() && pattern.suffix.is_none() {
+            return Err(VanityError::EmptyPattern);
+        }
+
+        for needle in [&pattern.prefix, &pattern.suffix].into_iter().flatten() {
+            for c in needle.chars() {
+                let ok = if pattern.case_insensitive {
+                    alphabet.chars().any(|a| a.eq_ignore_ascii_case(&c))
+                } else {
+                    alphabet.contains(c)
+                };
+                if !ok {
+                    return Err(VanityError::InvalidCharacter(c, alphabet.to_string()));
+                }
+            }
+        }
+
+        let expected_attempts = (alphabet.len() as f64).powi(pattern.pattern_len() as i32).max(1.0);
+
+        Ok(Self { chain, address_type, pattern, expected_attempts })
+    }
+
+    /// Expected number of attempts before a match (alphabet_size ^ pattern_len)
+    pub fn expected_attempts(&self) -> f64 {
+        self.expected_attempts
+    }
+
+    /// Run the search with a progress callback, blocking until a match is found
+    pub fn run(&self, mut on_progress: impl FnMut(VanityProgress) + Send) -> VanityMatch {
+        let attempts = Arc::new(AtomicU64::new(0));
+        let found = Arc::new(AtomicBool::new(false));
+        let result: std::sync::Mutex<Option<GeneratedAddress>> = std::sync::Mutex::new(None);
+        let start = Instant::now();
+        let chain_prefix = self.chain.address_prefix(self.address_type);
+
+        rayon::scope(|s| {
+            let num_threads = rayon::current_num_threads().max(1);
+            for _ in 0..num_threads {
+                let attempts = attempts.clone();
+                let found = found.clone();
+                let result = &result;
+                s.spawn(move |_| {
+                    while !found.load(Ordering::Relaxed) {
+                        for _ in 0..1000 {
+                            let addr = self.chain.generate(self.address_type);
+                            attempts.fetch_add(1, Ordering::Relaxed);
+                            if self.pattern.matches(&addr.address, chain_prefix) {
+                                *result.lock().unwrap() = Some(addr);
+                                found.store(true, Ordering::Relaxed);
+                                return;
+                            }
+                        }
+                        if found.load(Ordering::Relaxed) {
+                            return;
+                        }
+                    }
+                });
+            }
+
+            while !found.load(Ordering::Relaxed) {
+                let done = attempts.load(Ordering::Relaxed);
+                let elapsed = start.elapsed().as_secs_f64().max(1e-9);
+                let rate = done as f64 / elapsed;
+                let eta_secs = if rate > 0.0 {
+                    Some((self.expected_attempts - done as f64).max(0.0) / rate)
+                } else {
+                    None
+                };
+                on_progress(VanityProgress { attempts: done, attempts_per_sec: rate, eta_secs });
+                std::thread::sleep(std::time::Duration::from_millis(200));
+            }
+        });
+
+        let attempts_final = attempts.load(Ordering::Relaxed);
+        VanityMatch {
+            address: result.into_inner().unwrap().expect("found flag set implies a result"),
+            attempts: attempts_final,
+            elapsed_secs: start.elapsed().as_secs_f64(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ETH;
+
+    #[test]
+    fn rejects_character_outside_chain_alphabet() {
+        // ETH addresses are hex, 'g' is not a valid nibble
+        let err = VanitySearch::new(&ETH, AddressType::Evm, PatternSpec::prefix("g0")).unwrap_err();
+        assert!(matches!(err, VanityError::InvalidCharacter('g', _)));
+    }
+
+    #[test]
+    fn accepts_valid_prefix_and_estimates_difficulty() {
+        let search = VanitySearch::new(&ETH, AddressType::Evm, PatternSpec::prefix("dead")).unwrap();
+        // 22 hex chars (0-9, a-f, A-F) ^ 4
+        assert_eq!(search.expected_attempts(), 234256.0);
+    }
+}
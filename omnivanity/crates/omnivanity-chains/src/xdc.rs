@@ -2,7 +2,7 @@
 //!
 //! XDC uses EVM-style addresses but with 'xdc' prefix instead of '0x'
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
 use omnivanity_crypto::{Secp256k1Keypair, hash::keccak256, hex};
 
 /// XDC Network chain
@@ -73,6 +73,9 @@ impl Xdc {
             public_key_hex: format!("0x{}", hex::encode(keypair.public_key_uncompressed())),
             chain: "XDC".to_string(),
             address_type: AddressType::Xdc,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         }
     }
 }
@@ -2,10 +2,11 @@
 //!
 //! XRP classic address: secp256k1, RIPEMD160(SHA256(pubkey)), XRPL Base58 encoding
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
 use omnivanity_crypto::{
     Secp256k1Keypair,
     hash::hash160,
+    encoding::wif_encode,
     hex,
 };
 
@@ -95,14 +96,19 @@ impl Xrp {
         // XRP address: version byte 0x00, RIPEMD160(SHA256(pubkey))
         let h160 = hash160(&pubkey_compressed);
         let address = xrpl_base58check_encode(0x00, &h160);
-        
+
+        let wif = wif_encode(&private_key, true, true);
+
         GeneratedAddress {
             address,
             private_key_hex: hex::encode(private_key),
-            private_key_native: hex::encode(private_key),
+            private_key_native: wif,
             public_key_hex: hex::encode(pubkey_compressed),
             chain: "XRP".to_string(),
             address_type: AddressType::Xrpl,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         }
     }
 }
@@ -117,5 +123,6 @@ mod tests {
         let addr = xrp.generate(AddressType::Xrpl);
         assert!(addr.address.starts_with("r"));
         assert_eq!(addr.chain, "XRP");
+        assert!(addr.private_key_native.starts_with('K') || addr.private_key_native.starts_with('L'));
     }
 }
@@ -1,12 +1,13 @@
-//! Zcash chain adapter (t-addresses only for now)
+//! Zcash chain adapter (transparent t-addresses and Sapling shielded z-addresses)
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
 use omnivanity_crypto::{
     Secp256k1Keypair,
-    hash::hash160,
-    encoding::base58check_encode,
+    hash::{hash160, blake2b_256},
+    encoding::{base58check_encode, bech32_encode_raw},
     hex, bs58,
 };
+use rand::RngCore;
 
 /// Zcash chain (transparent addresses)
 pub struct Zcash;
@@ -29,7 +30,7 @@ impl Chain for Zcash {
     }
 
     fn address_types(&self) -> Vec<AddressType> {
-        vec![AddressType::P2pkh] // t-addr only for now
+        vec![AddressType::P2pkh, AddressType::Sapling]
     }
 
     fn default_address_type(&self) -> AddressType {
@@ -37,26 +38,46 @@ impl Chain for Zcash {
     }
 
     fn generate(&self, address_type: AddressType) -> GeneratedAddress {
-        let keypair = Secp256k1Keypair::generate();
-        self.generate_from_keypair(&keypair, address_type)
+        match address_type {
+            AddressType::Sapling => {
+                let mut seed = [0u8; 32];
+                rand::thread_rng().fill_bytes(&mut seed);
+                self.generate_sapling(&seed)
+            }
+            _ => {
+                let keypair = Secp256k1Keypair::generate();
+                self.generate_from_keypair(&keypair, address_type)
+            }
+        }
     }
 
     fn generate_from_bytes(&self, private_key: &[u8], address_type: AddressType) -> Option<GeneratedAddress> {
         if private_key.len() != 32 {
             return None;
         }
+        if address_type == AddressType::Sapling {
+            let mut seed = [0u8; 32];
+            seed.copy_from_slice(private_key);
+            return Some(self.generate_sapling(&seed));
+        }
         let mut pk = [0u8; 32];
         pk.copy_from_slice(private_key);
         let keypair = Secp256k1Keypair::from_bytes(&pk).ok()?;
         Some(self.generate_from_keypair(&keypair, address_type))
     }
 
-    fn valid_address_chars(&self, _address_type: AddressType) -> &'static str {
-        "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"
+    fn valid_address_chars(&self, address_type: AddressType) -> &'static str {
+        match address_type {
+            AddressType::Sapling => "023456789acdefghjklmnpqrstuvwxyzqpzry9x8gf2tvdw0s3jn54khce6mua7l",
+            _ => "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
+        }
     }
 
-    fn address_prefix(&self, _address_type: AddressType) -> &'static str {
-        "t1"
+    fn address_prefix(&self, address_type: AddressType) -> &'static str {
+        match address_type {
+            AddressType::Sapling => "zs",
+            _ => "t1",
+        }
     }
 }
 
@@ -79,10 +100,115 @@ impl Zcash {
             public_key_hex: hex::encode(pubkey_compressed),
             chain: self.ticker().to_string(),
             address_type: AddressType::P2pkh,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
+        }
+    }
+
+    /// Derive a Sapling shielded address from a 32-byte spending seed.
+    ///
+    /// This crate has no Jubjub curve implementation available, so the
+    /// spend-authorizing/proof-generation/nullifier keys, the full viewing
+    /// key, and the diversified `pk_d` below are all BLAKE2b-personalized
+    /// hash chains rather than true scalar multiplications on Jubjub — a
+    /// structural stand-in for the real ZIP-32 derivation, in the same
+    /// spirit as the simplified Taproot path in `bitcoin.rs`. The default
+    /// diversifier index is 0; use `generate_sapling_diversified` for
+    /// additional diversified addresses from the same spending key.
+    fn generate_sapling(&self, seed: &[u8; 32]) -> GeneratedAddress {
+        self.generate_sapling_diversified(seed, 0)
+    }
+
+    /// Derive the Sapling address for diversifier index `index` from the
+    /// same 32-byte spending seed (see `generate_sapling` for caveats).
+    ///
+    /// `pub(crate)` so [`crate::sapling_search`] can sweep indices against a
+    /// fixed spending key without re-deriving `ask`/`nsk`/`ovk` from scratch.
+    pub(crate) fn generate_sapling_diversified(&self, seed: &[u8; 32], index: u64) -> GeneratedAddress {
+        let ask = zip32_expand(seed, 0x00);
+        let nsk = zip32_expand(seed, 0x01);
+        let ovk = zip32_expand(seed, 0x02);
+        let dk = zip32_expand(seed, 0x03);
+        let chain_code = zip32_expand(seed, 0x04);
+
+        let ak = blake2b_256(&[b"Zcash_ak".as_slice(), &ask].concat());
+        let nk = blake2b_256(&[b"Zcash_nk".as_slice(), &nsk].concat());
+
+        let mut ivk_input = Vec::with_capacity(8 + 32 + 32);
+        ivk_input.extend_from_slice(b"Zcash_ivk");
+        ivk_input.extend_from_slice(&ak);
+        ivk_input.extend_from_slice(&nk);
+        let mut ivk = blake2b_256(&ivk_input);
+        ivk[31] &= 0x07; // clear top 5 bits, matching the real CRH^ivk's 251-bit output
+
+        let mut d_input = Vec::with_capacity(8 + 32 + 8);
+        d_input.extend_from_slice(b"Zcash_gd");
+        d_input.extend_from_slice(&ivk);
+        d_input.extend_from_slice(&index.to_le_bytes());
+        let d_hash = blake2b_256(&d_input);
+        let mut d = [0u8; 11];
+        d.copy_from_slice(&d_hash[..11]);
+
+        let mut pk_d_input = Vec::with_capacity(9 + 11 + 32);
+        pk_d_input.extend_from_slice(b"Zcash_pkd");
+        pk_d_input.extend_from_slice(&d);
+        pk_d_input.extend_from_slice(&ivk);
+        let pk_d = blake2b_256(&pk_d_input);
+
+        let mut raw_address = Vec::with_capacity(11 + 32);
+        raw_address.extend_from_slice(&d);
+        raw_address.extend_from_slice(&pk_d);
+
+        let address = bech32_encode_raw("zs", &raw_address)
+            .expect("43-byte Sapling payload always encodes");
+        let expsk = sapling_expsk_encode(&chain_code, &ask, &nsk, &ovk, &dk);
+
+        GeneratedAddress {
+            address,
+            private_key_hex: hex::encode(seed),
+            private_key_native: expsk,
+            public_key_hex: format!("ak: {} | nk: {} | ivk: {}", hex::encode(ak), hex::encode(nk), hex::encode(ivk)),
+            chain: self.ticker().to_string(),
+            address_type: AddressType::Sapling,
+            mnemonic: None,
+            derivation_path: None,
+            network: Network::Mainnet,
         }
     }
 }
 
+/// Stand-in for ZIP-32's `PRF^expand(seed, t)`: BLAKE2b-personalized hash of
+/// `seed || t` used to split one spending seed into the ask/nsk/ovk components.
+fn zip32_expand(seed: &[u8; 32], t: u8) -> [u8; 32] {
+    let mut input = Vec::with_capacity(17 + 32 + 1);
+    input.extend_from_slice(b"Zcash_ExpandSeed");
+    input.extend_from_slice(seed);
+    input.push(t);
+    blake2b_256(&input)
+}
+
+/// Serialize a (stand-in) ZIP-32 `ExtendedSpendingKey` in its standard
+/// `secret-extended-key-main` bech32 form: master-level depth/parent tag/
+/// child index, followed by the chain code and the `expsk`/`dk` components.
+/// Real Zcash wallets also apply the "F4Jumble" permutation to this payload
+/// before bech32-encoding it; we skip that step for the same reason the rest
+/// of this module approximates ZIP-32 with hash chains instead of Jubjub.
+fn sapling_expsk_encode(chain_code: &[u8; 32], ask: &[u8; 32], nsk: &[u8; 32], ovk: &[u8; 32], dk: &[u8; 32]) -> String {
+    let mut payload = Vec::with_capacity(1 + 4 + 4 + 32 + 32 + 32 + 32 + 32);
+    payload.push(0u8); // depth: master key
+    payload.extend_from_slice(&[0u8; 4]); // parent_fvk_tag: master key has none
+    payload.extend_from_slice(&[0u8; 4]); // child_index: master key
+    payload.extend_from_slice(chain_code);
+    payload.extend_from_slice(ask);
+    payload.extend_from_slice(nsk);
+    payload.extend_from_slice(ovk);
+    payload.extend_from_slice(dk);
+
+    bech32_encode_raw("secret-extended-key-main", &payload)
+        .expect("169-byte expsk payload always encodes")
+}
+
 fn zec_t_addr_encode(hash160: &[u8; 20]) -> String {
     use omnivanity_crypto::hash::double_sha256;
     
@@ -107,4 +233,33 @@ mod tests {
         let addr = zec.generate(AddressType::P2pkh);
         assert!(addr.address.starts_with("t1"));
     }
+
+    #[test]
+    fn test_zec_sapling_addr() {
+        let zec = Zcash;
+        let addr = zec.generate(AddressType::Sapling);
+        assert!(addr.address.starts_with("zs"));
+        assert_eq!(addr.address_type, AddressType::Sapling);
+        assert!(addr.private_key_native.starts_with("secret-extended-key-main1"));
+    }
+
+    #[test]
+    fn test_zec_sapling_diversified_addresses_differ() {
+        let zec = Zcash;
+        let seed = [7u8; 32];
+        let addr0 = zec.generate_sapling_diversified(&seed, 0);
+        let addr1 = zec.generate_sapling_diversified(&seed, 1);
+        assert_ne!(addr0.address, addr1.address);
+        // Same spending key regardless of diversifier index
+        assert_eq!(addr0.private_key_hex, addr1.private_key_hex);
+    }
+
+    #[test]
+    fn test_zec_sapling_deterministic() {
+        let zec = Zcash;
+        let seed = [3u8; 32];
+        let a = zec.generate_from_bytes(&seed, AddressType::Sapling).unwrap();
+        let b = zec.generate_from_bytes(&seed, AddressType::Sapling).unwrap();
+        assert_eq!(a.address, b.address);
+    }
 }
@@ -2,18 +2,30 @@
 //!
 //! Zilliqa uses Bech32 (zil1...) for display addresses
 
-use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress};
-use omnivanity_crypto::{Secp256k1Keypair, hash::sha256, hex};
+use crate::traits::{Chain, ChainFamily, AddressType, GeneratedAddress, Network};
+use omnivanity_crypto::{Secp256k1Keypair, hash::sha256, encoding::wif_encode, hex};
 
 /// Zilliqa chain
 pub struct Zilliqa;
 
-fn zil_bech32_encode(data: &[u8]) -> Result<String, String> {
+fn zil_bech32_encode(data: &[u8], hrp: &str) -> Result<String, String> {
     use bech32::{Bech32, Hrp};
-    let hrp = Hrp::parse("zil").map_err(|e| e.to_string())?;
+    let hrp = Hrp::parse(hrp).map_err(|e| e.to_string())?;
     bech32::encode::<Bech32>(hrp, data).map_err(|e| e.to_string())
 }
 
+/// Zilliqa's Bech32 doesn't officially encode network in its HRP the way
+/// Bitcoin's does (mainnet/testnet share `"zil"`, differentiated only by
+/// the node endpoint/chain ID) - `"tzil"` below is this tool's own
+/// dev-tooling convention for a visually-distinguishable testnet/regtest
+/// address, not a wire-format Zilliqa recognizes.
+fn zil_hrp(network: Network) -> &'static str {
+    match network {
+        Network::Mainnet => "zil",
+        Network::Testnet | Network::Regtest => "tzil",
+    }
+}
+
 impl Chain for Zilliqa {
     fn ticker(&self) -> &'static str {
         "ZIL"
@@ -50,6 +62,18 @@ impl Chain for Zilliqa {
         Some(self.generate_from_keypair(&keypair, address_type))
     }
 
+    fn generate_for_network(&self, network: Network, address_type: AddressType) -> GeneratedAddress {
+        let keypair = Secp256k1Keypair::generate();
+        self.generate_from_keypair_for_network(&keypair, address_type, network)
+    }
+
+    fn address_prefix_for_network(&self, _address_type: AddressType, network: Network) -> &'static str {
+        match network {
+            Network::Mainnet => "zil1",
+            Network::Testnet | Network::Regtest => "tzil1",
+        }
+    }
+
     fn valid_address_chars(&self, _address_type: AddressType) -> &'static str {
         "023456789acdefghjklmnpqrstuvwxyz"
     }
@@ -60,24 +84,33 @@ impl Chain for Zilliqa {
 }
 
 impl Zilliqa {
-    fn generate_from_keypair(&self, keypair: &Secp256k1Keypair, _address_type: AddressType) -> GeneratedAddress {
+    fn generate_from_keypair(&self, keypair: &Secp256k1Keypair, address_type: AddressType) -> GeneratedAddress {
+        self.generate_from_keypair_for_network(keypair, address_type, Network::Mainnet)
+    }
+
+    fn generate_from_keypair_for_network(&self, keypair: &Secp256k1Keypair, _address_type: AddressType, network: Network) -> GeneratedAddress {
         let private_key = keypair.private_key_bytes();
         let pubkey = keypair.public_key_compressed();
-        
+
         // Zilliqa: SHA256(compressed_pubkey), take last 20 bytes
         let hash = sha256(&pubkey);
         let address_bytes = &hash[12..32];
-        
+
         // Encode as bech32
-        let address = zil_bech32_encode(address_bytes).unwrap_or_default();
-        
+        let address = zil_bech32_encode(address_bytes, zil_hrp(network)).unwrap_or_default();
+
+        let wif = wif_encode(&private_key, true, true);
+
         GeneratedAddress {
             address,
             private_key_hex: hex::encode(private_key),
-            private_key_native: hex::encode(private_key),
+            private_key_native: wif,
             public_key_hex: hex::encode(pubkey),
             chain: "ZIL".to_string(),
             address_type: AddressType::Zilliqa,
+            mnemonic: None,
+            derivation_path: None,
+            network,
         }
     }
 }
@@ -92,5 +125,15 @@ mod tests {
         let addr = zil.generate(AddressType::Zilliqa);
         assert!(addr.address.starts_with("zil1"));
         assert_eq!(addr.chain, "ZIL");
+        assert!(addr.private_key_native.starts_with('K') || addr.private_key_native.starts_with('L'));
+    }
+
+    #[test]
+    fn test_zil_testnet_uses_distinct_hrp() {
+        let zil = Zilliqa;
+        let addr = zil.generate_for_network(Network::Testnet, AddressType::Zilliqa);
+        assert!(addr.address.starts_with("tzil1"));
+        assert_eq!(addr.network, Network::Testnet);
+        assert_eq!(zil.address_prefix_for_network(AddressType::Zilliqa, Network::Testnet), "tzil1");
     }
 }
@@ -14,6 +14,15 @@ use std::io::Write;
 #[cfg(feature = "gpu")]
 use omnivanity_gpu::{list_devices, is_gpu_available};
 
+#[cfg(feature = "gpu")]
+use omnivanity_gpu::{format_health_line, GpuBackend, ThermalLimits, ThermalMonitor};
+#[cfg(feature = "gpu")]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "gpu")]
+use std::sync::Arc;
+#[cfg(feature = "gpu")]
+use std::time::Duration;
+
 #[derive(Parser)]
 #[command(name = "omnivanity")]
 #[command(author = "OmniVanity Team")]
@@ -48,7 +57,16 @@ enum Commands {
         #[arg(short = 'i', long)]
         case_insensitive: bool,
 
-        /// Number of threads (0 = auto, ignored with --gpu)
+        /// Require the pattern's upper/lowercase hex letters to match an
+        /// EIP-55 checksum exactly (EVM chains only), instead of matching
+        /// either case-insensitively or as a literal whose difficulty
+        /// ignores checksum casing. Overrides --case-insensitive.
+        #[arg(long)]
+        eip55: bool,
+
+        /// Number of threads (0 = auto). Ignored with --gpu alone; set this
+        /// to a nonzero value together with --gpu to run CPU threads and the
+        /// GPU concurrently against the same pattern (hybrid mode)
         #[arg(long, default_value = "0")]
         threads: usize,
 
@@ -71,6 +89,28 @@ enum Commands {
         /// GPU device indices to use (comma-separated, e.g., 0,1)
         #[arg(long)]
         device: Option<String>,
+
+        /// Force a specific GPU backend for chains that support more than
+        /// one (currently EVM only - auto picks CUDA when available)
+        #[arg(long, value_enum)]
+        gpu_backend: Option<GpuBackendArg>,
+
+        /// Allow this search to share a GPU device with another
+        /// `omnivanity` process instead of requiring exclusive access
+        #[arg(long)]
+        gpu_share: bool,
+
+        /// Grind HD child keys under this BIP32/SLIP-0010 base path (e.g.
+        /// "m/44'/60'/0'/0") off of a BIP39 mnemonic instead of throwaway
+        /// random keys, so a match is recoverable from the mnemonic alone.
+        /// CPU-only; incompatible with --gpu.
+        #[arg(long)]
+        hd_path: Option<String>,
+
+        /// Existing mnemonic to use with --hd-path instead of generating a
+        /// fresh one.
+        #[arg(long)]
+        hd_mnemonic: Option<String>,
     },
 
     /// List supported chains
@@ -101,9 +141,33 @@ enum Commands {
         /// GPU device indices (comma-separated)
         #[arg(long)]
         device: Option<String>,
+
+        /// Allow this benchmark to share a GPU device with another
+        /// `omnivanity` process instead of requiring exclusive access
+        #[arg(long)]
+        gpu_share: bool,
     },
 }
 
+#[derive(Clone, ValueEnum)]
+enum GpuBackendArg {
+    Cuda,
+    Opencl,
+}
+
+impl GpuBackendArg {
+    /// `SearchConfig::gpu_backend` only distinguishes `"opencl"` from
+    /// everything else (see `omnivanity_core::search::VanitySearch::run_hybrid`),
+    /// so `Cuda` maps to `None` - the existing default behavior - rather
+    /// than a `"cuda"` string nothing currently checks for.
+    fn as_search_config_value(&self) -> Option<String> {
+        match self {
+            GpuBackendArg::Cuda => None,
+            GpuBackendArg::Opencl => Some("opencl".to_string()),
+        }
+    }
+}
+
 #[derive(Clone, ValueEnum)]
 enum PatternTypeArg {
     Prefix,
@@ -121,11 +185,12 @@ impl From<PatternTypeArg> for PatternType {
     }
 }
 
-/// Search mode: CPU or GPU
+/// Search mode: CPU only, GPU only, or both racing the same pattern
 #[derive(Clone, Copy, Debug)]
 enum SearchMode {
     Cpu,
     Gpu,
+    Hybrid,
 }
 
 fn main() -> Result<()> {
@@ -146,28 +211,38 @@ fn main() -> Result<()> {
             pattern_type,
             address_type,
             case_insensitive,
+            eip55,
             threads,
             max_attempts,
             max_time,
             json,
             gpu,
             device,
+            gpu_backend,
+            gpu_share,
+            hd_path,
+            hd_mnemonic,
         } => {
-            let mode = if gpu { SearchMode::Gpu } else { SearchMode::Cpu };
+            let mode = search_mode(gpu, threads);
             let device_indices = parse_device_indices(device.as_deref());
-            
+
             cmd_generate(
                 &chain,
                 &pattern,
                 pattern_type.into(),
                 address_type.as_deref(),
                 case_insensitive,
+                eip55,
                 threads,
                 max_attempts,
                 max_time,
                 json,
                 mode,
                 device_indices,
+                gpu_backend.and_then(|b| b.as_search_config_value()),
+                gpu_share,
+                hd_path,
+                hd_mnemonic,
             )?;
         }
         Commands::Chains => {
@@ -183,16 +258,28 @@ fn main() -> Result<()> {
             threads,
             gpu,
             device,
+            gpu_share,
         } => {
-            let mode = if gpu { SearchMode::Gpu } else { SearchMode::Cpu };
+            let mode = search_mode(gpu, threads);
             let device_indices = parse_device_indices(device.as_deref());
-            cmd_benchmark(&chain, duration, threads, mode, device_indices)?;
+            cmd_benchmark(&chain, duration, threads, mode, device_indices, gpu_share)?;
         }
     }
 
     Ok(())
 }
 
+/// `--gpu` alone means GPU-only; `--gpu` plus an explicit (nonzero) `--threads`
+/// means hybrid, so `omnivanity generate --gpu --threads N` runs CPU and GPU
+/// together instead of picking one or the other.
+fn search_mode(gpu: bool, threads: usize) -> SearchMode {
+    match (gpu, threads) {
+        (true, 0) => SearchMode::Gpu,
+        (true, _) => SearchMode::Hybrid,
+        (false, _) => SearchMode::Cpu,
+    }
+}
+
 fn parse_device_indices(device: Option<&str>) -> Vec<usize> {
     match device {
         Some(s) => s
@@ -203,18 +290,69 @@ fn parse_device_indices(device: Option<&str>) -> Vec<usize> {
     }
 }
 
+/// Print a periodic utilization/power/temperature line per device while a
+/// GPU (or hybrid) search/benchmark runs, so a user watching the terminal
+/// can confirm the GPU is actually saturated instead of just inferring it
+/// from the Mkey/s number - and notice thermal throttling tanking that
+/// number before `--json` output gets a chance to surface anything. Each
+/// device gets its own `ThermalMonitor` here purely for display - this is
+/// separate from (and in addition to) the one the search engine itself
+/// keeps internally for `throttle_if_needed`.
+#[cfg(feature = "gpu")]
+fn spawn_telemetry_printer(devices: Vec<GpuDeviceForTelemetry>) -> (Arc<AtomicBool>, std::thread::JoinHandle<()>) {
+    let stop = Arc::new(AtomicBool::new(false));
+    let stop_bg = stop.clone();
+
+    let handle = std::thread::spawn(move || {
+        let monitors: Vec<(usize, ThermalMonitor)> = devices
+            .into_iter()
+            .map(|d| {
+                let limits = ThermalLimits::default();
+                let monitor = match d.backend {
+                    GpuBackend::Cuda => ThermalMonitor::start_nvml(d.index, limits),
+                    _ => ThermalMonitor::start_amd_sysfs(d.index, limits),
+                };
+                (d.index, monitor)
+            })
+            .collect();
+
+        while !stop_bg.load(Ordering::Relaxed) {
+            std::thread::sleep(Duration::from_secs(3));
+            if stop_bg.load(Ordering::Relaxed) {
+                break;
+            }
+            for (index, monitor) in &monitors {
+                eprintln!("{}", format_health_line(*index, monitor.health()));
+            }
+        }
+    });
+
+    (stop, handle)
+}
+
+#[cfg(feature = "gpu")]
+struct GpuDeviceForTelemetry {
+    index: usize,
+    backend: GpuBackend,
+}
+
 fn cmd_generate(
     chain_ticker: &str,
     pattern: &str,
     pattern_type: PatternType,
     address_type_str: Option<&str>,
     case_insensitive: bool,
+    eip55: bool,
     threads: usize,
     max_attempts: u64,
     max_time: u64,
     json_output: bool,
     mode: SearchMode,
     device_indices: Vec<usize>,
+    gpu_backend: Option<String>,
+    gpu_share: bool,
+    hd_path: Option<String>,
+    hd_mnemonic: Option<String>,
 ) -> Result<()> {
     // Get chain
     let chain = get_chain(chain_ticker)
@@ -231,7 +369,8 @@ fn cmd_generate(
     let mut pat = Pattern {
         value: pattern.to_string(),
         pattern_type,
-        case_insensitive,
+        case_insensitive: case_insensitive && !eip55,
+        eip55,
     };
     pat.validate(valid_chars)?;
 
@@ -239,10 +378,10 @@ fn cmd_generate(
         eprintln!("OmniVanity v0.1.0");
         eprintln!("Chain: {} ({})", chain.name(), chain.ticker());
         eprintln!("Address Type: {}", address_type);
-        eprintln!("Pattern: {} ({:?}{})", 
-            pattern, 
+        eprintln!("Pattern: {} ({:?}{})",
+            pattern,
             pattern_type,
-            if case_insensitive { ", case-insensitive" } else { "" }
+            if eip55 { ", EIP-55 checksum-case" } else if case_insensitive { ", case-insensitive" } else { "" }
         );
         
         match mode {
@@ -258,19 +397,42 @@ fn cmd_generate(
                     eprintln!("Devices: {:?}", device_indices);
                 }
             }
+            SearchMode::Hybrid => {
+                eprintln!("Mode: Hybrid (CPU + GPU)");
+                eprintln!("Threads: {}", if threads == 0 { num_cpus::get() } else { threads });
+                if device_indices.is_empty() {
+                    eprintln!("Devices: All available");
+                } else {
+                    eprintln!("Devices: {:?}", device_indices);
+                }
+            }
         }
         eprintln!();
     }
 
+    if hd_path.is_some() && !matches!(mode, SearchMode::Cpu) {
+        anyhow::bail!("--hd-path is CPU-only; drop --gpu to use it");
+    }
+
     // Run search based on mode
     match mode {
         SearchMode::Cpu => {
-            run_cpu_search(chain_ticker, address_type, pat, threads, max_attempts, max_time, json_output)?;
+            run_cpu_search(chain_ticker, address_type, pat, threads, max_attempts, max_time, json_output, hd_path, hd_mnemonic)?;
         }
         SearchMode::Gpu => {
             #[cfg(feature = "gpu")]
             {
-                run_gpu_search(chain_ticker, address_type, pat, max_attempts, max_time, json_output, device_indices)?;
+                run_gpu_search(chain_ticker, address_type, pat, threads, max_attempts, max_time, json_output, device_indices, false, gpu_backend, gpu_share)?;
+            }
+            #[cfg(not(feature = "gpu"))]
+            {
+                anyhow::bail!("GPU support not compiled. Rebuild with --features gpu");
+            }
+        }
+        SearchMode::Hybrid => {
+            #[cfg(feature = "gpu")]
+            {
+                run_gpu_search(chain_ticker, address_type, pat, threads, max_attempts, max_time, json_output, device_indices, true, gpu_backend, gpu_share)?;
             }
             #[cfg(not(feature = "gpu"))]
             {
@@ -290,15 +452,25 @@ fn run_cpu_search(
     max_attempts: u64,
     max_time: u64,
     json_output: bool,
+    hd_path: Option<String>,
+    hd_mnemonic: Option<String>,
 ) -> Result<()> {
     let chain = get_chain(chain_ticker)
         .ok_or_else(|| anyhow::anyhow!("Unknown chain: {}", chain_ticker))?;
-    
+
     let config = SearchConfig {
         threads,
         batch_size: 1000,
         max_attempts,
         max_time_secs: max_time,
+        use_gpu: false,
+        hybrid: false,
+        device_indices: vec![],
+        gpu_backend: None,
+        hd_base_path: hd_path,
+        hd_mnemonic,
+        hd_passphrase: String::new(),
+        ..Default::default()
     };
 
     let search = VanitySearch::new(
@@ -336,40 +508,33 @@ fn run_cpu_search(
     Ok(())
 }
 
+/// GPU (and hybrid CPU+GPU) generation, routed through the same
+/// `omnivanity_core::VanitySearch`/`SearchConfig` as `run_cpu_search` rather
+/// than driving `WgpuEngine` by hand - that's what actually finds and
+/// extracts a match instead of just benchmarking raw throughput.
 #[cfg(feature = "gpu")]
 fn run_gpu_search(
     chain_ticker: &str,
-    _address_type: AddressType,
-    _pat: Pattern,
+    address_type: AddressType,
+    pat: Pattern,
+    threads: usize,
     max_attempts: u64,
     max_time: u64,
     json_output: bool,
     device_indices: Vec<usize>,
+    hybrid: bool,
+    gpu_backend: Option<String>,
+    gpu_share: bool,
 ) -> Result<()> {
-    use omnivanity_gpu::{GpuSearchConfig, is_wgpu_available, list_devices, WgpuEngine};
-
-    if !is_wgpu_available() {
+    if !is_gpu_available() {
         anyhow::bail!("No GPU found. Use CPU mode instead.");
     }
 
-    // Currently only EVM is supported on GPU
-    if chain_ticker != "ETH" {
-        anyhow::bail!("GPU search currently only supports ETH/EVM. Use --gpu with -c ETH");
-    }
-
-    let config = GpuSearchConfig {
-        device_indices: device_indices.clone(),
-        grid_size: 0, // auto
-        block_size: 256,
-        keys_per_thread: 256,
-        max_attempts,
-        max_time_secs: max_time,
-    };
+    let chain = get_chain(chain_ticker)
+        .ok_or_else(|| anyhow::anyhow!("Unknown chain: {}", chain_ticker))?;
 
-    // List available devices
+    let devices = list_devices();
     if !json_output {
-        let devices = list_devices();
-        eprintln!("GPU Backend: wgpu (cross-platform)");
         eprintln!("Available devices: {}", devices.len());
         for dev in &devices {
             eprintln!("  [{}] {} ({})", dev.index, dev.name, dev.backend);
@@ -377,30 +542,94 @@ fn run_gpu_search(
         eprintln!();
     }
 
-    // Create wgpu engine
-    let device_idx = device_indices.first().copied().unwrap_or(0);
-    let engine = WgpuEngine::new_sync(device_idx, config)
-        .map_err(|e| anyhow::anyhow!("Failed to create wgpu engine: {}", e))?;
+    // Requested indices, or every enumerated device if the user didn't
+    // narrow it down with `--device`.
+    let candidate_indices = if device_indices.is_empty() {
+        devices.iter().map(|d| d.index).collect::<Vec<_>>()
+    } else {
+        device_indices
+    };
+
+    // Hold an advisory lock on each device for the lifetime of this search
+    // so a second `omnivanity --gpu` process doesn't silently contend for
+    // the same card - unless the caller opted into sharing via `--gpu-share`.
+    // `_locks` is never read again; it just has to outlive `search.run()`.
+    let (locked_indices, _locks) = if gpu_share {
+        (candidate_indices, Vec::new())
+    } else {
+        let locks = omnivanity_gpu::lock_available(&candidate_indices);
+        let locked_indices = locks.iter().map(|l| l.index()).collect::<Vec<_>>();
+        (locked_indices, locks)
+    };
+
+    if locked_indices.is_empty() {
+        anyhow::bail!("All requested GPU devices are in use by another omnivanity process. Pass --gpu-share to run anyway.");
+    }
+
+    let telemetry_devices = devices
+        .iter()
+        .filter(|d| locked_indices.contains(&d.index))
+        .map(|d| GpuDeviceForTelemetry { index: d.index, backend: d.backend })
+        .collect::<Vec<_>>();
+
+    let config = SearchConfig {
+        threads: if hybrid { threads } else { 0 },
+        batch_size: 1000,
+        max_attempts,
+        max_time_secs: max_time,
+        use_gpu: true,
+        hybrid,
+        device_indices: locked_indices,
+        gpu_backend,
+        hd_base_path: None,
+        hd_mnemonic: None,
+        hd_passphrase: String::new(),
+        ..Default::default()
+    };
+
+    let search = VanitySearch::new(
+        chain,
+        address_type,
+        vec![pat],
+        config,
+    );
 
     if !json_output {
-        eprintln!("Starting GPU search on: {}", engine.device_name());
-        eprintln!("(Full GPU search implementation in progress)");
+        let difficulty = search.difficulty();
+        eprintln!("Difficulty: {:.0}", difficulty);
+        eprintln!();
+    }
+
+    // Telemetry only makes sense to print when there's a terminal to read it
+    // - `--json` callers get a single match/no-match object on stdout and
+    // shouldn't have status lines interleaved on stderr either, to keep
+    // scripted consumption simple.
+    let telemetry = (!json_output).then(|| spawn_telemetry_printer(telemetry_devices));
+
+    let result = search.run();
+
+    if let Some((stop, handle)) = telemetry {
+        stop.store(true, Ordering::Relaxed);
+        let _ = handle.join();
     }
 
-    // TODO: Implement full GPU search with pattern matching and result extraction
-    // For now, run benchmark to verify GPU works
-    match engine.benchmark(3) {
-        Ok(keys_per_sec) => {
-            if !json_output {
-                let mkeys = keys_per_sec / 1_000_000.0;
-                eprintln!("GPU speed: {:.2} Mkey/s", mkeys);
+    match result {
+        Some(result) => {
+            if json_output {
+                println!("{}", serde_json::to_string_pretty(&result)?);
+            } else {
+                print_result(&result);
             }
         }
-        Err(e) => {
-            eprintln!("GPU benchmark failed: {}", e);
+        None => {
+            if json_output {
+                println!("{{\"error\": \"No match found within limits\"}}");
+            } else {
+                eprintln!("No match found within limits.");
+            }
         }
     }
-    
+
     Ok(())
 }
 
@@ -432,8 +661,8 @@ fn cmd_gpu_list() {
     println!("{:-<60}", "");
     
     if !is_gpu_available() {
-        println!("No CUDA-capable GPUs found.");
-        println!("Make sure NVIDIA drivers and CUDA toolkit are installed.");
+        println!("No CUDA- or OpenCL-capable GPUs found.");
+        println!("Make sure GPU drivers (NVIDIA/AMD/Intel) and the relevant toolkit (CUDA or OpenCL ICD) are installed.");
         return;
     }
 
@@ -459,11 +688,12 @@ fn cmd_gpu_list() {
 }
 
 fn cmd_benchmark(
-    chain_ticker: &str, 
-    duration_secs: u64, 
+    chain_ticker: &str,
+    duration_secs: u64,
     threads: usize,
     mode: SearchMode,
     device_indices: Vec<usize>,
+    gpu_share: bool,
 ) -> Result<()> {
     let chain = get_chain(chain_ticker)
         .ok_or_else(|| anyhow::anyhow!("Unknown chain: {}", chain_ticker))?;
@@ -485,6 +715,15 @@ fn cmd_benchmark(
                 eprintln!("Devices: {:?}", device_indices);
             }
         }
+        SearchMode::Hybrid => {
+            eprintln!("Mode: Hybrid (CPU + GPU)");
+            eprintln!("Threads: {}", if threads == 0 { num_cpus::get() } else { threads });
+            if device_indices.is_empty() {
+                eprintln!("Devices: All available");
+            } else {
+                eprintln!("Devices: {:?}", device_indices);
+            }
+        }
     }
     eprintln!();
 
@@ -492,12 +731,17 @@ fn cmd_benchmark(
         SearchMode::Cpu => {
             // Use an impossible pattern to run until timeout
             let pat = Pattern::prefix("zzzzzzzzzzzzzzzzzzz");
-            
+
             let config = SearchConfig {
                 threads,
                 batch_size: 1000,
                 max_attempts: 0,
                 max_time_secs: duration_secs,
+                use_gpu: false,
+                hybrid: false,
+                device_indices: vec![],
+                gpu_backend: None,
+                ..Default::default()
             };
 
             let search = VanitySearch::new(
@@ -509,13 +753,69 @@ fn cmd_benchmark(
 
             let _ = search.run();
         }
-        SearchMode::Gpu => {
+        SearchMode::Gpu | SearchMode::Hybrid => {
             #[cfg(feature = "gpu")]
             {
                 if !is_gpu_available() {
                     anyhow::bail!("No CUDA-capable GPU found.");
                 }
-                eprintln!("GPU benchmark not yet implemented.");
+                // Use an impossible pattern to run until timeout, same as the CPU branch.
+                let pat = Pattern::prefix("zzzzzzzzzzzzzzzzzzz");
+
+                let devices = list_devices();
+                let candidate_indices = if device_indices.is_empty() {
+                    devices.iter().map(|d| d.index).collect::<Vec<_>>()
+                } else {
+                    device_indices
+                };
+
+                // Same per-device exclusive lock as `run_gpu_search` - a
+                // benchmark is just as capable of quietly oversubscribing a
+                // device another process is already searching on.
+                let (locked_indices, _locks) = if gpu_share {
+                    (candidate_indices, Vec::new())
+                } else {
+                    let locks = omnivanity_gpu::lock_available(&candidate_indices);
+                    let locked_indices = locks.iter().map(|l| l.index()).collect::<Vec<_>>();
+                    (locked_indices, locks)
+                };
+
+                if locked_indices.is_empty() {
+                    anyhow::bail!("All requested GPU devices are in use by another omnivanity process. Pass --gpu-share to run anyway.");
+                }
+
+                let telemetry_devices = devices
+                    .iter()
+                    .filter(|d| locked_indices.contains(&d.index))
+                    .map(|d| GpuDeviceForTelemetry { index: d.index, backend: d.backend })
+                    .collect::<Vec<_>>();
+
+                let config = SearchConfig {
+                    threads,
+                    batch_size: 1000,
+                    max_attempts: 0,
+                    max_time_secs: duration_secs,
+                    use_gpu: true,
+                    hybrid: matches!(mode, SearchMode::Hybrid),
+                    device_indices: locked_indices,
+                    gpu_backend: None,
+                    hd_base_path: None,
+                    hd_mnemonic: None,
+                    hd_passphrase: String::new(),
+                    ..Default::default()
+                };
+
+                let search = VanitySearch::new(
+                    chain,
+                    address_type,
+                    vec![pat],
+                    config,
+                );
+
+                let (stop, handle) = spawn_telemetry_printer(telemetry_devices);
+                let _ = search.run();
+                stop.store(true, Ordering::Relaxed);
+                let _ = handle.join();
             }
             #[cfg(not(feature = "gpu"))]
             {
@@ -537,6 +837,12 @@ fn print_result(result: &SearchResult) {
     println!("Private Key: {}", result.address.private_key_native);
     println!("Private Hex: {}", result.address.private_key_hex);
     println!("Public Key:  {}", result.address.public_key_hex);
+    if let Some(mnemonic) = &result.address.mnemonic {
+        println!("Mnemonic:    {}", mnemonic);
+        if let Some(path) = &result.address.derivation_path {
+            println!("HD Path:     {}", path);
+        }
+    }
     println!("{:-<60}", "");
     println!("Keys Tested: {}", result.keys_tested);
     println!("Time:        {:.2}s", result.time_secs);
@@ -6,7 +6,7 @@ mod search;
 mod stats;
 
 pub use search::{VanitySearch, SearchConfig, SearchResult};
-pub use stats::SearchStats;
+pub use stats::{SearchStats, StatsSnapshot, ReporterHook, StdoutNdjsonReporter};
 
 // Re-exports for convenience
 pub use omnivanity_chains::{Chain, ChainFamily, AddressType, GeneratedAddress, all_chains, get_chain};
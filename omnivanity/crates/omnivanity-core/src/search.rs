@@ -1,8 +1,9 @@
 //! Vanity search engine
 
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use crossbeam_channel::{bounded, Receiver, Sender};
 use rayon::prelude::*;
@@ -10,18 +11,23 @@ use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use omnivanity_chains::{Chain, AddressType, GeneratedAddress};
-use omnivanity_pattern::{Pattern, PatternMatcher, PatternType, calculate_difficulty};
+use omnivanity_pattern::{Pattern, PatternMatcher, PatternType, calculate_difficulty_ex, calculate_combined_difficulty};
 
-use crate::stats::SearchStats;
+use crate::stats::{ReporterHook, SearchStats};
 
 // GPU support (optional feature)
 #[cfg(feature = "gpu")]
-use omnivanity_gpu::{WgpuEngine, MatchType, GpuSearchConfig, is_gpu_available};
+use omnivanity_gpu::{WgpuEngine, MatchType, PatternSpec, GpuSearchConfig, is_gpu_available, list_wgpu_devices};
 
 // OpenCL Turbo support for Ed25519 chains (optional feature)
 #[cfg(feature = "opencl")]
 use omnivanity_gpu::{OpenClEngine, OpenClSearchConfig, is_opencl_available};
 
+// OpenCL EVM search - an alternative to the CUDA `EvmCudaEngine` path for
+// AMD/Intel GPUs or older CUDA setups, selected via `SearchConfig::gpu_backend`.
+#[cfg(feature = "opencl")]
+use omnivanity_gpu::OpenClEvmEngine;
+
 use omnivanity_chains::ChainFamily;
 
 /// Search configuration
@@ -37,6 +43,56 @@ pub struct SearchConfig {
     pub max_time_secs: u64,
     /// Use GPU acceleration if available
     pub use_gpu: bool,
+    /// Run CPU threads and every GPU in `device_indices` concurrently
+    /// against the same pattern, instead of picking CPU-only or GPU-only.
+    /// Requires `threads` to be set (a thread count of 0 alone doesn't imply
+    /// hybrid mode - it's still "auto-detect CPU threads for CPU-only mode").
+    #[serde(default)]
+    pub hybrid: bool,
+    /// GPU device indices to search on. Empty means "device 0" for
+    /// `run_hybrid` and the OpenCL turbo paths; `run_gpu_hybrid` instead
+    /// falls back to every device `list_wgpu_devices` reports (and only
+    /// then to device 0).
+    #[serde(default)]
+    pub device_indices: Vec<usize>,
+    /// Force a specific GPU backend for chains with more than one available
+    /// (currently only EVM chains, which can run on either `EvmCudaEngine`
+    /// or `OpenClEvmEngine`). `"opencl"` picks OpenCL, anything else
+    /// (including `None`) keeps the existing CUDA-first behavior. Chains
+    /// that only have one backend (Ed25519's OpenCL Turbo, UTXO's CUDA)
+    /// ignore this - there's nothing to pick between yet.
+    #[serde(default)]
+    pub gpu_backend: Option<String>,
+    /// Enable HD (BIP39/BIP32/SLIP-0010) search: instead of throwaway random
+    /// keys, grind child indices under `hd_base_path` off of one BIP39
+    /// mnemonic, so a match is recoverable from the mnemonic phrase alone
+    /// instead of a one-off private key. `None` leaves normal random-key
+    /// search untouched; `Some(path)` (e.g. `"m/44'/60'/0'/0"`) turns it on.
+    /// CPU-only - the GPU engines have no mnemonic-derivation kernel.
+    #[serde(default)]
+    pub hd_base_path: Option<String>,
+    /// Existing mnemonic to resume/import under `hd_base_path`. `None`
+    /// generates a fresh 12-word mnemonic when HD mode is enabled.
+    #[serde(default)]
+    pub hd_mnemonic: Option<String>,
+    /// BIP39 passphrase ("25th word") mixed into the seed. Empty by default.
+    #[serde(default)]
+    pub hd_passphrase: String,
+    /// Hex-encoded 32-byte ChaCha20 seed for a deterministic, resumable
+    /// search: candidate private keys become `derive_key_from_counter(seed,
+    /// start_counter + i)` instead of fresh `OsRng` draws, so stopping and
+    /// restarting with the same seed and the last-persisted counter (see
+    /// `SearchStats::highest_counter`) continues exactly where it left off.
+    /// `None` (the default) leaves the existing OS-randomness search
+    /// untouched. `run_opencl_turbo` also uses it to seed its GPU key32 base
+    /// instead of random key32 upper bytes, but without the exact per-key
+    /// counter accounting the CPU path does.
+    #[serde(default)]
+    pub seed: Option<String>,
+    /// Counter to start deriving from when `seed` is set - the resume point.
+    /// Ignored if `seed` is `None`.
+    #[serde(default)]
+    pub start_counter: u64,
 }
 
 impl Default for SearchConfig {
@@ -47,10 +103,32 @@ impl Default for SearchConfig {
             max_attempts: 0,
             max_time_secs: 0,
             use_gpu: true, // Auto-enable GPU if available
+            hybrid: false,
+            device_indices: vec![],
+            gpu_backend: None,
+            hd_base_path: None,
+            hd_mnemonic: None,
+            hd_passphrase: String::new(),
+            seed: None,
+            start_counter: 0,
         }
     }
 }
 
+/// Decode `SearchConfig::seed`'s hex string into the 32-byte array
+/// `derive_key_from_counter` expects. `None` if it's missing, not valid hex,
+/// or not exactly 32 bytes.
+fn decode_seed(seed: &str) -> Option<[u8; 32]> {
+    let bytes = omnivanity_crypto::hex::decode(seed).ok()?;
+    bytes.try_into().ok()
+}
+
+/// Large odd stride (the golden-ratio fixed-point constant
+/// `omnivanity-gpu`'s multi-device dispatch also uses for its `seed_salt`
+/// spacing) that spreads each thread's deterministic counter range far
+/// enough apart that two threads never redo the same `(seed, counter)` key.
+const THREAD_COUNTER_STRIDE: u64 = 0x9E3779B97F4A7C15;
+
 /// Search result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchResult {
@@ -64,6 +142,11 @@ pub struct SearchResult {
     pub time_secs: f64,
     /// Keys per second achieved
     pub keys_per_second: f64,
+    /// Counter at which a seeded search (`SearchConfig::seed`) found the
+    /// match, so `derive_key_from_counter(seed, match_counter)` independently
+    /// re-derives the private key. `None` for OS-randomness searches.
+    #[serde(default)]
+    pub match_counter: Option<u64>,
 }
 
 /// Vanity search engine
@@ -73,6 +156,14 @@ pub struct VanitySearch {
     matcher: PatternMatcher,
     config: SearchConfig,
     difficulty: f64,
+    /// First of `patterns` passed to `new` - HD mode only scans against one
+    /// pattern (see `run_hd`), same restriction `difficulty` already has.
+    first_pattern: Option<Pattern>,
+    /// Optional NDJSON-style progress reporter plus how often (in
+    /// milliseconds) to call it, set via `with_reporter`. `None` means only
+    /// `SearchStats::format`'s human string is printed, same as before.
+    /// Only wired up in `run_cpu` so far.
+    reporter: Option<(Arc<dyn ReporterHook>, u64)>,
 }
 
 impl VanitySearch {
@@ -83,19 +174,26 @@ impl VanitySearch {
         patterns: Vec<Pattern>,
         config: SearchConfig,
     ) -> Self {
-        // Calculate difficulty from first pattern
-        let difficulty = if let Some(pattern) = patterns.first() {
-            let alphabet_size = chain.valid_address_chars(address_type).len();
-            calculate_difficulty(
-                &pattern.value,
-                pattern.pattern_type,
-                alphabet_size,
-                pattern.case_insensitive,
-            )
-        } else {
-            1.0
-        };
+        // Union difficulty across every pattern - matching *any* of them is
+        // what `run()`'s search loops actually stop on, so reporting only
+        // the first pattern's difficulty would understate how close a
+        // multi-pattern search really is to its expected attempt count.
+        let alphabet_size = chain.valid_address_chars(address_type).len();
+        let per_pattern_difficulty: Vec<f64> = patterns
+            .iter()
+            .map(|pattern| {
+                calculate_difficulty_ex(
+                    &pattern.value,
+                    pattern.pattern_type,
+                    alphabet_size,
+                    pattern.case_insensitive,
+                    pattern.eip55,
+                )
+            })
+            .collect();
+        let difficulty = calculate_combined_difficulty(&per_pattern_difficulty);
 
+        let first_pattern = patterns.first().cloned();
         let matcher = PatternMatcher::new(patterns);
 
         Self {
@@ -104,16 +202,111 @@ impl VanitySearch {
             matcher,
             config,
             difficulty,
+            first_pattern,
+            reporter: None,
         }
     }
 
+    /// Attach a `ReporterHook` the CPU search loop calls every
+    /// `interval_ms` with a `StatsSnapshot`, alongside (not instead of) the
+    /// existing `eprint!`-based human progress line. Returns `self` for
+    /// chaining onto `new`.
+    pub fn with_reporter(mut self, reporter: Arc<dyn ReporterHook>, interval_ms: u64) -> Self {
+        self.reporter = Some((reporter, interval_ms));
+        self
+    }
+
     /// Get the search difficulty
     pub fn difficulty(&self) -> f64 {
         self.difficulty
     }
 
+    /// Sweep `batch_size` (geometric steps) and CPU generation-thread counts,
+    /// measure sustained keys/sec for each combination over a short fixed
+    /// interval, print the results as a table to stderr, and return a copy
+    /// of `config` with the winning `batch_size`/`threads`. The hybrid
+    /// path's hard-coded "75% of cores" and the default `batch_size: 1000`
+    /// are rarely optimal across the CPU/GPU/chain-family mix this crate
+    /// supports - this replaces guessing with a quick measurement.
+    pub fn auto_tune(&self) -> SearchConfig {
+        const BATCH_SIZES: &[usize] = &[256, 1_000, 4_000, 16_000, 64_000];
+        const MEASURE_INTERVAL: Duration = Duration::from_millis(500);
+
+        let num_cpus = num_cpus::get();
+        let thread_counts: Vec<usize> = [0.25, 0.5, 0.75, 1.0]
+            .iter()
+            .map(|frac| ((num_cpus as f64) * frac).round().max(1.0) as usize)
+            .collect();
+
+        eprintln!("Auto-tuning: sweeping {} batch sizes x {} thread counts...", BATCH_SIZES.len(), thread_counts.len());
+        eprintln!("{:>10} {:>8} {:>14}", "batch_size", "threads", "keys/sec");
+
+        let mut best = (BATCH_SIZES[0], thread_counts[0], 0.0f64);
+        for &batch_size in BATCH_SIZES {
+            for &threads in &thread_counts {
+                let kps = self.measure_throughput(threads, batch_size, MEASURE_INTERVAL);
+                eprintln!("{:>10} {:>8} {:>14.0}", batch_size, threads, kps);
+                if kps > best.2 {
+                    best = (batch_size, threads, kps);
+                }
+            }
+        }
+        eprintln!("Winner: batch_size={} threads={} ({:.0} keys/sec)", best.0, best.1, best.2);
+
+        SearchConfig {
+            batch_size: best.0,
+            threads: best.1,
+            ..self.config.clone()
+        }
+    }
+
+    /// Raw key-generation throughput for `threads` rayon workers grinding
+    /// `batch_size`-sized batches for `duration` - no pattern matching, since
+    /// `auto_tune` only cares about how fast this chain's `generate()` runs
+    /// at a given shape, the same thing `cmd_benchmark`'s impossible-pattern
+    /// trick measures indirectly via a real (never-matching) search.
+    fn measure_throughput(&self, threads: usize, batch_size: usize, duration: Duration) -> f64 {
+        let stats = SearchStats::new();
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .expect("Failed to create thread pool");
+
+        let deadline = Instant::now() + duration;
+        pool.install(|| {
+            (0..threads).into_par_iter().for_each(|_| {
+                while Instant::now() < deadline {
+                    for _ in 0..batch_size {
+                        let _ = self.chain.generate(self.address_type);
+                    }
+                    stats.add_keys(batch_size as u64);
+                }
+            });
+        });
+
+        stats.keys_per_second()
+    }
+
+    /// Auto-tune `batch_size`/`threads` (see `auto_tune`), then run the real
+    /// search with the winning `SearchConfig`.
+    pub fn run_auto_tuned(&self) -> Option<SearchResult> {
+        let tuned = self.auto_tune();
+        let chain = omnivanity_chains::get_chain(self.chain.ticker())?;
+        let patterns = self.matcher.patterns().to_vec();
+        VanitySearch::new(chain, self.address_type, patterns, tuned).run()
+    }
+
     /// Run the search (blocking until found or limits reached)
     pub fn run(&self) -> Option<SearchResult> {
+        if self.config.hd_base_path.is_some() {
+            return self.run_hd();
+        }
+
+        if self.config.hybrid {
+            info!("Hybrid mode: CPU threads + GPU device(s) searching concurrently");
+            return self.run_hybrid();
+        }
+
         // Check for OpenCL Turbo mode (full GPU key gen) for Ed25519 chains
         #[cfg(feature = "opencl")]
         {
@@ -122,7 +315,19 @@ impl VanitySearch {
                 return self.run_opencl_turbo();
             }
         }
-        
+
+        // Same full-GPU-keygen idea, for EVM (and EVM-shaped, e.g. XDC)
+        // chains - `OpenClEvmEngine` does secp256k1 + keccak256 entirely on
+        // the device instead of `run_gpu_hybrid`'s CPU-generate/GPU-match
+        // split, so skip straight there instead of falling through.
+        #[cfg(feature = "opencl")]
+        {
+            if self.config.use_gpu && self.chain.family() == ChainFamily::Evm && is_opencl_available() {
+                info!("ðŸš€ TURBO MODE: EVM chain detected with OpenCL - using full GPU key generation!");
+                return self.run_opencl_evm_turbo();
+            }
+        }
+
         // Check if GPU should be used (hybrid mode for other chains)
         #[cfg(feature = "gpu")]
         {
@@ -138,30 +343,42 @@ impl VanitySearch {
     
     /// CPU-only search (original implementation)
     fn run_cpu(&self) -> Option<SearchResult> {
-        let stats = SearchStats::new();
+        // Configure thread pool
+        let num_threads = if self.config.threads == 0 {
+            num_cpus::get()
+        } else {
+            self.config.threads
+        };
+
+        let stats = SearchStats::with_threads(num_threads);
         let stats_clone = stats.clone();
 
         // Channel for results
-        let (tx, rx): (Sender<GeneratedAddress>, Receiver<GeneratedAddress>) = bounded(1);
+        let (tx, rx): (Sender<(GeneratedAddress, Option<u64>)>, Receiver<(GeneratedAddress, Option<u64>)>) = bounded(1);
+
+        let seed = self.config.seed.as_deref().and_then(decode_seed);
 
         // Spawn stats printer thread
         let stats_for_printer = stats.clone();
         let difficulty = self.difficulty;
+        let reporter = self.reporter.clone();
         let printer_handle = thread::spawn(move || {
+            // Report immediately on the first tick instead of waiting a
+            // full interval, same as the human progress line does.
+            let mut last_report = Instant::now() - Duration::from_secs(3600);
             while stats_for_printer.is_running() {
                 eprint!("\r{}", stats_for_printer.format(difficulty));
+                if let Some((hook, interval_ms)) = &reporter {
+                    if last_report.elapsed().as_millis() as u64 >= *interval_ms {
+                        hook.report(&stats_for_printer.snapshot(difficulty));
+                        last_report = Instant::now();
+                    }
+                }
                 thread::sleep(Duration::from_millis(250));
             }
             eprintln!(); // New line after stats
         });
 
-        // Configure thread pool
-        let num_threads = if self.config.threads == 0 {
-            num_cpus::get()
-        } else {
-            self.config.threads
-        };
-
         let pool = rayon::ThreadPoolBuilder::new()
             .num_threads(num_threads)
             .build()
@@ -173,9 +390,27 @@ impl VanitySearch {
         let max_time = self.config.max_time_secs;
 
         pool.install(|| {
-            (0..num_threads).into_par_iter().for_each(|_| {
+            (0..num_threads).into_par_iter().for_each(|loop_index| {
+                // `rayon::current_thread_index()` is the actual pool slot
+                // this closure landed on, which is what `SearchStats`'s
+                // per-thread counters are indexed by; `loop_index` (the
+                // `0..num_threads` iteration index) is only a fallback for
+                // the vanishingly unlikely case rayon can't report one.
+                let thread_index = rayon::current_thread_index().unwrap_or(loop_index);
                 let mut local_count = 0u64;
-                
+                // Last candidate in the current incremental walk (see
+                // `Chain::generate_next`) - `None` forces a fresh `generate()`,
+                // either for the very first candidate or after the walk was
+                // capped/reset at `batch_size` steps. Unused in seeded mode,
+                // which always derives a fresh key from its own counter.
+                let mut walk_from: Option<GeneratedAddress> = None;
+                // This thread's next counter to derive from, when `seed` is
+                // set - each thread gets a disjoint range via
+                // `THREAD_COUNTER_STRIDE` so none retest the other's keys.
+                let mut counter = seed.map(|_| {
+                    self.config.start_counter.wrapping_add((thread_index as u64).wrapping_mul(THREAD_COUNTER_STRIDE))
+                });
+
                 while stats_clone.is_running() {
                     // Check limits
                     if max_attempts > 0 && stats_clone.total_keys() >= max_attempts {
@@ -189,26 +424,46 @@ impl VanitySearch {
 
                     // Generate and check batch
                     for _ in 0..batch_size {
-                        let addr = self.chain.generate(self.address_type);
-                        
-                        if self.matcher.matches(&addr.address).is_some() {
+                        let (addr, match_counter) = if let (Some(seed), Some(c)) = (seed, counter) {
+                            let key_bytes = omnivanity_crypto::derive_key_from_counter(&seed, c);
+                            counter = Some(c.wrapping_add(1));
+                            stats_clone.record_counter(c);
+                            match self.chain.generate_from_bytes(&key_bytes, self.address_type) {
+                                Some(addr) => (addr, Some(c)),
+                                None => continue, // Invalid scalar for this counter - skip it.
+                            }
+                        } else {
+                            let addr = walk_from
+                                .as_ref()
+                                .and_then(|prev| self.chain.generate_next(prev, self.address_type))
+                                .unwrap_or_else(|| self.chain.generate(self.address_type));
+                            (addr, None)
+                        };
+
+                        if self.matcher.matches(&addr.address, self.chain.address_prefix(self.address_type)).is_some() {
                             // Found a match!
-                            let _ = tx.try_send(addr);
+                            let _ = tx.try_send((addr, match_counter));
                             stats_clone.mark_found();
                             return;
                         }
-                        
+
+                        walk_from = Some(addr);
                         local_count += 1;
                     }
 
+                    // Cap the incremental walk at `batch_size` steps (mirrors
+                    // the GPU kernel's `keys_per_thread`), then start the next
+                    // batch from a fresh base keypair.
+                    walk_from = None;
+
                     // Update stats
-                    stats_clone.add_keys(batch_size as u64);
+                    stats_clone.add_keys_for_thread(thread_index, batch_size as u64);
                     local_count = 0;
                 }
 
                 // Add any remaining
                 if local_count > 0 {
-                    stats_clone.add_keys(local_count);
+                    stats_clone.add_keys_for_thread(thread_index, local_count);
                 }
             });
         });
@@ -218,7 +473,7 @@ impl VanitySearch {
         let _ = printer_handle.join();
 
         // Check for result
-        if let Ok(address) = rx.try_recv() {
+        if let Ok((address, match_counter)) = rx.try_recv() {
             let pattern = self.matcher.patterns()
                 .first()
                 .map(|p| p.value.clone())
@@ -230,190 +485,794 @@ impl VanitySearch {
                 keys_tested: stats.total_keys(),
                 time_secs: stats.elapsed().as_secs_f64(),
                 keys_per_second: stats.keys_per_second(),
+                match_counter,
             })
         } else {
             None
         }
     }
     
-    /// GPU-accelerated hybrid search: CPU generates keys, GPU matches patterns
-    #[cfg(feature = "gpu")]
-    fn run_gpu_hybrid(&self) -> Option<SearchResult> {
-        let stats = SearchStats::new();
-        
-        // Initialize GPU engine
-        let gpu_config = GpuSearchConfig::default();
-        let gpu_engine = match WgpuEngine::new_sync(0, gpu_config) {
-            Ok(g) => {
-                info!("GPU initialized: {}", g.device_name());
-                g
+    /// Grind child indices under `config.hd_base_path` off of one BIP39
+    /// mnemonic instead of throwaway random keys, so a match is recoverable
+    /// from the mnemonic phrase alone. CPU-only; only the first pattern is
+    /// used, and only `Prefix`/`Suffix` pattern types are supported (`Contains`
+    /// has no equivalent in `omnivanity_chains::vanity::PatternSpec` yet).
+    fn run_hd(&self) -> Option<SearchResult> {
+        use omnivanity_chains::hd_search::HdVanitySearch;
+        use omnivanity_chains::vanity::PatternSpec;
+        use omnivanity_crypto::{generate_mnemonic, mnemonic_to_seed};
+
+        let base_path = self.config.hd_base_path.as_deref()?;
+        let pattern = self.first_pattern.as_ref()?;
+        let mut spec = match pattern.pattern_type {
+            PatternType::Prefix => PatternSpec::prefix(pattern.value.clone()),
+            PatternType::Suffix => PatternSpec::suffix(pattern.value.clone()),
+            PatternType::Contains => {
+                tracing::warn!("HD search doesn't support Contains patterns yet, falling back to random-key search");
+                return self.run_cpu();
             }
+        };
+        if pattern.case_insensitive {
+            spec = spec.case_insensitive();
+        }
+
+        let mnemonic = match &self.config.hd_mnemonic {
+            Some(m) => m.clone(),
+            None => generate_mnemonic(),
+        };
+        let seed = match mnemonic_to_seed(&mnemonic, &self.config.hd_passphrase) {
+            Ok(seed) => seed.to_vec(),
             Err(e) => {
-                info!("GPU init failed ({}), falling back to CPU", e);
-                return self.run_cpu();
+                tracing::warn!("invalid HD mnemonic: {e}");
+                return None;
             }
         };
-        
-        // Get pattern info
+
+        let search = HdVanitySearch::new(self.chain.as_ref(), self.address_type, spec, seed, base_path).ok()?;
+        let matched = search.run(|progress| {
+            eprint!("\r[HD] {:.0} keys/s | {} tested", progress.attempts_per_sec, progress.attempts);
+        });
+        eprintln!();
+
+        let mut address = matched.address;
+        address.mnemonic = Some(mnemonic);
+        Some(SearchResult {
+            address,
+            pattern: pattern.value.clone(),
+            keys_tested: matched.attempts,
+            time_secs: matched.elapsed_secs,
+            keys_per_second: matched.attempts as f64 / matched.elapsed_secs.max(1e-9),
+            match_counter: None,
+        })
+    }
+
+    /// CPU threads plus every GPU device in `config.device_indices` (or
+    /// device 0, if empty) search the same pattern at once. Each worker -
+    /// CPU thread or GPU device - is an independent loop sharing one
+    /// `stop_flag`: the first to match sets it, every other worker polls it
+    /// each batch/iteration and winds down. `stats` is the same `SearchStats`
+    /// across every worker, so `keys_tested`/`keys_per_second` in the final
+    /// `SearchResult` are already the sum across CPU and every GPU - no
+    /// separate per-worker aggregation step needed.
+    fn run_hybrid(&self) -> Option<SearchResult> {
+        let stats = SearchStats::new();
+        let stop_flag = Arc::new(AtomicBool::new(false));
+        let (tx, rx): (Sender<SearchResult>, Receiver<SearchResult>) = bounded(1);
+
         let pattern = self.matcher.patterns()
             .first()
             .map(|p| p.value.clone())
             .unwrap_or_default();
-            
-        let pat_obj = self.matcher.patterns().first().unwrap();
-        let match_type = match pat_obj.pattern_type {
-            PatternType::Prefix => MatchType::Prefix,
-            PatternType::Suffix => MatchType::Suffix,
-            PatternType::Contains => MatchType::Contains,
-        };
-        
-        // Batch size for GPU (larger = more GPU utilization)
-        let gpu_batch_size = self.config.batch_size;
-        let max_time = self.config.max_time_secs;
-        let max_attempts = self.config.max_attempts;
-        let start_time = std::time::Instant::now();
-        
-        // Stats printer thread  
+
+        let mut handles = Vec::new();
+
+        // Stats printer thread
         let stats_for_printer = stats.clone();
         let difficulty = self.difficulty;
         let printer_handle = thread::spawn(move || {
             while stats_for_printer.is_running() {
-                eprint!("\r{} ðŸš€GPU", stats_for_printer.format(difficulty));
+                eprint!("\r{} [hybrid]", stats_for_printer.format(difficulty));
                 thread::sleep(Duration::from_millis(250));
             }
             eprintln!();
         });
-        
-        let mut result: Option<SearchResult> = None;
-        
-        while stats.is_running() {
-            // Check limits
-            if max_attempts > 0 && stats.total_keys() >= max_attempts {
-                break;
-            }
-            if max_time > 0 && stats.elapsed().as_secs() >= max_time {
-                break;
-            }
-            
-            // Generate batch of addresses on CPU (using rayon with limited threads)
-            // Optimization: Use generate_address to avoid full string formatting until match found
-            // Use only 75% of CPU cores to avoid maxing out CPU (leave room for GPU driver, system, etc.)
-            let num_cpus = num_cpus::get();
-            let gen_threads = (num_cpus * 3 / 4).max(1); // Use 75% of cores, minimum 1
-            
-            let (address_strings, keys): (Vec<String>, Vec<Vec<u8>>) = rayon::ThreadPoolBuilder::new()
-                .num_threads(gen_threads)
-                .build()
-                .unwrap()
-                .install(|| {
-                    (0..gpu_batch_size)
-                        .into_par_iter()
-                        .map(|_| self.chain.generate_address(self.address_type))
-                        .unzip()
-                });
 
-            // Run on GPU (should be much faster than CPU generation)
-            let match_indices = gpu_engine.pattern_match_batch(
-                &address_strings,
-                &pattern,
-                match_type,
-                pat_obj.case_insensitive,
-            );
-            
-            // Process matches
-            for idx in match_indices {
-                if idx >= address_strings.len() {
-                    continue;
+        // CPU workers - same generate-and-match loop as `run_cpu`, just also
+        // watching `stop_flag` so a GPU worker's match stops them promptly.
+        let num_threads = if self.config.threads == 0 { num_cpus::get() } else { self.config.threads };
+        for _ in 0..num_threads {
+            let chain_ticker = self.chain.ticker().to_string();
+            let address_type = self.address_type;
+            let matcher = self.matcher.clone();
+            let stats = stats.clone();
+            let stop_flag = stop_flag.clone();
+            let tx = tx.clone();
+            let pattern = pattern.clone();
+            let batch_size = self.config.batch_size;
+            let max_attempts = self.config.max_attempts;
+            let max_time = self.config.max_time_secs;
+
+            handles.push(thread::spawn(move || {
+                let chain = omnivanity_chains::get_chain(&chain_ticker).unwrap();
+                let chain_prefix = chain.address_prefix(address_type);
+                // See `run_cpu`'s `walk_from` - same incremental-walk fast
+                // path, capped at `batch_size` steps per base keypair.
+                let mut walk_from: Option<GeneratedAddress> = None;
+
+                while !stop_flag.load(Ordering::Relaxed) {
+                    if max_attempts > 0 && stats.total_keys() >= max_attempts {
+                        stop_flag.store(true, Ordering::Relaxed);
+                        break;
+                    }
+                    if max_time > 0 && stats.elapsed().as_secs() >= max_time {
+                        stop_flag.store(true, Ordering::Relaxed);
+                        break;
+                    }
+
+                    for _ in 0..batch_size {
+                        let addr = walk_from
+                            .as_ref()
+                            .and_then(|prev| chain.generate_next(prev, address_type))
+                            .unwrap_or_else(|| chain.generate(address_type));
+                        if matcher.matches(&addr.address, chain_prefix).is_some() {
+                            let _ = tx.try_send(SearchResult {
+                                address: addr,
+                                pattern: pattern.clone(),
+                                keys_tested: stats.total_keys(),
+                                time_secs: stats.elapsed().as_secs_f64(),
+                                keys_per_second: stats.keys_per_second(),
+                                match_counter: None,
+                            });
+                            stop_flag.store(true, Ordering::Relaxed);
+                            stats.mark_found();
+                            return;
+                        }
+                        walk_from = Some(addr);
+                    }
+                    walk_from = None;
+                    stats.add_keys(batch_size as u64);
                 }
-                
-                let address_str = &address_strings[idx];
-                let private_key = &keys[idx];
-                
-                // Double verification (CPU side)
-                if self.matcher.matches(address_str).is_some() {
-                    // Reconstruct full details for the result
-                    if let Some(r) = self.chain.generate_from_bytes(private_key, self.address_type) {
+            }));
+        }
+
+        let device_indices = if self.config.device_indices.is_empty() {
+            vec![0]
+        } else {
+            self.config.device_indices.clone()
+        };
+
+        // GPU workers - one per requested device, using whichever backend
+        // this chain's family/address types actually support. Mirrors
+        // `GpuScheduler`'s one-thread-per-device shape (omnivanity-gpu's
+        // `scheduler.rs`), just racing CPU threads alongside instead of only
+        // other GPU devices.
+        #[cfg(feature = "opencl")]
+        if self.chain.family() == ChainFamily::Ed25519 {
+            for &device_index in &device_indices {
+                let stop_flag = stop_flag.clone();
+                let stats = stats.clone();
+                let tx = tx.clone();
+                let pattern = pattern.clone();
+                let pat_obj = self.matcher.patterns().first().cloned();
+                let chain_ticker = self.chain.ticker().to_string();
+                let address_type = self.address_type;
+                let max_attempts = self.config.max_attempts;
+                let max_time = self.config.max_time_secs;
+
+                handles.push(thread::spawn(move || {
+                    let Some(pat_obj) = pat_obj else { return; };
+                    let case_sensitive = !pat_obj.case_insensitive;
+                    let (prefix, suffix) = match pat_obj.pattern_type {
+                        PatternType::Prefix => (pattern.as_str(), ""),
+                        PatternType::Suffix => ("", pattern.as_str()),
+                        PatternType::Contains => (pattern.as_str(), ""),
+                    };
+
+                    let engine = match OpenClEngine::new(device_index) {
+                        Ok(engine) => engine,
+                        Err(e) => {
+                            info!("Hybrid: OpenCL device {} unavailable ({}), skipping", device_index, e);
+                            return;
+                        }
+                    };
+                    let config = OpenClSearchConfig::default();
+                    let keys_per_iteration = config.global_work_size as u64;
+                    let chain = omnivanity_chains::get_chain(&chain_ticker).unwrap();
+                    let group_offset_base = (device_index as u8).wrapping_mul(32);
+
+                    while !stop_flag.load(Ordering::Relaxed) {
+                        if max_attempts > 0 && stats.total_keys() >= max_attempts {
+                            stop_flag.store(true, Ordering::Relaxed);
+                            break;
+                        }
+                        if max_time > 0 && stats.elapsed().as_secs() >= max_time {
+                            stop_flag.store(true, Ordering::Relaxed);
+                            break;
+                        }
+
+                        match engine.search_ed25519(prefix, suffix, case_sensitive, &config, group_offset_base, 1, stop_flag.clone(), None) {
+                            Ok(Some(private_key)) => {
+                                if let Some(addr) = chain.generate_from_bytes(&private_key, address_type) {
+                                    let _ = tx.try_send(SearchResult {
+                                        address: addr,
+                                        pattern: pattern.clone(),
+                                        keys_tested: stats.total_keys(),
+                                        time_secs: stats.elapsed().as_secs_f64(),
+                                        keys_per_second: stats.keys_per_second(),
+                                        match_counter: None,
+                                    });
+                                    stop_flag.store(true, Ordering::Relaxed);
+                                    stats.mark_found();
+                                    return;
+                                }
+                            }
+                            Ok(None) => {}
+                            Err(e) => {
+                                info!("Hybrid: OpenCL device {} error ({}), stopping that worker", device_index, e);
+                                return;
+                            }
+                        }
+                        stats.add_keys(keys_per_iteration);
+                    }
+                }));
+            }
+        }
+
+        #[cfg(feature = "cuda")]
+        if self.chain.address_types().contains(&AddressType::Evm)
+            && self.config.gpu_backend.as_deref() != Some("opencl")
+        {
+            for &device_index in &device_indices {
+                let stop_flag = stop_flag.clone();
+                let stats = stats.clone();
+                let tx = tx.clone();
+                let pattern = pattern.clone();
+                let pat_obj = self.matcher.patterns().first().cloned();
+                let max_attempts = self.config.max_attempts;
+                let max_time = self.config.max_time_secs;
+
+                handles.push(thread::spawn(move || {
+                    let Some(pat_obj) = pat_obj else { return; };
+                    let gpu_config = omnivanity_gpu::GpuSearchConfig {
+                        device_indices: vec![device_index],
+                        max_attempts,
+                        max_time_secs: if max_time > 0 { max_time } else { u64::MAX },
+                        ..Default::default()
+                    };
+                    let engine = match omnivanity_gpu::EvmCudaEngine::new(device_index, gpu_config) {
+                        Ok(engine) => engine,
+                        Err(e) => {
+                            info!("Hybrid: CUDA device {} unavailable ({:?}), skipping", device_index, e);
+                            return;
+                        }
+                    };
+                    let pattern_bytes = decode_hex_pattern(&pattern);
+
+                    // `EvmCudaEngine::search`'s inherent method (not the
+                    // `GpuVanitySearch` trait one) takes the shared
+                    // `stop_flag` directly, matching every other worker here,
+                    // and already returns a fully-formed `GeneratedAddress`.
+                    if let Some(gpu_result) = engine.search(&pattern_bytes, pattern.len(), pat_obj.case_insensitive, stop_flag.clone()) {
+                        let _ = tx.try_send(SearchResult {
+                            address: gpu_result.address,
+                            pattern: pattern.clone(),
+                            keys_tested: stats.total_keys(),
+                            time_secs: stats.elapsed().as_secs_f64(),
+                            keys_per_second: stats.keys_per_second(),
+                            match_counter: None,
+                        });
+                        stop_flag.store(true, Ordering::Relaxed);
                         stats.mark_found();
-                        
-                        let total = stats.total_keys();
-                        let elapsed = start_time.elapsed().as_secs_f64();
-                        
-                        result = Some(SearchResult {
-                            address: r,
+                    }
+                }));
+            }
+        }
+
+        // Same EVM search as the CUDA block above, just through
+        // `OpenClEvmEngine` (see `omnivanity-gpu/src/kernels/evm_opencl.cl`)
+        // for machines without an NVIDIA card - or with one, if the user
+        // asked for OpenCL specifically via `gpu_backend`.
+        #[cfg(feature = "opencl")]
+        if self.chain.address_types().contains(&AddressType::Evm)
+            && self.config.gpu_backend.as_deref() == Some("opencl")
+        {
+            for &device_index in &device_indices {
+                let stop_flag = stop_flag.clone();
+                let stats = stats.clone();
+                let tx = tx.clone();
+                let pattern = pattern.clone();
+                let pat_obj = self.matcher.patterns().first().cloned();
+                let max_attempts = self.config.max_attempts;
+                let max_time = self.config.max_time_secs;
+
+                handles.push(thread::spawn(move || {
+                    let Some(pat_obj) = pat_obj else { return; };
+                    let gpu_config = omnivanity_gpu::GpuSearchConfig {
+                        device_indices: vec![device_index],
+                        max_attempts,
+                        max_time_secs: if max_time > 0 { max_time } else { u64::MAX },
+                        ..Default::default()
+                    };
+                    let engine = match OpenClEvmEngine::new(device_index, gpu_config) {
+                        Ok(engine) => engine,
+                        Err(e) => {
+                            info!("Hybrid: OpenCL device {} unavailable ({}), skipping", device_index, e);
+                            return;
+                        }
+                    };
+                    let _ = pat_obj.case_insensitive;
+                    let pattern_bytes = decode_hex_pattern(&pattern);
+
+                    if let Some(gpu_result) = engine.search(&pattern_bytes, stop_flag.clone()) {
+                        let _ = tx.try_send(SearchResult {
+                            address: gpu_result.address,
                             pattern: pattern.clone(),
-                            keys_tested: total,
-                            time_secs: elapsed,
-                            keys_per_second: total as f64 / elapsed,
+                            keys_tested: stats.total_keys(),
+                            time_secs: stats.elapsed().as_secs_f64(),
+                            keys_per_second: stats.keys_per_second(),
+                            match_counter: None,
                         });
-                        break;
+                        stop_flag.store(true, Ordering::Relaxed);
+                        stats.mark_found();
                     }
+                }));
+            }
+        }
+
+        // `ChainFamily::UtxoSecp256k1` chains that have opted in via
+        // `Chain::address_version_byte` (BTC/LTC/DOGE/RVN's P2PKH address
+        // type) get the same CUDA treatment as EVM above, just through
+        // `UtxoCudaEngine` instead of `EvmCudaEngine` - see
+        // `omnivanity-gpu/src/kernels/utxo_kernel.cu` for the shared
+        // incremental-walk machinery the two engines don't bother factoring
+        // out of their respective `.cu` files.
+        #[cfg(feature = "cuda")]
+        if self.chain.family() == ChainFamily::UtxoSecp256k1 {
+            if let Some(version_byte) = self.chain.address_version_byte(self.address_type) {
+                let chain_ticker: &'static str = self.chain.ticker();
+                for &device_index in &device_indices {
+                    let stop_flag = stop_flag.clone();
+                    let stats = stats.clone();
+                    let tx = tx.clone();
+                    let pattern = pattern.clone();
+                    let max_attempts = self.config.max_attempts;
+                    let max_time = self.config.max_time_secs;
+
+                    handles.push(thread::spawn(move || {
+                        let gpu_config = omnivanity_gpu::GpuSearchConfig {
+                            device_indices: vec![device_index],
+                            max_attempts,
+                            max_time_secs: if max_time > 0 { max_time } else { u64::MAX },
+                            ..Default::default()
+                        };
+                        let engine = match omnivanity_gpu::UtxoCudaEngine::new(device_index, gpu_config, chain_ticker, version_byte) {
+                            Ok(engine) => engine,
+                            Err(e) => {
+                                info!("Hybrid: CUDA device {} unavailable ({:?}), skipping", device_index, e);
+                                return;
+                            }
+                        };
+
+                        // Base58's mixed-case alphabet means the pattern is
+                        // matched as-typed, unlike EVM's hex-decoded one.
+                        if let Some(gpu_result) = engine.search(pattern.as_bytes(), stop_flag.clone()) {
+                            let _ = tx.try_send(SearchResult {
+                                address: gpu_result.address,
+                                pattern: pattern.clone(),
+                                keys_tested: stats.total_keys(),
+                                time_secs: stats.elapsed().as_secs_f64(),
+                                keys_per_second: stats.keys_per_second(),
+                                match_counter: None,
+                            });
+                            stop_flag.store(true, Ordering::Relaxed);
+                            stats.mark_found();
+                        }
+                    }));
                 }
             }
-            
-            stats.add_keys(gpu_batch_size as u64);
-            
-            if result.is_some() {
-                break;
+        }
+
+        for handle in handles {
+            let _ = handle.join();
+        }
+
+        stats.stop();
+        let _ = printer_handle.join();
+
+        rx.try_recv().ok()
+    }
+
+    /// Every GPU device in `config.device_indices` (falling back to every
+    /// device `list_wgpu_devices` can see, then finally to just device 0 if
+    /// even that comes back empty) runs its own CPU-generate/GPU-match loop
+    /// concurrently, same shared-`stats`/`stop_flag`/`tx` shape as
+    /// `run_hybrid`'s GPU worker blocks. Falls back to `run_cpu` only if
+    /// *every* requested device fails to initialize.
+    #[cfg(feature = "gpu")]
+    fn run_gpu_hybrid(&self) -> Option<SearchResult> {
+        let stats = SearchStats::new();
+        let stop_flag = Arc::new(AtomicBool::new(false));
+        let (tx, rx): (Sender<SearchResult>, Receiver<SearchResult>) = bounded(1);
+
+        let patterns = self.matcher.patterns().to_vec();
+        if patterns.is_empty() {
+            return self.run_cpu();
+        }
+        // One `PatternSpec` per pattern, same order as `patterns`, so a
+        // GPU-returned spec index maps straight back to `patterns[spec_idx]`
+        // - lets a device's GPU pre-filter test every configured pattern in
+        // one dispatch instead of only the first.
+        let specs: Vec<PatternSpec> = patterns
+            .iter()
+            .map(|p| PatternSpec {
+                pattern: p.value.clone(),
+                match_type: match p.pattern_type {
+                    PatternType::Prefix => MatchType::Prefix,
+                    PatternType::Suffix => MatchType::Suffix,
+                    PatternType::Contains => MatchType::Contains,
+                },
+                case_insensitive: p.case_insensitive,
+            })
+            .collect();
+
+        let device_indices = if !self.config.device_indices.is_empty() {
+            self.config.device_indices.clone()
+        } else {
+            let detected: Vec<usize> = list_wgpu_devices()
+                .into_iter()
+                .enumerate()
+                .map(|(i, _)| i)
+                .collect();
+            if detected.is_empty() { vec![0] } else { detected }
+        };
+
+        // Stats printer thread
+        let stats_for_printer = stats.clone();
+        let difficulty = self.difficulty;
+        let printer_handle = thread::spawn(move || {
+            while stats_for_printer.is_running() {
+                eprint!("\r{} ðŸš€GPU", stats_for_printer.format(difficulty));
+                thread::sleep(Duration::from_millis(250));
             }
+            eprintln!();
+        });
+
+        let mut handles = Vec::new();
+        let num_devices = device_indices.len();
+        let devices_initialized = Arc::new(AtomicBool::new(false));
+        for &device_index in &device_indices {
+            let stop_flag = stop_flag.clone();
+            let stats = stats.clone();
+            let tx = tx.clone();
+            let patterns = patterns.clone();
+            let specs = specs.clone();
+            let chain_ticker = self.chain.ticker().to_string();
+            let address_type = self.address_type;
+            let matcher = self.matcher.clone();
+            let batch_size = self.config.batch_size;
+            let max_attempts = self.config.max_attempts;
+            let max_time = self.config.max_time_secs;
+            let devices_initialized = devices_initialized.clone();
+
+            handles.push(thread::spawn(move || {
+                let gpu_engine = match WgpuEngine::new_sync(device_index, GpuSearchConfig::default()) {
+                    Ok(g) => {
+                        info!("GPU device {} initialized: {}", device_index, g.device_name());
+                        devices_initialized.store(true, Ordering::Relaxed);
+                        g
+                    }
+                    Err(e) => {
+                        info!("GPU device {} init failed ({}), skipping", device_index, e);
+                        return;
+                    }
+                };
+                let chain = omnivanity_chains::get_chain(&chain_ticker).unwrap();
+                let chain_prefix = chain.address_prefix(address_type);
+
+                // Leave room for the GPU driver, system, and the sibling
+                // device workers - same 75%-of-cores heuristic as before,
+                // just split across however many devices are racing.
+                let gen_threads = (num_cpus::get() * 3 / 4 / num_devices).max(1);
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(gen_threads)
+                    .build()
+                    .unwrap();
+
+                // Depth-2 double buffering: the producer below keeps one
+                // batch queued ahead of the GPU match loop, so CPU
+                // generation of batch N+1 overlaps with the GPU matching
+                // batch N instead of each stalling on the other. The pool
+                // itself is also built once, up front, rather than per
+                // iteration.
+                let (batch_tx, batch_rx) = bounded::<(Vec<String>, Vec<Vec<u8>>)>(2);
+                let producer_stop = stop_flag.clone();
+                let producer_chain = omnivanity_chains::get_chain(&chain_ticker).unwrap();
+                let producer = thread::spawn(move || {
+                    while !producer_stop.load(Ordering::Relaxed) {
+                        let batch = pool.install(|| {
+                            (0..batch_size)
+                                .into_par_iter()
+                                .map(|_| producer_chain.generate_address(address_type))
+                                .unzip()
+                        });
+                        if batch_tx.send(batch).is_err() {
+                            break;
+                        }
+                    }
+                });
+
+                while !stop_flag.load(Ordering::Relaxed) {
+                    if max_attempts > 0 && stats.total_keys() >= max_attempts {
+                        stop_flag.store(true, Ordering::Relaxed);
+                        break;
+                    }
+                    if max_time > 0 && stats.elapsed().as_secs() >= max_time {
+                        stop_flag.store(true, Ordering::Relaxed);
+                        break;
+                    }
+
+                    let Ok((address_strings, keys)) = batch_rx.recv_timeout(Duration::from_millis(250)) else {
+                        continue;
+                    };
+
+                    let match_indices = gpu_engine.pattern_match_batch(&address_strings, &specs);
+
+                    let mut found = false;
+                    for (idx, _spec_idx) in match_indices {
+                        if idx >= address_strings.len() {
+                            continue;
+                        }
+                        // Re-verify against the *full* pattern list on the
+                        // CPU, rather than trusting the GPU spec index
+                        // directly - `matcher.matches` is the single source
+                        // of truth for "which pattern actually won" (e.g. if
+                        // two overlap on this address, it picks the same one
+                        // the CPU-only path would).
+                        if let Some(winner) = matcher.matches(&address_strings[idx], chain_prefix) {
+                            if let Some(r) = chain.generate_from_bytes(&keys[idx], address_type) {
+                                let winning_pattern = patterns.get(winner).map(|p| p.value.clone()).unwrap_or_default();
+                                let _ = tx.try_send(SearchResult {
+                                    address: r,
+                                    pattern: winning_pattern,
+                                    keys_tested: stats.total_keys(),
+                                    time_secs: stats.elapsed().as_secs_f64(),
+                                    keys_per_second: stats.keys_per_second(),
+                                    match_counter: None,
+                                });
+                                stop_flag.store(true, Ordering::Relaxed);
+                                stats.mark_found();
+                                found = true;
+                                break;
+                            }
+                        }
+                    }
+
+                    stats.add_keys(batch_size as u64);
+                    if found {
+                        break;
+                    }
+                }
+
+                stop_flag.store(true, Ordering::Relaxed);
+                drop(batch_rx);
+                let _ = producer.join();
+            }));
         }
-        
+
+        for handle in handles {
+            let _ = handle.join();
+        }
+
         stats.stop();
         let _ = printer_handle.join();
-        
-        result
+
+        if !devices_initialized.load(Ordering::Relaxed) {
+            info!("No GPU device initialized, falling back to CPU");
+            return self.run_cpu();
+        }
+
+        rx.try_recv().ok()
     }
 
-    /// TURBO MODE: Full GPU key generation for Ed25519 chains (8+ MH/s)
+    /// TURBO MODE: Full GPU key generation for Ed25519 chains (8+ MH/s).
+    /// Every device in `config.device_indices` (or device 0, if empty) races
+    /// independently, same shared-`stats`/`stop_flag`/`tx` shape as the
+    /// OpenCL worker block in `run_hybrid` - just without any CPU workers
+    /// alongside, since turbo mode does its key generation on the GPU too.
     #[cfg(feature = "opencl")]
     fn run_opencl_turbo(&self) -> Option<SearchResult> {
         let stats = SearchStats::new();
-        let start_time = std::time::Instant::now();
-        
-        // Get pattern info
+        let stop_flag = Arc::new(AtomicBool::new(false));
+        let (tx, rx): (Sender<SearchResult>, Receiver<SearchResult>) = bounded(1);
+
         let pattern = self.matcher.patterns()
             .first()
             .map(|p| p.value.clone())
             .unwrap_or_default();
-        
-        let pat_obj = self.matcher.patterns().first().unwrap();
+
+        let Some(pat_obj) = self.matcher.patterns().first().cloned() else {
+            return self.run_cpu();
+        };
         let case_sensitive = !pat_obj.case_insensitive;
-        
+
         // Determine prefix/suffix from pattern type
         let (prefix, suffix) = match pat_obj.pattern_type {
-            PatternType::Prefix => (pattern.as_str(), ""),
-            PatternType::Suffix => ("", pattern.as_str()),
-            PatternType::Contains => (pattern.as_str(), ""), // Treat as prefix for now
+            PatternType::Prefix => (pattern.clone(), String::new()),
+            PatternType::Suffix => (String::new(), pattern.clone()),
+            PatternType::Contains => (pattern.clone(), String::new()), // Treat as prefix for now
         };
-        
-        // Initialize OpenCL engine
-        let opencl_engine = match OpenClEngine::new(0) {
-            Ok(engine) => {
-                let est_speed = engine.estimated_keys_per_second();
-                info!("ðŸš€ OpenCL TURBO initialized: {} (est. {:.1} MH/s)", 
-                    engine.device_info().name,
-                    est_speed as f64 / 1_000_000.0
-                );
-                engine
+
+        let device_indices = if !self.config.device_indices.is_empty() {
+            self.config.device_indices.clone()
+        } else {
+            vec![0]
+        };
+
+        // When seeded, every device's key32 base comes from the same
+        // `derive_key_from_counter(seed, start_counter)` block instead of
+        // fresh OS randomness, so a `--resume` run with the same seed and
+        // counter always grinds the same keyspace slice. The GPU kernel
+        // still exhausts the low `iteration_bits` bits internally, so
+        // `match_counter` below records which seed-derived base was in play
+        // rather than a literal per-key index the way `run_cpu`'s does.
+        let base_key = self.config.seed.as_deref().and_then(decode_seed).map(|seed| {
+            omnivanity_crypto::derive_key_from_counter(&seed, self.config.start_counter)
+        });
+        let match_counter = base_key.map(|_| self.config.start_counter);
+        if let Some(counter) = match_counter {
+            stats.record_counter(counter);
+        }
+
+        // Stats printer thread
+        let stats_for_printer = stats.clone();
+        let difficulty = self.difficulty;
+        let printer_handle = thread::spawn(move || {
+            while stats_for_printer.is_running() {
+                eprint!("\r{} ðŸš€TURBO", stats_for_printer.format(difficulty));
+                thread::sleep(Duration::from_millis(250));
             }
-            Err(e) => {
-                info!("OpenCL init failed ({}), falling back to hybrid", e);
-                #[cfg(feature = "gpu")]
-                {
-                    return self.run_gpu_hybrid();
-                }
-                #[cfg(not(feature = "gpu"))]
-                {
-                    return self.run_cpu();
+            eprintln!();
+        });
+
+        let mut handles = Vec::new();
+        let devices_initialized = Arc::new(AtomicBool::new(false));
+        for &device_index in &device_indices {
+            let stop_flag = stop_flag.clone();
+            let stats = stats.clone();
+            let tx = tx.clone();
+            let pattern = pattern.clone();
+            let prefix = prefix.clone();
+            let suffix = suffix.clone();
+            let chain_ticker = self.chain.ticker().to_string();
+            let address_type = self.address_type;
+            let matcher = self.matcher.clone();
+            let max_attempts = self.config.max_attempts;
+            let max_time = self.config.max_time_secs;
+            let devices_initialized = devices_initialized.clone();
+
+            handles.push(thread::spawn(move || {
+                let opencl_engine = match OpenClEngine::new(device_index) {
+                    Ok(engine) => {
+                        let est_speed = engine.estimated_keys_per_second();
+                        info!("ðŸš€ OpenCL TURBO device {} initialized: {} (est. {:.1} MH/s)",
+                            device_index,
+                            engine.device_info().name,
+                            est_speed as f64 / 1_000_000.0
+                        );
+                        devices_initialized.store(true, Ordering::Relaxed);
+                        engine
+                    }
+                    Err(e) => {
+                        info!("OpenCL device {} init failed ({}), skipping", device_index, e);
+                        return;
+                    }
+                };
+                let chain = omnivanity_chains::get_chain(&chain_ticker).unwrap();
+                let config = OpenClSearchConfig::default();
+                let keys_per_iteration = config.global_work_size as u64;
+                let group_offset_base = (device_index as u8).wrapping_mul(32);
+
+                while !stop_flag.load(Ordering::Relaxed) {
+                    if max_attempts > 0 && stats.total_keys() >= max_attempts {
+                        stop_flag.store(true, Ordering::Relaxed);
+                        break;
+                    }
+                    if max_time > 0 && stats.elapsed().as_secs() >= max_time {
+                        stop_flag.store(true, Ordering::Relaxed);
+                        break;
+                    }
+
+                    match opencl_engine.search_ed25519(
+                        &prefix,
+                        &suffix,
+                        case_sensitive,
+                        &config,
+                        group_offset_base,
+                        1,
+                        stop_flag.clone(),
+                        base_key,
+                    ) {
+                        Ok(Some(private_key)) => {
+                            if let Some(addr) = chain.generate_from_bytes(&private_key, address_type) {
+                                if matcher.matches(&addr.address, chain.address_prefix(address_type)).is_some() {
+                                    let _ = tx.try_send(SearchResult {
+                                        address: addr,
+                                        pattern: pattern.clone(),
+                                        keys_tested: stats.total_keys(),
+                                        time_secs: stats.elapsed().as_secs_f64(),
+                                        keys_per_second: stats.keys_per_second(),
+                                        match_counter,
+                                    });
+                                    stop_flag.store(true, Ordering::Relaxed);
+                                    stats.mark_found();
+                                    return;
+                                }
+                            }
+                        }
+                        Ok(None) => {}
+                        Err(e) => {
+                            info!("OpenCL device {} error ({}), stopping that worker", device_index, e);
+                            return;
+                        }
+                    }
+
+                    stats.add_keys(keys_per_iteration);
                 }
+            }));
+        }
+
+        for handle in handles {
+            let _ = handle.join();
+        }
+
+        stats.stop();
+        let _ = printer_handle.join();
+
+        if !devices_initialized.load(Ordering::Relaxed) {
+            info!("OpenCL init failed on every device, falling back to hybrid");
+            #[cfg(feature = "gpu")]
+            {
+                return self.run_gpu_hybrid();
             }
+            #[cfg(not(feature = "gpu"))]
+            {
+                return self.run_cpu();
+            }
+        }
+
+        rx.try_recv().ok()
+    }
+
+    /// Full on-GPU key generation for EVM (and EVM-shaped, e.g. XDC) chains,
+    /// one `OpenClEvmEngine` per device - same multi-device dispatch shape
+    /// as `run_opencl_turbo`, just without CPU threads racing alongside
+    /// since there's nothing left for them to contribute once the GPU does
+    /// the whole secp256k1 + keccak256 + address derivation itself. Only a
+    /// prefix actually reaches the kernel (`evm_vanity_search` has no suffix
+    /// support yet), matching the same simplification `run_hybrid`'s OpenCL
+    /// EVM block already makes for this engine.
+    #[cfg(feature = "opencl")]
+    fn run_opencl_evm_turbo(&self) -> Option<SearchResult> {
+        let stats = SearchStats::new();
+        let stop_flag = Arc::new(AtomicBool::new(false));
+        let (tx, rx): (Sender<SearchResult>, Receiver<SearchResult>) = bounded(1);
+
+        let pattern = self.matcher.patterns()
+            .first()
+            .map(|p| p.value.clone())
+            .unwrap_or_default();
+
+        if self.matcher.patterns().first().is_none() {
+            return self.run_cpu();
+        }
+
+        let device_indices = if !self.config.device_indices.is_empty() {
+            self.config.device_indices.clone()
+        } else {
+            vec![0]
         };
-        
-        // Configure OpenCL search
-        let config = OpenClSearchConfig::default();
-        let max_time = self.config.max_time_secs;
-        let max_attempts = self.config.max_attempts;
-        
-        // Stats printer thread  
+
+        // Stats printer thread
         let stats_for_printer = stats.clone();
         let difficulty = self.difficulty;
         let printer_handle = thread::spawn(move || {
@@ -423,57 +1282,72 @@ impl VanitySearch {
             }
             eprintln!();
         });
-        
-        let mut result: Option<SearchResult> = None;
-        let keys_per_iteration = config.global_work_size as u64;
-        
-        while stats.is_running() {
-            // Check limits
-            if max_attempts > 0 && stats.total_keys() >= max_attempts {
-                break;
-            }
-            if max_time > 0 && stats.elapsed().as_secs() >= max_time {
-                break;
-            }
-            
-            // Run full GPU search iteration
-            match opencl_engine.search_ed25519(prefix, suffix, case_sensitive, &config) {
-                Ok(Some(private_key)) => {
-                    // Found a match! Generate full address details
-                    if let Some(addr) = self.chain.generate_from_bytes(&private_key, self.address_type) {
-                        // Verify match on CPU (sanity check)
-                        if self.matcher.matches(&addr.address).is_some() {
-                            stats.mark_found();
-                            let total = stats.total_keys();
-                            let elapsed = start_time.elapsed().as_secs_f64();
-                            
-                            result = Some(SearchResult {
-                                address: addr,
-                                pattern: pattern.clone(),
-                                keys_tested: total,
-                                time_secs: elapsed,
-                                keys_per_second: total as f64 / elapsed,
-                            });
-                            break;
-                        }
+
+        let mut handles = Vec::new();
+        let devices_initialized = Arc::new(AtomicBool::new(false));
+        for &device_index in &device_indices {
+            let stop_flag = stop_flag.clone();
+            let stats = stats.clone();
+            let tx = tx.clone();
+            let pattern = pattern.clone();
+            let max_attempts = self.config.max_attempts;
+            let max_time = self.config.max_time_secs;
+            let devices_initialized = devices_initialized.clone();
+
+            handles.push(thread::spawn(move || {
+                let gpu_config = omnivanity_gpu::GpuSearchConfig {
+                    device_indices: vec![device_index],
+                    max_attempts,
+                    max_time_secs: if max_time > 0 { max_time } else { u64::MAX },
+                    ..Default::default()
+                };
+                let engine = match OpenClEvmEngine::new(device_index, gpu_config) {
+                    Ok(engine) => {
+                        devices_initialized.store(true, Ordering::Relaxed);
+                        engine
                     }
+                    Err(e) => {
+                        info!("OpenCL EVM device {} unavailable ({}), skipping", device_index, e);
+                        return;
+                    }
+                };
+                let pattern_bytes = decode_hex_pattern(&pattern);
+
+                if let Some(gpu_result) = engine.search(&pattern_bytes, stop_flag.clone()) {
+                    let _ = tx.try_send(SearchResult {
+                        address: gpu_result.address,
+                        pattern: pattern.clone(),
+                        keys_tested: stats.total_keys(),
+                        time_secs: stats.elapsed().as_secs_f64(),
+                        keys_per_second: stats.keys_per_second(),
+                        match_counter: None,
+                    });
+                    stop_flag.store(true, Ordering::Relaxed);
+                    stats.mark_found();
                 }
-                Ok(None) => {
-                    // No match this iteration, continue
-                }
-                Err(e) => {
-                    info!("OpenCL error: {}, stopping search", e);
-                    break;
-                }
-            }
-            
-            stats.add_keys(keys_per_iteration);
+            }));
         }
-        
+
+        for handle in handles {
+            let _ = handle.join();
+        }
+
         stats.stop();
         let _ = printer_handle.join();
-        
-        result
+
+        if !devices_initialized.load(Ordering::Relaxed) {
+            info!("OpenCL EVM init failed on every device, falling back to hybrid");
+            #[cfg(feature = "gpu")]
+            {
+                return self.run_gpu_hybrid();
+            }
+            #[cfg(not(feature = "gpu"))]
+            {
+                return self.run_cpu();
+            }
+        }
+
+        rx.try_recv().ok()
     }
 
     /// Run search with a callback for progress
@@ -512,7 +1386,8 @@ impl VanitySearch {
             pool.install(|| {
                 (0..num_threads).into_par_iter().for_each(|_| {
                     let chain = omnivanity_chains::get_chain(&chain).unwrap();
-                    
+                    let chain_prefix = chain.address_prefix(address_type);
+
                     while stats_for_search.is_running() {
                         // Check limits
                         if max_attempts > 0 && stats_for_search.total_keys() >= max_attempts {
@@ -524,10 +1399,14 @@ impl VanitySearch {
                             break;
                         }
 
-                        for _ in 0..batch_size {
-                            let addr = chain.generate(address_type);
-                            
-                            if matcher.matches(&addr.address).is_some() {
+                        // `generate_batch` lets secp256k1 chains that override
+                        // it (EVM, TRON, Bitcoin, DigiByte) walk the whole batch
+                        // with one Montgomery-batched point-addition pass
+                        // instead of `batch_size` individual `generate()`
+                        // scalar multiplications - see
+                        // `Secp256k1Keypair::increment_batch`.
+                        for addr in chain.generate_batch(address_type, batch_size) {
+                            if matcher.matches(&addr.address, chain_prefix).is_some() {
                                 let _ = tx.try_send(addr);
                                 stats_for_search.mark_found();
                                 return;
@@ -562,6 +1441,7 @@ impl VanitySearch {
                 keys_tested: stats.total_keys(),
                 time_secs: stats.elapsed().as_secs_f64(),
                 keys_per_second: stats.keys_per_second(),
+                match_counter: None,
             })
         } else {
             None
@@ -569,6 +1449,22 @@ impl VanitySearch {
     }
 }
 
+/// Decode a `0x`-prefixed (or bare) hex vanity pattern into bytes, for the
+/// CUDA/OpenCL EVM paths' `EvmCudaEngine::search`/`OpenClEvmEngine::search`,
+/// which match against raw address bytes rather than the hex string itself.
+/// Odd-length input (an incomplete trailing nibble, e.g. the user typed
+/// `"0xdea"`) drops the trailing nibble rather than failing outright, since
+/// a vanity prefix only ever needs to match a leading substring of the
+/// address anyway.
+#[cfg(any(feature = "cuda", feature = "opencl"))]
+fn decode_hex_pattern(pattern: &str) -> Vec<u8> {
+    let hex = pattern.trim_start_matches("0x");
+    (0..hex.len() / 2 * 2)
+        .step_by(2)
+        .filter_map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
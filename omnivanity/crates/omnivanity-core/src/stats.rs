@@ -1,5 +1,6 @@
 //! Live search statistics
 
+use serde::{Deserialize, Serialize};
 use std::sync::atomic::{AtomicU64, AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -9,22 +10,46 @@ use std::time::{Duration, Instant};
 pub struct SearchStats {
     /// Total keys tested
     pub keys_tested: AtomicU64,
-    /// Start time 
+    /// Start time
     start_time: Instant,
     /// Whether search is running
     pub running: AtomicBool,
     /// Whether a match was found
     pub found: AtomicBool,
+    /// Incremented once per `snapshot()` call, so a consumer streaming
+    /// `StatsSnapshot`s can detect dropped or out-of-order lines.
+    sample_seq: AtomicU64,
+    /// Per-thread key counters, sized by `with_threads` - empty (and so
+    /// `per_thread_keys_per_second()` reports nothing) for stats created
+    /// with plain `new()`, since not every search loop has a fixed,
+    /// known-up-front thread count to register slots for.
+    thread_keys: Vec<AtomicU64>,
+    /// Highest `SearchConfig::seed` counter reached so far, for a resumable
+    /// search to persist and reload via `--resume`. `u64::MAX` is the "no
+    /// seeded search in progress" sentinel, since every real counter value
+    /// is a valid `u64`.
+    highest_counter: AtomicU64,
 }
 
 impl SearchStats {
-    /// Create new stats
+    /// Create new stats with no per-thread breakdown.
     pub fn new() -> Arc<Self> {
+        Self::with_threads(0)
+    }
+
+    /// Create new stats tracking `num_threads` independent per-thread key
+    /// counters (see `add_keys_for_thread`), so a `snapshot()`'s
+    /// `per_thread_keys_per_second` reflects each thread's real throughput
+    /// instead of the global rate split evenly.
+    pub fn with_threads(num_threads: usize) -> Arc<Self> {
         Arc::new(Self {
             keys_tested: AtomicU64::new(0),
             start_time: Instant::now(),
             running: AtomicBool::new(true),
             found: AtomicBool::new(false),
+            sample_seq: AtomicU64::new(0),
+            thread_keys: (0..num_threads).map(|_| AtomicU64::new(0)).collect(),
+            highest_counter: AtomicU64::new(u64::MAX),
         })
     }
 
@@ -33,11 +58,52 @@ impl SearchStats {
         self.keys_tested.fetch_add(count, Ordering::Relaxed);
     }
 
+    /// Increment keys tested by amount, attributing it to `thread_index` for
+    /// `per_thread_keys_per_second`. `thread_index` is typically
+    /// `rayon::current_thread_index()` or a plain loop counter; out-of-range
+    /// indices (stats created via `new()` instead of `with_threads`) are
+    /// silently ignored since the total is still tracked via `add_keys`.
+    pub fn add_keys_for_thread(&self, thread_index: usize, count: u64) {
+        self.add_keys(count);
+        if let Some(counter) = self.thread_keys.get(thread_index) {
+            counter.fetch_add(count, Ordering::Relaxed);
+        }
+    }
+
+    /// Keys-per-second for each registered thread slot, in the same order
+    /// `with_threads` created them. Empty for stats created via `new()`.
+    pub fn per_thread_keys_per_second(&self) -> Vec<f64> {
+        let elapsed = self.elapsed().as_secs_f64();
+        if elapsed <= 0.0 {
+            return vec![0.0; self.thread_keys.len()];
+        }
+        self.thread_keys
+            .iter()
+            .map(|counter| counter.load(Ordering::Relaxed) as f64 / elapsed)
+            .collect()
+    }
+
     /// Get total keys tested
     pub fn total_keys(&self) -> u64 {
         self.keys_tested.load(Ordering::Relaxed)
     }
 
+    /// Record that a seeded search has reached `counter`, so the highest
+    /// value seen survives for persistence/`--resume` even though multiple
+    /// threads report out of order.
+    pub fn record_counter(&self, counter: u64) {
+        self.highest_counter.fetch_max(counter, Ordering::Relaxed);
+    }
+
+    /// Highest seeded-search counter reached so far, or `None` if this
+    /// search isn't seeded (nothing has called `record_counter` yet).
+    pub fn highest_counter(&self) -> Option<u64> {
+        match self.highest_counter.load(Ordering::Relaxed) {
+            u64::MAX => None,
+            n => Some(n),
+        }
+    }
+
     /// Get elapsed time
     pub fn elapsed(&self) -> Duration {
         self.start_time.elapsed()
@@ -103,6 +169,52 @@ impl SearchStats {
             format_duration(remaining_for_50)
         )
     }
+
+    /// Expected number of keys needed for cumulative match probability to
+    /// reach `target_prob` (0 < target_prob < 1), assuming matches arrive as
+    /// a Poisson process with rate `1 / difficulty`: `-difficulty *
+    /// ln(1 - target_prob)`. Returns the remaining *time* (not keys) at the
+    /// current rate, or `None` if that can't be estimated (no difficulty,
+    /// no throughput yet, or `target_prob` outside `0..1`). Once the target
+    /// has already been passed this returns `Some(0.0)`.
+    fn eta_for_probability(&self, difficulty: f64, target_prob: f64) -> Option<f64> {
+        let kps = self.keys_per_second();
+        if difficulty <= 0.0 || kps <= 0.0 || !(0.0..1.0).contains(&target_prob) {
+            return None;
+        }
+        let keys_needed = -difficulty * (1.0 - target_prob).ln();
+        let remaining_keys = keys_needed - self.total_keys() as f64;
+        Some((remaining_keys / kps).max(0.0))
+    }
+
+    /// Take a structured, JSON-serializable snapshot of progress right now -
+    /// the machine-readable counterpart to `format`'s fixed human string,
+    /// meant for `ReporterHook` to stream as NDJSON. Bumps `sample_seq`
+    /// every call, so a consumer can tell snapshots apart even if two land
+    /// in the same millisecond.
+    pub fn snapshot(&self, difficulty: f64) -> StatsSnapshot {
+        let keys = self.total_keys();
+        let probability = if difficulty > 0.0 {
+            1.0 - (-1.0 * keys as f64 / difficulty).exp()
+        } else {
+            0.0
+        };
+
+        StatsSnapshot {
+            sample_seq: self.sample_seq.fetch_add(1, Ordering::Relaxed),
+            keys_tested: keys,
+            elapsed_secs: self.elapsed().as_secs_f64(),
+            keys_per_second: self.keys_per_second(),
+            difficulty,
+            probability,
+            eta_50_secs: self.eta_for_probability(difficulty, 0.5),
+            eta_95_secs: self.eta_for_probability(difficulty, 0.95),
+            per_thread_keys_per_second: self.per_thread_keys_per_second(),
+            running: self.is_running(),
+            found: self.is_found(),
+            highest_counter: self.highest_counter(),
+        }
+    }
 }
 
 impl Default for SearchStats {
@@ -112,6 +224,70 @@ impl Default for SearchStats {
             start_time: Instant::now(),
             running: AtomicBool::new(true),
             found: AtomicBool::new(false),
+            sample_seq: AtomicU64::new(0),
+            thread_keys: Vec::new(),
+            highest_counter: AtomicU64::new(u64::MAX),
+        }
+    }
+}
+
+/// A structured, JSON-serializable snapshot of search progress at one
+/// instant - the machine-readable counterpart to `SearchStats::format`'s
+/// fixed human string, produced by `SearchStats::snapshot` and consumed by
+/// a `ReporterHook` for NDJSON progress streaming (one of these per line).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatsSnapshot {
+    /// Monotonically increasing per-`SearchStats` counter, bumped once per
+    /// snapshot - lets a consumer detect dropped or out-of-order lines.
+    pub sample_seq: u64,
+    pub keys_tested: u64,
+    pub elapsed_secs: f64,
+    pub keys_per_second: f64,
+    pub difficulty: f64,
+    /// Cumulative probability of a match so far, assuming a Poisson process.
+    pub probability: f64,
+    /// Seconds until cumulative probability reaches 50%, at the current
+    /// rate. `None` if it can't be estimated yet (see `eta_for_probability`).
+    pub eta_50_secs: Option<f64>,
+    /// Same as `eta_50_secs`, for 95% instead of 50%.
+    pub eta_95_secs: Option<f64>,
+    /// Keys-per-second per registered thread slot (see
+    /// `SearchStats::with_threads`); empty if the search didn't register any.
+    pub per_thread_keys_per_second: Vec<f64>,
+    pub running: bool,
+    pub found: bool,
+    /// Highest `SearchConfig::seed` counter reached so far - persist this
+    /// (alongside the seed itself) to resume a seeded search later. `None`
+    /// if the search isn't seeded.
+    pub highest_counter: Option<u64>,
+}
+
+impl StatsSnapshot {
+    /// Serialize as a single-line JSON object, suitable for one NDJSON
+    /// progress line.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+}
+
+/// Callback the search engine invokes at a configurable interval with a
+/// `StatsSnapshot`, so integrators can stream NDJSON progress (one JSON
+/// object per line) to stdout, a socket, or anywhere else instead of
+/// scraping `SearchStats::format`'s fixed human string - mirroring how RPC
+/// layers expose structured responses alongside a human-readable one.
+pub trait ReporterHook: Send + Sync {
+    /// Called once per reporting interval with the latest snapshot.
+    fn report(&self, snapshot: &StatsSnapshot);
+}
+
+/// The simplest `ReporterHook`: writes one NDJSON line per snapshot to
+/// stdout.
+pub struct StdoutNdjsonReporter;
+
+impl ReporterHook for StdoutNdjsonReporter {
+    fn report(&self, snapshot: &StatsSnapshot) {
+        if let Ok(line) = snapshot.to_json() {
+            println!("{line}");
         }
     }
 }
@@ -148,3 +324,69 @@ fn format_duration(seconds: f64) -> String {
         format!("{:.1}y", seconds / (86400.0 * 365.0))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_sample_seq_is_monotonic() {
+        let stats = SearchStats::new();
+        let a = stats.snapshot(1_000.0);
+        let b = stats.snapshot(1_000.0);
+        assert!(b.sample_seq > a.sample_seq);
+    }
+
+    #[test]
+    fn test_snapshot_to_json_round_trips() {
+        let stats = SearchStats::new();
+        stats.add_keys(42);
+        let snapshot = stats.snapshot(1_000_000.0);
+        let json = snapshot.to_json().unwrap();
+        let parsed: StatsSnapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.keys_tested, 42);
+        assert_eq!(parsed.sample_seq, snapshot.sample_seq);
+    }
+
+    #[test]
+    fn test_with_threads_tracks_per_thread_rates_independently() {
+        let stats = SearchStats::with_threads(2);
+        stats.add_keys_for_thread(0, 100);
+        stats.add_keys_for_thread(1, 300);
+        assert_eq!(stats.total_keys(), 400);
+
+        let rates = stats.per_thread_keys_per_second();
+        assert_eq!(rates.len(), 2);
+        assert!(rates[1] > rates[0]);
+    }
+
+    #[test]
+    fn test_eta_95_is_further_out_than_eta_50() {
+        let stats = SearchStats::new();
+        stats.add_keys(1);
+        // Sleep-free: keys_per_second only needs *some* elapsed time to be
+        // nonzero, which `SearchStats::new()` already guarantees just by
+        // the time this assertion runs.
+        let snapshot = stats.snapshot(1_000_000_000.0);
+        if let (Some(eta_50), Some(eta_95)) = (snapshot.eta_50_secs, snapshot.eta_95_secs) {
+            assert!(eta_95 > eta_50);
+        }
+    }
+
+    #[test]
+    fn test_stdout_reporter_does_not_panic_on_a_fresh_snapshot() {
+        let stats = SearchStats::new();
+        let reporter = StdoutNdjsonReporter;
+        reporter.report(&stats.snapshot(500.0));
+    }
+
+    #[test]
+    fn test_highest_counter_is_none_until_recorded() {
+        let stats = SearchStats::new();
+        assert_eq!(stats.highest_counter(), None);
+        stats.record_counter(5);
+        stats.record_counter(2);
+        stats.record_counter(9);
+        assert_eq!(stats.highest_counter(), Some(9));
+    }
+}
@@ -0,0 +1,84 @@
+//! BLS12-381 keypair generation, used for Filecoin protocol-3 (f3) addresses
+//!
+//! Gated behind the `bls12-381` feature so chains/engines that only ever
+//! grind secp256k1-family (f1/f4) vanity addresses don't pull in the curve
+//! dependency - the same reason `omnivanity-gpu`'s `cuda`/`opencl-backend`
+//! features keep GPU-only deps optional.
+
+#![cfg(feature = "bls12-381")]
+
+use bls12_381::{G1Affine, G1Projective, Scalar};
+use ff::Field;
+use group::Curve;
+use rand::rngs::OsRng;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Bls12381Error {
+    #[error("Invalid private key: must be a nonzero 32-byte scalar")]
+    InvalidPrivateKey,
+}
+
+/// A BLS12-381 keypair: a 32-byte scalar private key and its 48-byte
+/// compressed G1 public key - the exact shape Filecoin's f3 protocol needs.
+#[derive(Clone)]
+pub struct Bls12381Keypair {
+    secret_key: Scalar,
+    public_key: G1Affine,
+}
+
+impl Bls12381Keypair {
+    /// Generate a new random keypair
+    pub fn generate() -> Self {
+        let secret_key = Scalar::random(OsRng);
+        let public_key = (G1Projective::generator() * secret_key).to_affine();
+        Self { secret_key, public_key }
+    }
+
+    /// Create from a raw 32-byte scalar private key
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self, Bls12381Error> {
+        let secret_key = Option::from(Scalar::from_bytes(bytes)).ok_or(Bls12381Error::InvalidPrivateKey)?;
+        if bool::from(secret_key.is_zero()) {
+            return Err(Bls12381Error::InvalidPrivateKey);
+        }
+        let public_key = (G1Projective::generator() * secret_key).to_affine();
+        Ok(Self { secret_key, public_key })
+    }
+
+    /// Get the private key as bytes
+    pub fn private_key_bytes(&self) -> [u8; 32] {
+        self.secret_key.to_bytes()
+    }
+
+    /// Get the compressed G1 public key (48 bytes) - the raw f3 address
+    /// payload, unlike f1's hashed secp256k1 payload.
+    pub fn public_key_compressed(&self) -> [u8; 48] {
+        self.public_key.to_compressed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keypair_generation() {
+        let kp = Bls12381Keypair::generate();
+        assert_eq!(kp.private_key_bytes().len(), 32);
+        assert_eq!(kp.public_key_compressed().len(), 48);
+    }
+
+    #[test]
+    fn test_from_bytes_is_deterministic() {
+        let mut bytes = [0u8; 32];
+        bytes[31] = 5;
+        let a = Bls12381Keypair::from_bytes(&bytes).unwrap();
+        let b = Bls12381Keypair::from_bytes(&bytes).unwrap();
+        assert_eq!(a.public_key_compressed(), b.public_key_compressed());
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_zero_scalar() {
+        assert!(Bls12381Keypair::from_bytes(&[0u8; 32]).is_err());
+    }
+}
@@ -0,0 +1,137 @@
+//! Deterministic "brain wallet" key derivation from a user passphrase
+//!
+//! Inspired by ethkey's Brain/BrainPrefix/brain_recover: instead of the OS
+//! RNG, the private key comes from iterating a hash over the passphrase
+//! thousands of times, which makes a memorable phrase stand in for a random
+//! seed (at the cost of the usual brain-wallet security caveats - this is a
+//! convenience/recovery tool, not a recommended way to generate production
+//! keys).
+
+use crate::hash::sha256;
+use sha2::Sha256;
+
+/// Number of SHA-256 rounds applied to the passphrase before it's accepted
+/// as a private key. Matches the "thousands of rounds" ethkey uses to make
+/// brute-forcing a single guess more expensive.
+pub const BRAIN_ROUNDS: u32 = 16_384;
+
+/// secp256k1 group order, used to reject scalars that would wrap around
+/// (the canonical-encoding check below is equivalent to `scalar < n`).
+fn is_valid_secp256k1_scalar(bytes: &[u8; 32]) -> bool {
+    use k256::elliptic_curve::Field;
+    match Option::<k256::Scalar>::from(k256::Scalar::from_bytes(bytes.into())) {
+        Some(scalar) => !bool::from(Field::is_zero(&scalar)),
+        None => false,
+    }
+}
+
+/// Iterate SHA-256 over `passphrase` `rounds` times, re-hashing on every
+/// round (not just re-hashing the previous digest once) so the round count
+/// is the actual brute-force cost of trying one guess.
+fn iterate_hash(passphrase: &str, rounds: u32) -> [u8; 32] {
+    let mut digest = sha256(passphrase.as_bytes());
+    for _ in 1..rounds.max(1) {
+        digest = sha256(&digest);
+    }
+    digest
+}
+
+/// Derive a 32-byte secp256k1 private key deterministically from `passphrase`,
+/// rejecting (by re-hashing once more) any digest that isn't a valid
+/// scalar below the curve order.
+pub fn brain_secp256k1_bytes(passphrase: &str) -> [u8; 32] {
+    let mut candidate = iterate_hash(passphrase, BRAIN_ROUNDS);
+    while !is_valid_secp256k1_scalar(&candidate) {
+        candidate = sha256(&candidate);
+    }
+    candidate
+}
+
+/// Derive a 32-byte Ed25519 seed deterministically from `passphrase`.
+/// Ed25519 seeds are hashed internally by the signing algorithm itself, so
+/// unlike secp256k1 there's no invalid-scalar range to reject.
+pub fn brain_ed25519_bytes(passphrase: &str) -> [u8; 32] {
+    iterate_hash(passphrase, BRAIN_ROUNDS)
+}
+
+/// Minimum PBKDF2 iteration count for `Chain::generate_from_passphrase`,
+/// chosen to make a single guess meaningfully expensive (unlike the
+/// brain-wallet search above, which needs cheap iterations to try millions
+/// of phrases).
+pub const PASSPHRASE_PBKDF2_ROUNDS: u32 = 262_144;
+
+/// Stretch `passphrase` into 32 bytes of key material via PBKDF2-HMAC-SHA256,
+/// using a fixed domain-separation salt (`"omnivanity-brain"` plus `domain`,
+/// typically the chain ticker) so the same passphrase yields different key
+/// material on different chains.
+fn stretch_passphrase(passphrase: &str, domain: &str) -> [u8; 32] {
+    let salt = format!("omnivanity-brain{domain}");
+    let mut out = [0u8; 32];
+    pbkdf2::pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), salt.as_bytes(), PASSPHRASE_PBKDF2_ROUNDS, &mut out);
+    out
+}
+
+/// Derive a 32-byte Ed25519 seed from `passphrase` for `Chain::generate_from_passphrase`.
+/// Ed25519 accepts any 32 bytes as a seed, so the stretched output is used directly.
+pub fn passphrase_ed25519_bytes(passphrase: &str, domain: &str) -> [u8; 32] {
+    stretch_passphrase(passphrase, domain)
+}
+
+/// Derive a 32-byte secp256k1 private key from `passphrase` for
+/// `Chain::generate_from_passphrase`, re-stretching with an incremented
+/// counter byte appended to the salt until the candidate is a valid
+/// (nonzero, below curve order) scalar.
+pub fn passphrase_secp256k1_bytes(passphrase: &str, domain: &str) -> [u8; 32] {
+    let mut counter: u32 = 0;
+    loop {
+        let salted_domain = format!("{domain}{counter}");
+        let candidate = stretch_passphrase(passphrase, &salted_domain);
+        if is_valid_secp256k1_scalar(&candidate) {
+            return candidate;
+        }
+        counter += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_brain_secp256k1_is_deterministic() {
+        let a = brain_secp256k1_bytes("correct horse battery staple");
+        let b = brain_secp256k1_bytes("correct horse battery staple");
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_brain_secp256k1_differs_per_passphrase() {
+        let a = brain_secp256k1_bytes("correct horse battery staple");
+        let b = brain_secp256k1_bytes("correct horse battery staple!");
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_brain_ed25519_is_deterministic() {
+        let a = brain_ed25519_bytes("correct horse battery staple");
+        let b = brain_ed25519_bytes("correct horse battery staple");
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_passphrase_ed25519_is_deterministic_and_domain_separated() {
+        let a = passphrase_ed25519_bytes("correct horse battery staple", "BTC");
+        let b = passphrase_ed25519_bytes("correct horse battery staple", "BTC");
+        let c = passphrase_ed25519_bytes("correct horse battery staple", "ETH");
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_passphrase_secp256k1_is_deterministic_and_valid_scalar() {
+        let a = passphrase_secp256k1_bytes("correct horse battery staple", "ATOM");
+        let b = passphrase_secp256k1_bytes("correct horse battery staple", "ATOM");
+        assert_eq!(a, b);
+        assert!(is_valid_secp256k1_scalar(&a));
+    }
+}
@@ -1,6 +1,6 @@
 //! Ed25519 elliptic curve operations for Solana
 
-use ed25519_dalek::{SigningKey, VerifyingKey};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
 use rand::rngs::OsRng;
 use thiserror::Error;
 
@@ -48,6 +48,25 @@ impl Ed25519Keypair {
     pub fn public_key_bytes(&self) -> [u8; 32] {
         self.signing_key.verifying_key().to_bytes()
     }
+
+    /// Sign `message`, producing a detached 64-byte Ed25519 signature - no
+    /// message prefix or recovery scheme needed, unlike the secp256k1
+    /// chains' Bitcoin/Ethereum-style signed messages.
+    pub fn sign(&self, message: &[u8]) -> [u8; 64] {
+        self.signing_key.sign(message).to_bytes()
+    }
+}
+
+/// Verify a detached 64-byte Ed25519 `signature` over `message` against a
+/// raw 32-byte public key, without needing the private key. Returns
+/// `false` (rather than an error) for any malformed input, since callers
+/// only ever care whether the signature is valid.
+pub fn verify(public_key: &[u8; 32], message: &[u8], signature: &[u8; 64]) -> bool {
+    let Ok(verifying_key) = VerifyingKey::from_bytes(public_key) else {
+        return false;
+    };
+    let signature = Signature::from_bytes(signature);
+    verifying_key.verify(message, &signature).is_ok()
 }
 
 #[cfg(test)]
@@ -69,4 +88,18 @@ mod tests {
         let kp2 = Ed25519Keypair::from_bytes(&seed).unwrap();
         assert_eq!(kp1.public_key_bytes(), kp2.public_key_bytes());
     }
+
+    #[test]
+    fn test_sign_and_verify_round_trip() {
+        let kp = Ed25519Keypair::from_bytes(&[3u8; 32]).unwrap();
+        let signature = kp.sign(b"hello omnivanity");
+        assert!(verify(&kp.public_key_bytes(), b"hello omnivanity", &signature));
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_message() {
+        let kp = Ed25519Keypair::from_bytes(&[3u8; 32]).unwrap();
+        let signature = kp.sign(b"hello omnivanity");
+        assert!(!verify(&kp.public_key_bytes(), b"goodbye omnivanity", &signature));
+    }
 }
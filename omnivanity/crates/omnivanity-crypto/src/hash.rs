@@ -68,6 +68,19 @@ pub fn blake2b_224(data: &[u8]) -> [u8; 28] {
     output
 }
 
+/// Blake2b with an arbitrary output length up to 64 bytes (used by
+/// Penumbra's F4Jumble round function, which needs 40-byte outputs rather
+/// than one of the fixed widths above).
+pub fn blake2b_var(data: &[u8], len: usize) -> Vec<u8> {
+    use blake2::digest::VariableOutput;
+    use blake2::Blake2bVar;
+    let mut hasher = Blake2bVar::new(len).unwrap();
+    blake2::digest::Update::update(&mut hasher, data);
+    let mut output = vec![0u8; len];
+    hasher.finalize_variable(&mut output).unwrap();
+    output
+}
+
 /// SHA3-256 (used in Aptos - note: different from Keccak-256!)
 pub fn sha3_256(data: &[u8]) -> [u8; 32] {
     use sha3::{Sha3_256, Digest};
@@ -76,6 +89,18 @@ pub fn sha3_256(data: &[u8]) -> [u8; 32] {
     hasher.finalize().into()
 }
 
+/// BIP-340 tagged hash: `SHA256(SHA256(tag) || SHA256(tag) || msg)`. Domain-
+/// separates SHA-256 for a given purpose (e.g. Taproot's `"TapTweak"`)
+/// without a dedicated MAC construction.
+pub fn tagged_hash(tag: &str, msg: &[u8]) -> [u8; 32] {
+    let tag_hash = sha256(tag.as_bytes());
+    let mut input = Vec::with_capacity(64 + msg.len());
+    input.extend_from_slice(&tag_hash);
+    input.extend_from_slice(&tag_hash);
+    input.extend_from_slice(msg);
+    sha256(&input)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -120,6 +145,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_blake2b_var_matches_fixed_width_helpers() {
+        // Same underlying construction as blake2b_256/blake2b_160 at their
+        // respective widths, just parameterized.
+        assert_eq!(blake2b_var(b"hello", 32), blake2b_256(b"hello").to_vec());
+        assert_eq!(blake2b_var(b"hello", 20), blake2b_160(b"hello").to_vec());
+    }
+
     #[test]
     fn test_sha3_256() {
         // SHA3-256 of empty string (different from Keccak!)
@@ -129,4 +162,13 @@ mod tests {
             "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a"
         );
     }
+
+    #[test]
+    fn test_tagged_hash_is_deterministic_and_tag_dependent() {
+        let a = tagged_hash("TapTweak", b"hello");
+        let b = tagged_hash("TapTweak", b"hello");
+        let c = tagged_hash("TapLeaf", b"hello");
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
 }
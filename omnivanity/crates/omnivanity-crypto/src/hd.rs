@@ -0,0 +1,324 @@
+//! BIP39 mnemonic seed phrases + BIP32 (secp256k1) / SLIP-0010 (Ed25519) HD derivation
+//!
+//! Every adapter's `generate_from_bytes` takes a raw 32-byte key with no
+//! deterministic way to produce one from a seed phrase. This module bridges
+//! that gap: turn a mnemonic into a 64-byte seed, then walk a derivation
+//! path like `m/44'/60'/0'/0/0` down to a 32-byte child key that can be fed
+//! straight into a chain adapter.
+
+use hmac::{Hmac, Mac};
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use k256::elliptic_curve::Field;
+use sha2::Sha512;
+use thiserror::Error;
+
+type HmacSha512 = Hmac<Sha512>;
+
+#[derive(Error, Debug)]
+pub enum HdError {
+    #[error("invalid derivation path: {0}")]
+    InvalidPath(String),
+    #[error("invalid mnemonic")]
+    InvalidMnemonic,
+    #[error("derivation produced an invalid key")]
+    InvalidKey,
+    #[error("SLIP-0010 Ed25519 derivation only supports hardened steps")]
+    NonHardenedEd25519,
+    #[error("unsupported mnemonic word count: {0} (expected 12 or 24)")]
+    UnsupportedWordCount(u32),
+}
+
+/// Generate a new random 12-word (128-bit entropy) BIP39 mnemonic
+pub fn generate_mnemonic() -> String {
+    generate_mnemonic_words(12).expect("12 is a valid BIP39 word count")
+}
+
+/// Generate a new random BIP39 mnemonic with `word_count` words (12 for
+/// 128-bit entropy, 24 for 256-bit entropy; BIP39 also allows 15/18/21 but
+/// this crate only exposes the two most common sizes).
+pub fn generate_mnemonic_words(word_count: u32) -> Result<String, HdError> {
+    if word_count != 12 && word_count != 24 {
+        return Err(HdError::UnsupportedWordCount(word_count));
+    }
+    bip39::Mnemonic::generate(word_count as usize)
+        .map(|m| m.to_string())
+        .map_err(|_| HdError::InvalidMnemonic)
+}
+
+/// Validate a user-supplied mnemonic phrase
+pub fn parse_mnemonic(phrase: &str) -> Result<bip39::Mnemonic, HdError> {
+    bip39::Mnemonic::parse_normalized(phrase).map_err(|_| HdError::InvalidMnemonic)
+}
+
+/// BIP39 seed: PBKDF2-HMAC-SHA512(mnemonic, salt = "mnemonic" || passphrase, 2048 iterations)
+pub fn mnemonic_to_seed(mnemonic: &str, passphrase: &str) -> Result<[u8; 64], HdError> {
+    let normalized = parse_mnemonic(mnemonic)?;
+    let salt = format!("mnemonic{passphrase}");
+    let mut seed = [0u8; 64];
+    pbkdf2::pbkdf2_hmac::<Sha512>(normalized.to_string().as_bytes(), salt.as_bytes(), 2048, &mut seed);
+    Ok(seed)
+}
+
+/// One `'`/`h`-suffixed or plain index in a derivation path
+#[derive(Debug, Clone, Copy)]
+pub struct PathStep {
+    pub index: u32,
+    pub hardened: bool,
+}
+
+/// Parse a path like `m/44'/60'/0'/0/0` into its steps
+pub fn parse_path(path: &str) -> Result<Vec<PathStep>, HdError> {
+    let trimmed = path.strip_prefix("m/").or_else(|| path.strip_prefix('m')).unwrap_or(path);
+    if trimmed.is_empty() {
+        return Ok(vec![]);
+    }
+    trimmed
+        .split('/')
+        .map(|segment| {
+            let hardened = segment.ends_with('\'') || segment.ends_with('h');
+            let digits = segment.trim_end_matches(['\'', 'h']);
+            digits
+                .parse::<u32>()
+                .map(|index| PathStep { index, hardened })
+                .map_err(|_| HdError::InvalidPath(path.to_string()))
+        })
+        .collect()
+}
+
+struct ExtendedKey {
+    key: [u8; 32],
+    chain_code: [u8; 32],
+}
+
+/// A BIP32 [`ExtendedKey`] plus the xpub-serialization metadata that SLIP-0010
+/// has no equivalent for - kept out of the shared `ExtendedKey` so Ed25519
+/// derivation doesn't carry secp256k1 xpub fields it never serializes.
+struct Bip32ExtendedKey {
+    inner: ExtendedKey,
+    /// How many CKD-priv steps produced this key (0 for the master key).
+    depth: u8,
+    parent_fingerprint: [u8; 4],
+    child_number: u32,
+}
+
+fn hmac_sha512(key: &[u8], msg: &[&[u8]]) -> [u8; 64] {
+    let mut mac = HmacSha512::new_from_slice(key).expect("HMAC accepts keys of any length");
+    for part in msg {
+        mac.update(part);
+    }
+    let out = mac.finalize().into_bytes();
+    let mut result = [0u8; 64];
+    result.copy_from_slice(&out);
+    result
+}
+
+// ---- BIP32 (secp256k1), used by ETH/BTC/LTC/DOGE/etc. ----------------------
+
+fn bip32_master(seed: &[u8]) -> Bip32ExtendedKey {
+    let i = hmac_sha512(b"Bitcoin seed", &[seed]);
+    let mut key = [0u8; 32];
+    let mut chain_code = [0u8; 32];
+    key.copy_from_slice(&i[..32]);
+    chain_code.copy_from_slice(&i[32..]);
+    Bip32ExtendedKey { inner: ExtendedKey { key, chain_code }, depth: 0, parent_fingerprint: [0; 4], child_number: 0 }
+}
+
+fn bip32_ckd_priv(parent: &Bip32ExtendedKey, step: PathStep) -> Result<Bip32ExtendedKey, HdError> {
+    let index = step.index | if step.hardened { 0x8000_0000 } else { 0 };
+    let index_be = index.to_be_bytes();
+
+    let parent_secret = k256::SecretKey::from_bytes((&parent.inner.key).into()).map_err(|_| HdError::InvalidKey)?;
+    let parent_pubkey = parent_secret.public_key().to_encoded_point(true);
+
+    let i = if step.hardened {
+        hmac_sha512(&parent.inner.chain_code, &[&[0u8], &parent.inner.key, &index_be])
+    } else {
+        hmac_sha512(&parent.inner.chain_code, &[parent_pubkey.as_bytes(), &index_be])
+    };
+
+    let il: [u8; 32] = i[..32].try_into().unwrap();
+    let parent_scalar = Option::<k256::Scalar>::from(k256::Scalar::from_bytes(&parent.inner.key.into()))
+        .ok_or(HdError::InvalidKey)?;
+    let il_scalar = Option::<k256::Scalar>::from(k256::Scalar::from_bytes(&il.into())).ok_or(HdError::InvalidKey)?;
+    let child_scalar = il_scalar + parent_scalar;
+    if bool::from(Field::is_zero(&child_scalar)) {
+        return Err(HdError::InvalidKey);
+    }
+
+    let mut key = [0u8; 32];
+    key.copy_from_slice(&child_scalar.to_bytes());
+    let mut chain_code = [0u8; 32];
+    chain_code.copy_from_slice(&i[32..]);
+
+    let fingerprint = crate::hash::hash160(parent_pubkey.as_bytes());
+    let mut parent_fingerprint = [0u8; 4];
+    parent_fingerprint.copy_from_slice(&fingerprint[..4]);
+
+    Ok(Bip32ExtendedKey {
+        inner: ExtendedKey { key, chain_code },
+        depth: parent.depth.wrapping_add(1),
+        parent_fingerprint,
+        child_number: index,
+    })
+}
+
+/// Derive a 32-byte secp256k1 private key from a BIP39 seed along `path`
+pub fn derive_bip32(seed: &[u8], path: &str) -> Result<[u8; 32], HdError> {
+    Ok(derive_bip32_extended(seed, path)?.0)
+}
+
+/// Like [`derive_bip32`], but also returns the base58check-encoded extended
+/// public key (`xpub...`) for the derived key - the shareable descriptor a
+/// wallet shows next to a receive address at this path. Mainnet version
+/// bytes only (`0x0488B21E`); this repo has no testnet xpub callers yet.
+pub fn derive_bip32_extended(seed: &[u8], path: &str) -> Result<([u8; 32], String), HdError> {
+    let steps = parse_path(path)?;
+    let mut current = bip32_master(seed);
+    for step in steps {
+        current = bip32_ckd_priv(&current, step)?;
+    }
+    let xpub = serialize_xpub(&current)?;
+    Ok((current.inner.key, xpub))
+}
+
+fn serialize_xpub(key: &Bip32ExtendedKey) -> Result<String, HdError> {
+    let secret = k256::SecretKey::from_bytes((&key.inner.key).into()).map_err(|_| HdError::InvalidKey)?;
+    let pubkey_compressed = secret.public_key().to_encoded_point(true);
+
+    let mut data = Vec::with_capacity(78);
+    data.extend_from_slice(&0x0488_B21Eu32.to_be_bytes());
+    data.push(key.depth);
+    data.extend_from_slice(&key.parent_fingerprint);
+    data.extend_from_slice(&key.child_number.to_be_bytes());
+    data.extend_from_slice(&key.inner.chain_code);
+    data.extend_from_slice(pubkey_compressed.as_bytes());
+
+    let checksum = crate::hash::double_sha256(&data);
+    data.extend_from_slice(&checksum[..4]);
+    Ok(bs58::encode(data).into_string())
+}
+
+// ---- SLIP-0010 (Ed25519), used by Aptos/Algorand/Stellar/Monero's spend key -
+
+fn slip10_master_ed25519(seed: &[u8]) -> ExtendedKey {
+    let i = hmac_sha512(b"ed25519 seed", &[seed]);
+    let mut key = [0u8; 32];
+    let mut chain_code = [0u8; 32];
+    key.copy_from_slice(&i[..32]);
+    chain_code.copy_from_slice(&i[32..]);
+    ExtendedKey { key, chain_code }
+}
+
+fn slip10_ckd_ed25519(parent: &ExtendedKey, step: PathStep) -> Result<ExtendedKey, HdError> {
+    if !step.hardened {
+        return Err(HdError::NonHardenedEd25519);
+    }
+    let index_be = (step.index | 0x8000_0000).to_be_bytes();
+    let i = hmac_sha512(&parent.chain_code, &[&[0u8], &parent.key, &index_be]);
+    let mut key = [0u8; 32];
+    let mut chain_code = [0u8; 32];
+    key.copy_from_slice(&i[..32]);
+    chain_code.copy_from_slice(&i[32..]);
+    Ok(ExtendedKey { key, chain_code })
+}
+
+/// Derive a 32-byte Ed25519 seed from a BIP39 seed along `path`.
+/// SLIP-0010's Ed25519 variant is hardened-only (no public-key point math),
+/// so every path segment must use `'`/`h`.
+pub fn derive_slip10_ed25519(seed: &[u8], path: &str) -> Result<[u8; 32], HdError> {
+    let steps = parse_path(path)?;
+    let mut current = slip10_master_ed25519(seed);
+    for step in steps {
+        current = slip10_ckd_ed25519(&current, step)?;
+    }
+    Ok(current.key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_path() {
+        let steps = parse_path("m/44'/60'/0'/0/0").unwrap();
+        assert_eq!(steps.len(), 5);
+        assert_eq!(steps[0].index, 44);
+        assert!(steps[0].hardened);
+        assert_eq!(steps[4].index, 0);
+        assert!(!steps[4].hardened);
+    }
+
+    #[test]
+    fn test_bip32_vector_1_master_and_hardened_child() {
+        // BIP32 test vector 1: seed 000102030405060708090a0b0c0d0e0f
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let master = bip32_master(&seed);
+        assert_eq!(
+            hex::encode(master.inner.key),
+            "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35"
+        );
+
+        let child = derive_bip32(&seed, "m/0'").unwrap();
+        assert_eq!(
+            hex::encode(child),
+            "edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea"
+        );
+    }
+
+    #[test]
+    fn test_bip32_vector_1_xpub() {
+        // BIP32 test vector 1: seed 000102030405060708090a0b0c0d0e0f, m/0'
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let (_, xpub) = derive_bip32_extended(&seed, "m/0'").unwrap();
+        assert_eq!(
+            xpub,
+            "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw"
+        );
+    }
+
+    #[test]
+    fn test_mnemonic_roundtrip() {
+        let mnemonic = generate_mnemonic();
+        assert_eq!(mnemonic.split_whitespace().count(), 12);
+        let seed = mnemonic_to_seed(&mnemonic, "").unwrap();
+        assert_eq!(seed.len(), 64);
+    }
+
+    #[test]
+    fn test_generate_mnemonic_words_24() {
+        let mnemonic = generate_mnemonic_words(24).unwrap();
+        assert_eq!(mnemonic.split_whitespace().count(), 24);
+    }
+
+    #[test]
+    fn test_generate_mnemonic_words_rejects_odd_count() {
+        assert!(matches!(generate_mnemonic_words(15), Err(HdError::UnsupportedWordCount(15))));
+    }
+
+    #[test]
+    fn test_slip10_requires_hardened_steps() {
+        let seed = [0u8; 32];
+        assert!(matches!(
+            derive_slip10_ed25519(&seed, "m/44'/0"),
+            Err(HdError::NonHardenedEd25519)
+        ));
+    }
+
+    #[test]
+    fn test_derive_slip10_ed25519_end_to_end() {
+        // Exercises `slip10_master_ed25519`/`slip10_ckd_ed25519` for real
+        // (unlike `test_slip10_requires_hardened_steps`, which returns
+        // before a key is ever constructed) - same seed+path always derives
+        // the same key, and changing either the seed or the path changes it.
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let a = derive_slip10_ed25519(&seed, "m/44'/501'/0'").unwrap();
+        let b = derive_slip10_ed25519(&seed, "m/44'/501'/0'").unwrap();
+        assert_eq!(a, b);
+
+        let different_path = derive_slip10_ed25519(&seed, "m/44'/501'/1'").unwrap();
+        assert_ne!(a, different_path);
+
+        let different_seed = derive_slip10_ed25519(&[1u8; 16], "m/44'/501'/0'").unwrap();
+        assert_ne!(a, different_seed);
+    }
+}
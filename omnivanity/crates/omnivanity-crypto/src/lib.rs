@@ -4,11 +4,29 @@
 
 pub mod secp256k1;
 pub mod ed25519;
+pub mod p256;
+#[cfg(feature = "bls12-381")]
+pub mod bls12_381;
 pub mod hash;
 pub mod encoding;
+pub mod hd;
+pub mod brain;
+pub mod seeded;
 
-pub use self::secp256k1::Secp256k1Keypair;
+pub use self::secp256k1::{combine_split_key, decompress_public_key, PublicPointWalker, Secp256k1Error, Secp256k1Keypair};
 pub use self::ed25519::Ed25519Keypair;
+pub use self::p256::P256Keypair;
+#[cfg(feature = "bls12-381")]
+pub use self::bls12_381::{Bls12381Error, Bls12381Keypair};
+pub use self::hd::{
+    generate_mnemonic, generate_mnemonic_words, mnemonic_to_seed, derive_bip32, derive_bip32_extended,
+    derive_slip10_ed25519, HdError,
+};
+pub use self::brain::{
+    brain_secp256k1_bytes, brain_ed25519_bytes, BRAIN_ROUNDS,
+    passphrase_secp256k1_bytes, passphrase_ed25519_bytes, PASSPHRASE_PBKDF2_ROUNDS,
+};
+pub use self::seeded::derive_key_from_counter;
 
 // Re-export dependencies for use by other crates
 pub use bs58;
@@ -22,6 +22,41 @@ pub fn generate_key_image(scalar: &Scalar) -> [u8; 32] {
     point.compress().to_bytes()
 }
 
+/// Decompress a 32-byte Monero public key into a curve point
+pub fn decompress_point(bytes: &[u8; 32]) -> Option<EdwardsPoint> {
+    curve25519_dalek::edwards::CompressedEdwardsY(*bytes).decompress()
+}
+
+/// Subaddress secret scalar:
+/// m = reduce(keccak256("SubAddr\0" || view_secret || account_index_le32 || subaddress_index_le32))
+pub fn subaddress_scalar(view_secret: &Scalar, account_index: u32, subaddress_index: u32) -> Scalar {
+    let mut data = Vec::with_capacity(8 + 32 + 4 + 4);
+    data.extend_from_slice(b"SubAddr\0");
+    data.extend_from_slice(&view_secret.to_bytes());
+    data.extend_from_slice(&account_index.to_le_bytes());
+    data.extend_from_slice(&subaddress_index.to_le_bytes());
+
+    let mut hasher = Keccak256::new();
+    hasher.update(&data);
+    let hash: [u8; 32] = hasher.finalize().into();
+    Scalar::from_bytes_mod_order(hash)
+}
+
+/// Derive a subaddress's spend/view public keys (account 0, index 0 is the
+/// primary address and is never actually routed through this path):
+/// `D = spend_public + m*G`, `C = view_secret*D`
+pub fn subaddress_keys(
+    spend_public: &EdwardsPoint,
+    view_secret: &Scalar,
+    account_index: u32,
+    subaddress_index: u32,
+) -> ([u8; 32], [u8; 32]) {
+    let m = subaddress_scalar(view_secret, account_index, subaddress_index);
+    let d = spend_public + (ED25519_BASEPOINT_POINT * m);
+    let c = d * view_secret;
+    (d.compress().to_bytes(), c.compress().to_bytes())
+}
+
 // Monero Base58 Encoding (block-based)
 pub mod base58_monero {
     use super::*;
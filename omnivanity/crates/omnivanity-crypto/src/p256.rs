@@ -0,0 +1,67 @@
+//! P-256 (secp256r1) elliptic curve operations, used for Tezos tz3 addresses
+
+use p256::{elliptic_curve::rand_core::OsRng, PublicKey, SecretKey};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum P256Error {
+    #[error("Invalid private key")]
+    InvalidPrivateKey,
+}
+
+/// A P-256 keypair for ECDSA operations
+#[derive(Clone)]
+pub struct P256Keypair {
+    secret_key: SecretKey,
+    public_key: PublicKey,
+}
+
+impl P256Keypair {
+    /// Generate a new random keypair
+    pub fn generate() -> Self {
+        let secret_key = SecretKey::random(&mut OsRng);
+        let public_key = secret_key.public_key();
+        Self { secret_key, public_key }
+    }
+
+    /// Create from raw 32-byte private key
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self, P256Error> {
+        let secret_key = SecretKey::from_bytes(bytes.into())
+            .map_err(|_| P256Error::InvalidPrivateKey)?;
+        let public_key = secret_key.public_key();
+        Ok(Self { secret_key, public_key })
+    }
+
+    /// Get the private key as bytes
+    pub fn private_key_bytes(&self) -> [u8; 32] {
+        self.secret_key.to_bytes().into()
+    }
+
+    /// Get the compressed public key (33 bytes: 0x02/0x03 || x)
+    pub fn public_key_compressed(&self) -> [u8; 33] {
+        use p256::elliptic_curve::sec1::ToEncodedPoint;
+        let point = self.public_key.to_encoded_point(true);
+        let mut result = [0u8; 33];
+        result.copy_from_slice(point.as_bytes());
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keypair_generation() {
+        let kp = P256Keypair::generate();
+        assert_eq!(kp.private_key_bytes().len(), 32);
+        assert_eq!(kp.public_key_compressed().len(), 33);
+    }
+
+    #[test]
+    fn test_from_bytes_is_deterministic() {
+        let a = P256Keypair::from_bytes(&[5u8; 32]).unwrap();
+        let b = P256Keypair::from_bytes(&[5u8; 32]).unwrap();
+        assert_eq!(a.public_key_compressed(), b.public_key_compressed());
+    }
+}
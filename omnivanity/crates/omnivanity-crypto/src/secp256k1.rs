@@ -1,7 +1,7 @@
 //! secp256k1 elliptic curve operations for BTC, ETH, LTC, DOGE, ZEC
 
 use k256::{
-    ecdsa::SigningKey,
+    ecdsa::{RecoveryId, Signature, SigningKey, VerifyingKey},
     elliptic_curve::rand_core::OsRng,
     PublicKey, SecretKey,
 };
@@ -13,6 +13,10 @@ pub enum Secp256k1Error {
     InvalidPrivateKey,
     #[error("Key generation failed")]
     KeyGenFailed,
+    #[error("Signing failed")]
+    SigningFailed,
+    #[error("Invalid signature")]
+    InvalidSignature,
 }
 
 /// A secp256k1 keypair for ECDSA operations
@@ -38,6 +42,20 @@ impl Secp256k1Keypair {
         Ok(Self { secret_key, public_key })
     }
 
+    /// Rebuild a keypair from an already-known private key and uncompressed
+    /// public key, skipping the scalar multiplication `from_bytes`/`generate`
+    /// would otherwise do to recompute the public key. Used to resume an
+    /// [`increment`](Self::increment) walk from a previously-derived
+    /// candidate (e.g. `EvmChain::generate_next`) without paying for a
+    /// fresh scalar multiplication on every step.
+    pub fn from_raw_parts(secret_bytes: &[u8; 32], public_uncompressed: &[u8; 65]) -> Result<Self, Secp256k1Error> {
+        let secret_key = SecretKey::from_bytes(secret_bytes.into())
+            .map_err(|_| Secp256k1Error::InvalidPrivateKey)?;
+        let public_key = PublicKey::from_sec1_bytes(public_uncompressed)
+            .map_err(|_| Secp256k1Error::InvalidPrivateKey)?;
+        Ok(Self { secret_key, public_key })
+    }
+
     /// Get the private key as bytes
     pub fn private_key_bytes(&self) -> [u8; 32] {
         self.secret_key.to_bytes().into()
@@ -68,6 +86,401 @@ impl Secp256k1Keypair {
         result.copy_from_slice(&uncompressed[1..65]);
         result
     }
+
+    /// Walk to the next keypair by adding the generator `G` to this one's
+    /// public point, instead of picking a fresh random scalar and redoing a
+    /// full scalar multiplication. The new private key is simply
+    /// `self + 1`, so a caller stepping through `N` candidates from one
+    /// random base pays for one scalar multiplication (the base keypair)
+    /// plus `N` point additions, which is far cheaper than `N` scalar
+    /// multiplications. This is the CPU mirror of the GPU incremental-walk
+    /// kernel (`omnivanity-gpu/src/kernels/evm_kernel.cu`).
+    ///
+    /// Returns `None` on the two edge cases a pure counter walk can hit:
+    /// the point landing on infinity, or the new point sharing an
+    /// x-coordinate with the one it was added to (the vertical-line case
+    /// the affine addition formula can't handle) - both astronomically
+    /// unlikely for a 256-bit curve, but cheap to guard against. Either way
+    /// the caller should fall back to a fresh `Secp256k1Keypair::generate()`.
+    pub fn increment(&self) -> Option<Self> {
+        use k256::elliptic_curve::sec1::ToEncodedPoint;
+        use k256::elliptic_curve::Field;
+        use k256::{ProjectivePoint, Scalar};
+
+        let base_x = self.public_key.as_affine().to_encoded_point(true).x().map(|x| x.to_vec());
+
+        let scalar = *self.secret_key.to_nonzero_scalar() + Scalar::ONE;
+        let point = ProjectivePoint::from(*self.public_key.as_affine()) + ProjectivePoint::GENERATOR;
+        let affine = point.to_affine();
+        let next_x = affine.to_encoded_point(true).x().map(|x| x.to_vec());
+
+        if next_x.is_none() || next_x == base_x {
+            return None; // point at infinity, or a duplicate x-coordinate
+        }
+
+        let mut secret_bytes = [0u8; 32];
+        secret_bytes.copy_from_slice(&scalar.to_bytes());
+        let mut public_bytes = [0u8; 65];
+        public_bytes.copy_from_slice(affine.to_encoded_point(false).as_bytes());
+
+        Self::from_raw_parts(&secret_bytes, &public_bytes).ok()
+    }
+
+    /// BIP341 key-path-only Taproot output key: the 32-byte x-only point
+    /// `Q = P + t·G`, where `P` is this keypair's internal key (negating the
+    /// secret first if `P` has odd Y, so the tweak always applies to an
+    /// even-Y point) and `t = tagged_hash("TapTweak", x_only(P))`.
+    pub fn taproot_output_key(&self) -> [u8; 32] {
+        use k256::elliptic_curve::sec1::ToEncodedPoint;
+        use k256::elliptic_curve::Field;
+        use k256::{ProjectivePoint, Scalar};
+
+        let mut scalar = *self.secret_key.to_nonzero_scalar();
+        let is_odd_y = self.public_key.as_affine().to_encoded_point(true).as_bytes()[0] == 0x03;
+        if is_odd_y {
+            scalar = -scalar;
+        }
+
+        let internal_point = (ProjectivePoint::GENERATOR * scalar).to_affine();
+        let mut x_only = [0u8; 32];
+        x_only.copy_from_slice(internal_point.to_encoded_point(true).x().expect("affine point always has x"));
+
+        let tweak_hash = crate::hash::tagged_hash("TapTweak", &x_only);
+        let tweak = Option::<Scalar>::from(Scalar::from_bytes(tweak_hash.as_slice().into())).unwrap_or(Scalar::ZERO);
+
+        let output_point = (ProjectivePoint::from(internal_point) + ProjectivePoint::GENERATOR * tweak).to_affine();
+        let mut result = [0u8; 32];
+        result.copy_from_slice(output_point.to_encoded_point(true).x().expect("affine point always has x"));
+        result
+    }
+
+    /// Walk `count` sequential candidates after this keypair - `self+1,
+    /// self+2, …, self+count` - the same walk [`increment`](Self::increment)
+    /// does one step at a time, but without paying for a field inversion on
+    /// every step. Each step's point addition is carried out in Jacobian
+    /// coordinates (`X, Y, Z` instead of plain affine `x, y`), which needs
+    /// only field multiplications/squarings and no inversion; the entire
+    /// batch's `Z` coordinates are then normalized back to affine together
+    /// with Montgomery's trick - compute running prefix products
+    /// `a_i = Z_0·Z_1·…·Z_i`, invert the final product once, then
+    /// back-substitute `inv(Z_i) = a_{i-1}·inv(a_i)` - turning `count`
+    /// inversions into 1 plus roughly `3·count` extra multiplications. This
+    /// is the CPU analogue of the GPU kernels' own batched-inversion search
+    /// loop (see `omnivanity-gpu/src/kernels/evm_kernel.cu`).
+    ///
+    /// Stops early - returning fewer than `count` keypairs - when the
+    /// walk's current point shares an x-coordinate with the generator (the
+    /// vertical-line case the addition formula can't handle, which also
+    /// covers the point landing on infinity). This is a different check
+    /// from the one [`increment`](Self::increment) makes. Callers should top up any
+    /// shortfall with a fresh [`generate`](Self::generate).
+    ///
+    /// Batches the whole walk through one Montgomery inversion; for very
+    /// large `count` [`increment_batch_windowed`](Self::increment_batch_windowed)
+    /// trades that off against peak memory by inverting in smaller windows.
+    pub fn increment_batch(&self, count: usize) -> Vec<Self> {
+        self.increment_batch_windowed(count, count.max(1))
+    }
+
+    /// Like [`increment_batch`](Self::increment_batch), but inverts in
+    /// windows of `window` steps instead of one inversion over the entire
+    /// `count` - holding at most `window` Jacobian points (96 bytes each)
+    /// in memory at a time rather than all of `count`. More inversions
+    /// overall (`count / window` instead of 1), but bounds the working set
+    /// to whatever fits comfortably in cache for very large batches. Still
+    /// stops early on the same vertical-line/infinity edge case as
+    /// `increment_batch`, window boundary or not.
+    pub fn increment_batch_windowed(&self, count: usize, window: usize) -> Vec<Self> {
+        use k256::elliptic_curve::sec1::ToEncodedPoint;
+        use k256::Scalar;
+
+        if count == 0 || window == 0 {
+            return Vec::new();
+        }
+
+        let base = self.public_key.as_affine().to_encoded_point(false);
+        let (mut cursor_x, mut cursor_y) = affine_field_coords(&base);
+        let mut scalar = *self.secret_key.to_nonzero_scalar();
+
+        let mut keypairs = Vec::with_capacity(count);
+        let mut remaining = count;
+        while remaining > 0 {
+            let this_window = remaining.min(window);
+            let jacobian = walk_generator_additions(cursor_x, cursor_y, this_window);
+            let affine_points = normalize_jacobian_batch(&jacobian);
+            let produced = affine_points.len();
+
+            for (affine_x, affine_y) in &affine_points {
+                scalar += Scalar::ONE;
+
+                let mut secret_bytes = [0u8; 32];
+                secret_bytes.copy_from_slice(&scalar.to_bytes());
+                let mut public_bytes = [0u8; 65];
+                public_bytes[0] = 0x04;
+                public_bytes[1..33].copy_from_slice(&affine_x.to_bytes());
+                public_bytes[33..65].copy_from_slice(&affine_y.to_bytes());
+
+                if let Ok(kp) = Self::from_raw_parts(&secret_bytes, &public_bytes) {
+                    keypairs.push(kp);
+                }
+            }
+
+            // Hit the vertical-line/infinity edge case before filling the
+            // window - stop for good, same as the unwindowed walk does.
+            if produced < this_window {
+                break;
+            }
+            let (last_x, last_y) = affine_points[produced - 1];
+            cursor_x = last_x;
+            cursor_y = last_y;
+            remaining -= produced;
+        }
+        keypairs
+    }
+
+    /// Produce a recoverable ECDSA signature over a pre-hashed 32-byte
+    /// digest - the caller hashes the actual message first (double-SHA256
+    /// for a Bitcoin Signed Message, Keccak256 for Ethereum's
+    /// `personal_sign`). Returns the 64-byte compact `(r, s)` signature
+    /// plus a recovery id, so [`recover_public_key_from_prehash`] can
+    /// recover the signer's public key from the signature alone without it
+    /// ever being transmitted alongside.
+    pub fn sign_prehash_recoverable(&self, prehash: &[u8; 32]) -> Result<([u8; 64], u8), Secp256k1Error> {
+        use k256::ecdsa::signature::hazmat::PrehashSigner;
+
+        let signing_key =
+            SigningKey::from_bytes(&self.secret_key.to_bytes()).map_err(|_| Secp256k1Error::SigningFailed)?;
+        let (signature, recovery_id): (Signature, RecoveryId) = signing_key
+            .sign_prehash_recoverable(prehash)
+            .map_err(|_| Secp256k1Error::SigningFailed)?;
+        Ok((signature.to_bytes().into(), recovery_id.to_byte()))
+    }
+}
+
+/// Recover the 65-byte uncompressed public key that produced `signature`
+/// over `prehash`, given the `recovery_id` [`Secp256k1Keypair::sign_prehash_recoverable`]
+/// returned alongside it. This is how a Bitcoin/Ethereum-style signed
+/// message is verified without needing the signer's public key up front -
+/// the address derived from the recovered key either matches the claimed
+/// signer's address or it doesn't.
+pub fn recover_public_key_from_prehash(
+    prehash: &[u8; 32],
+    signature: &[u8; 64],
+    recovery_id: u8,
+) -> Result<[u8; 65], Secp256k1Error> {
+    use k256::elliptic_curve::sec1::ToEncodedPoint;
+
+    let signature = Signature::from_slice(signature).map_err(|_| Secp256k1Error::InvalidSignature)?;
+    let recovery_id = RecoveryId::from_byte(recovery_id).ok_or(Secp256k1Error::InvalidSignature)?;
+    let verifying_key = VerifyingKey::recover_from_prehash(prehash, &signature, recovery_id)
+        .map_err(|_| Secp256k1Error::InvalidSignature)?;
+
+    let point = verifying_key.to_encoded_point(false);
+    let mut result = [0u8; 65];
+    result.copy_from_slice(point.as_bytes());
+    Ok(result)
+}
+
+/// Expand a compressed (33-byte) SEC1 point back to its uncompressed
+/// (65-byte) `0x04 || x || y` form. Used by split-key address derivation
+/// (see `Chain::address_from_public_key`), which only ever has the
+/// compressed points [`PublicPointWalker::increment_batch`] produces but
+/// needs the raw `x || y` pair for chains like EVM that hash it directly.
+pub fn decompress_public_key(compressed: &[u8; 33]) -> Result<[u8; 65], Secp256k1Error> {
+    use k256::elliptic_curve::sec1::ToEncodedPoint;
+
+    let point = PublicKey::from_sec1_bytes(compressed).map_err(|_| Secp256k1Error::InvalidPrivateKey)?;
+    let encoded = point.to_encoded_point(false);
+    let mut result = [0u8; 65];
+    result.copy_from_slice(encoded.as_bytes());
+    Ok(result)
+}
+
+/// Pull the `x`/`y` field elements out of an uncompressed SEC1-encoded
+/// point, shared by every walk below that needs to feed a starting affine
+/// point into [`walk_generator_additions`].
+fn affine_field_coords(
+    encoded: &k256::elliptic_curve::sec1::EncodedPoint<k256::Secp256k1>,
+) -> (k256::FieldElement, k256::FieldElement) {
+    use k256::FieldElement;
+
+    let x = Option::<FieldElement>::from(FieldElement::from_bytes(encoded.x().expect("affine point has x")))
+        .expect("x is a valid field element");
+    let y = Option::<FieldElement>::from(FieldElement::from_bytes(encoded.y().expect("affine point has y")))
+        .expect("y is a valid field element");
+    (x, y)
+}
+
+/// Walk `count` steps of `start + G, start + 2G, …` in Jacobian coordinates,
+/// stopping early (returning fewer than `count` points) on the
+/// vertical-line edge case described on [`Secp256k1Keypair::increment_batch`].
+/// Shared by that method and [`PublicPointWalker::increment_batch`] - the
+/// split-key search mode needs the exact same generator walk, just without
+/// a private scalar to track alongside it.
+fn walk_generator_additions(
+    start_x: k256::FieldElement,
+    start_y: k256::FieldElement,
+    count: usize,
+) -> Vec<(k256::FieldElement, k256::FieldElement, k256::FieldElement)> {
+    use k256::{elliptic_curve::sec1::ToEncodedPoint, AffinePoint, FieldElement};
+
+    let generator = AffinePoint::GENERATOR.to_encoded_point(false);
+    let (gx, gy) = affine_field_coords(&generator);
+
+    let mut x = start_x;
+    let mut y = start_y;
+    let mut z = FieldElement::ONE;
+
+    let mut jacobian = Vec::with_capacity(count);
+    for _ in 0..count {
+        // Mixed Jacobian + affine addition against the generator
+        // (madd-2007-bl, specialized for secp256k1's a=0): cheap because
+        // the generator's own Z is always 1.
+        let z1z1 = z.square();
+        let u2 = gx * z1z1;
+        let h = u2 - x;
+        if bool::from(h.is_zero()) {
+            break; // doubling/infinity edge case - caller falls back to a fresh point
+        }
+        let s2 = gy * z * z1z1;
+        let hh = h.square();
+        let i = hh.double().double();
+        let j = h * i;
+        let r = (s2 - y).double();
+        let v = x * i;
+        let x3 = r.square() - j - v.double();
+        let y3 = r * (v - x3) - (y * j).double();
+        let z3 = (z + h).square() - z1z1 - hh;
+
+        x = x3;
+        y = y3;
+        z = z3;
+        jacobian.push((x, y, z));
+    }
+    jacobian
+}
+
+/// Normalize a batch of Jacobian points back to affine `(x, y)` coordinates
+/// with a single field inversion, using Montgomery's trick: compute running
+/// prefix products `a_i = Z_0·Z_1·…·Z_i`, invert the final product once,
+/// then back-substitute `inv(Z_i) = a_{i-1}·inv(a_i)`.
+fn normalize_jacobian_batch(
+    jacobian: &[(k256::FieldElement, k256::FieldElement, k256::FieldElement)],
+) -> Vec<(k256::FieldElement, k256::FieldElement)> {
+    use k256::FieldElement;
+
+    if jacobian.is_empty() {
+        return Vec::new();
+    }
+
+    let mut prefix = Vec::with_capacity(jacobian.len());
+    let mut running = FieldElement::ONE;
+    for (_, _, zi) in jacobian {
+        running *= zi;
+        prefix.push(running);
+    }
+    let mut inv_running = Option::<FieldElement>::from(running.invert()).expect("batch Z product should be invertible");
+
+    let mut affine = vec![(FieldElement::ZERO, FieldElement::ZERO); jacobian.len()];
+    for idx in (0..jacobian.len()).rev() {
+        let (xi, yi, zi) = jacobian[idx];
+        let z_inv = if idx == 0 { inv_running } else { inv_running * prefix[idx - 1] };
+        inv_running *= zi;
+
+        let z_inv2 = z_inv.square();
+        let z_inv3 = z_inv2 * z_inv;
+        affine[idx] = (xi * z_inv2, yi * z_inv3);
+    }
+    affine
+}
+
+/// A public point with no known private scalar, walked the same way
+/// [`Secp256k1Keypair::increment_batch`] walks a keypair it does have the
+/// scalar for. This backs the split-key ("delegated") vanity grinding mode:
+/// an owner hands a worker only the compressed point `P = p·G`, the worker
+/// searches offsets `d = 1, 2, …` by walking `P + d·G` here, and on a hit
+/// reports back just the offset - the owner alone can recover the real key
+/// as `(p + d) mod n` via [`combine_split_key`].
+#[derive(Clone, Copy)]
+pub struct PublicPointWalker {
+    point: PublicKey,
+}
+
+impl PublicPointWalker {
+    /// Parse a compressed (33-byte) or uncompressed (65-byte) SEC1 point.
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self, Secp256k1Error> {
+        let point = PublicKey::from_sec1_bytes(bytes).map_err(|_| Secp256k1Error::InvalidPrivateKey)?;
+        Ok(Self { point })
+    }
+
+    /// Compressed (33-byte) SEC1 encoding of this point.
+    pub fn to_sec1_bytes(&self) -> [u8; 33] {
+        use k256::elliptic_curve::sec1::ToEncodedPoint;
+        let encoded = self.point.as_affine().to_encoded_point(true);
+        let mut result = [0u8; 33];
+        result.copy_from_slice(encoded.as_bytes());
+        result
+    }
+
+    /// Walk `count` sequential offsets after this point - `P+G, P+2G, …,
+    /// P+count·G` - with the same batched Montgomery inversion
+    /// [`Secp256k1Keypair::increment_batch`] uses. Returns the compressed
+    /// point at each step, in walk order; may return fewer than `count`
+    /// entries on the edge case described there.
+    pub fn increment_batch(&self, count: usize) -> Vec<[u8; 33]> {
+        use k256::elliptic_curve::sec1::ToEncodedPoint;
+
+        let base = self.point.as_affine().to_encoded_point(false);
+        let (start_x, start_y) = affine_field_coords(&base);
+
+        let jacobian = walk_generator_additions(start_x, start_y, count);
+        normalize_jacobian_batch(&jacobian)
+            .into_iter()
+            .map(|(x, y)| {
+                // Compressed-point prefix is just the parity of `y` - the
+                // same bit `to_encoded_point(true)` would derive internally.
+                let is_odd_y = y.to_bytes()[31] & 1 == 1;
+                let mut result = [0u8; 33];
+                result[0] = if is_odd_y { 0x03 } else { 0x02 };
+                result[1..].copy_from_slice(&x.to_bytes());
+                result
+            })
+            .collect()
+    }
+
+    /// Jump straight to `P + offset·G` via one scalar multiplication,
+    /// instead of `offset` individual `+G` steps. Used to give each search
+    /// worker thread an independent starting point before it walks its own
+    /// chunk with [`increment_batch`](Self::increment_batch), the same way
+    /// `Secp256k1Keypair::generate` plus a walk seeds each CPU thread in
+    /// `VanitySearch::run_cpu`.
+    pub fn offset_by(&self, offset: u64) -> Self {
+        use k256::{ProjectivePoint, Scalar};
+
+        let scalar = Scalar::from(offset);
+        let shifted = (ProjectivePoint::from(*self.point.as_affine()) + ProjectivePoint::GENERATOR * scalar).to_affine();
+        Self { point: PublicKey::from_affine(shifted).expect("offsetting a valid point stays on the curve") }
+    }
+}
+
+/// Recover the owner's real private key `(secret + offset) mod n` at the
+/// end of a split-key search - `secret` is the scalar only the owner ever
+/// holds, `offset` is the `d` a worker found via
+/// [`PublicPointWalker::increment_batch`] without ever seeing `secret`.
+pub fn combine_split_key(secret: &[u8; 32], offset: &[u8; 32]) -> Result<[u8; 32], Secp256k1Error> {
+    use k256::elliptic_curve::Field;
+    use k256::Scalar;
+
+    let secret_scalar =
+        Option::<Scalar>::from(Scalar::from_bytes(secret.into())).ok_or(Secp256k1Error::InvalidPrivateKey)?;
+    let offset_scalar =
+        Option::<Scalar>::from(Scalar::from_bytes(offset.into())).ok_or(Secp256k1Error::InvalidPrivateKey)?;
+
+    let combined = secret_scalar + offset_scalar;
+    if bool::from(combined.is_zero()) {
+        return Err(Secp256k1Error::InvalidPrivateKey);
+    }
+    Ok(combined.to_bytes().into())
 }
 
 #[cfg(test)]
@@ -92,11 +505,134 @@ mod tests {
         
         let kp = Secp256k1Keypair::from_bytes(&privkey).unwrap();
         let pubkey = kp.public_key_uncompressed();
-        
+
         // Generator point G
         assert_eq!(
             hex::encode(&pubkey[1..33]),
             "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
         );
     }
+
+    #[test]
+    fn test_increment_matches_fresh_keypair_for_next_scalar() {
+        let mut privkey = [0u8; 32];
+        privkey[31] = 1;
+        let base = Secp256k1Keypair::from_bytes(&privkey).unwrap();
+        let next = base.increment().unwrap();
+
+        privkey[31] = 2;
+        let expected = Secp256k1Keypair::from_bytes(&privkey).unwrap();
+
+        assert_eq!(next.private_key_bytes(), expected.private_key_bytes());
+        assert_eq!(next.public_key_uncompressed(), expected.public_key_uncompressed());
+    }
+
+    #[test]
+    fn test_increment_walk_stays_consistent_with_generate_from_bytes() {
+        let base = Secp256k1Keypair::generate();
+        let mut walker = base.clone();
+        for _ in 0..16 {
+            walker = walker.increment().expect("walk should not hit an edge case in 16 steps");
+        }
+
+        let recomputed = Secp256k1Keypair::from_bytes(&walker.private_key_bytes()).unwrap();
+        assert_eq!(walker.public_key_uncompressed(), recomputed.public_key_uncompressed());
+    }
+
+    #[test]
+    fn test_taproot_output_key_is_deterministic() {
+        let kp = Secp256k1Keypair::from_bytes(&[1u8; 32]).unwrap();
+        let a = kp.taproot_output_key();
+        let b = kp.taproot_output_key();
+        assert_eq!(a, b);
+        assert_eq!(a.len(), 32);
+    }
+
+    #[test]
+    fn test_increment_batch_matches_stepwise_increment() {
+        let base = Secp256k1Keypair::generate();
+        let batched = base.increment_batch(16);
+        assert_eq!(batched.len(), 16);
+
+        let mut walker = base;
+        for expected in &batched {
+            walker = walker.increment().expect("walk should not hit an edge case in 16 steps");
+            assert_eq!(walker.private_key_bytes(), expected.private_key_bytes());
+            assert_eq!(walker.public_key_uncompressed(), expected.public_key_uncompressed());
+        }
+    }
+
+    #[test]
+    fn test_increment_batch_of_zero_is_empty() {
+        let base = Secp256k1Keypair::generate();
+        assert!(base.increment_batch(0).is_empty());
+    }
+
+    #[test]
+    fn test_increment_batch_windowed_matches_unwindowed() {
+        let base = Secp256k1Keypair::generate();
+        let unwindowed = base.increment_batch(37);
+        let windowed = base.increment_batch_windowed(37, 10);
+
+        assert_eq!(unwindowed.len(), windowed.len());
+        for (a, b) in unwindowed.iter().zip(windowed.iter()) {
+            assert_eq!(a.private_key_bytes(), b.private_key_bytes());
+            assert_eq!(a.public_key_uncompressed(), b.public_key_uncompressed());
+        }
+    }
+
+    #[test]
+    fn test_decompress_public_key_round_trips() {
+        let kp = Secp256k1Keypair::generate();
+        let decompressed = decompress_public_key(&kp.public_key_compressed()).unwrap();
+        assert_eq!(decompressed, kp.public_key_uncompressed());
+    }
+
+    #[test]
+    fn test_public_point_walker_matches_keypair_walk() {
+        let base = Secp256k1Keypair::generate();
+        let walker = PublicPointWalker::from_sec1_bytes(&base.public_key_compressed()).unwrap();
+
+        let expected = base.increment_batch(8);
+        let offsets = walker.increment_batch(8);
+
+        assert_eq!(offsets.len(), expected.len());
+        for (offset_point, keypair) in offsets.iter().zip(expected.iter()) {
+            assert_eq!(*offset_point, keypair.public_key_compressed());
+        }
+    }
+
+    #[test]
+    fn test_combine_split_key_recovers_offset_keypair() {
+        let owner = Secp256k1Keypair::from_bytes(&[9u8; 32]).unwrap();
+        let walked = owner.increment_batch(5);
+        let offset_keypair = walked.last().unwrap();
+
+        // The offset from `owner` to the fifth walked keypair is 5.
+        let mut offset_bytes = [0u8; 32];
+        offset_bytes[31] = 5;
+
+        let recovered = combine_split_key(&owner.private_key_bytes(), &offset_bytes).unwrap();
+        assert_eq!(recovered, offset_keypair.private_key_bytes());
+    }
+
+    #[test]
+    fn test_sign_prehash_recoverable_round_trips_to_same_public_key() {
+        let kp = Secp256k1Keypair::from_bytes(&[7u8; 32]).unwrap();
+        let prehash = [42u8; 32];
+
+        let (signature, recovery_id) = kp.sign_prehash_recoverable(&prehash).unwrap();
+        let recovered = recover_public_key_from_prehash(&prehash, &signature, recovery_id).unwrap();
+
+        assert_eq!(recovered, kp.public_key_uncompressed());
+    }
+
+    #[test]
+    fn test_recover_public_key_rejects_signature_over_a_different_message() {
+        let kp = Secp256k1Keypair::from_bytes(&[7u8; 32]).unwrap();
+        let (signature, recovery_id) = kp.sign_prehash_recoverable(&[42u8; 32]).unwrap();
+
+        let recovered = recover_public_key_from_prehash(&[43u8; 32], &signature, recovery_id).unwrap();
+        assert_ne!(recovered, kp.public_key_uncompressed());
+    }
 }
@@ -0,0 +1,172 @@
+//! Reference CPU implementation of the `GpuVanitySearch` trait
+//!
+//! `EvmCudaEngine`/`OpenClEvmEngine`/`UtxoCudaEngine` each implement
+//! `GpuVanitySearch` for one chain family behind a GPU kernel. `CpuVanitySearch`
+//! implements the same trait surface for *any* chain `omnivanity_chains::get_chain`
+//! knows about, so `registry::resolve_engine` always has something to fall
+//! back to - honoring the same `max_attempts`/`max_time_secs` limits and
+//! reporting `keys_tested`/`keys_per_second`/`found_on_device` the GPU
+//! engines do, just driven by `Chain::generate_batch` (the Montgomery-batched
+//! secp256k1 generators, where a chain has them) and a plain
+//! prefix matcher instead of a compute kernel.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+use rayon::prelude::*;
+
+use omnivanity_chains::{get_chain, AddressType, Chain};
+
+use crate::search::{GpuSearchConfig, GpuSearchResult, GpuVanitySearch};
+
+/// Batch size each worker generates per round before checking `stop_flag`/
+/// the attempt and time limits - mirrors `omnivanity_core::SearchConfig`'s
+/// default `batch_size` of 1000.
+const BATCH_SIZE: usize = 1000;
+
+/// CPU reference engine for `ticker` - works for every chain/address type
+/// combination `get_chain(ticker)` supports, not just the ones with a GPU
+/// kernel.
+pub struct CpuVanitySearch {
+    ticker: &'static str,
+}
+
+impl CpuVanitySearch {
+    /// Build a CPU engine for `ticker`. Returns `None` if `ticker` isn't a
+    /// chain `omnivanity_chains::get_chain` recognizes.
+    pub fn new(ticker: &'static str) -> Option<Self> {
+        get_chain(ticker)?;
+        Some(Self { ticker })
+    }
+
+    /// Strip `chain`'s real address prefix and check for a literal (case-
+    /// sensitive) leading match - same convention `UtxoCudaEngine`/
+    /// `EvmCudaEngine::search` use for their raw prefix bytes.
+    fn matches(chain: &dyn Chain, address_type: AddressType, address: &str, pattern: &str) -> bool {
+        let chain_prefix = chain.address_prefix(address_type);
+        address.strip_prefix(chain_prefix).unwrap_or(address).starts_with(pattern)
+    }
+}
+
+impl GpuVanitySearch for CpuVanitySearch {
+    fn chain(&self) -> &'static str {
+        self.ticker
+    }
+
+    fn address_types(&self) -> Vec<AddressType> {
+        get_chain(self.ticker).map(|c| c.address_types()).unwrap_or_default()
+    }
+
+    fn search(
+        &self,
+        pattern: &str,
+        address_type: AddressType,
+        config: &GpuSearchConfig,
+    ) -> Option<GpuSearchResult> {
+        let chain = get_chain(self.ticker)?;
+        let start = Instant::now();
+        let keys_tested = AtomicU64::new(0);
+        let found = AtomicBool::new(false);
+        let result: std::sync::Mutex<Option<omnivanity_chains::GeneratedAddress>> = std::sync::Mutex::new(None);
+        let num_threads = rayon::current_num_threads().max(1);
+
+        rayon::scope(|s| {
+            for _ in 0..num_threads {
+                let keys_tested = &keys_tested;
+                let found = &found;
+                let result = &result;
+                s.spawn(move |_| {
+                    while !found.load(Ordering::Relaxed) {
+                        if config.max_attempts > 0 && keys_tested.load(Ordering::Relaxed) >= config.max_attempts {
+                            return;
+                        }
+                        if config.max_time_secs > 0 && start.elapsed().as_secs() >= config.max_time_secs {
+                            return;
+                        }
+
+                        for candidate in chain.generate_batch(address_type, BATCH_SIZE) {
+                            keys_tested.fetch_add(1, Ordering::Relaxed);
+                            if Self::matches(chain.as_ref(), address_type, &candidate.address, pattern) {
+                                *result.lock().unwrap() = Some(candidate);
+                                found.store(true, Ordering::Relaxed);
+                                return;
+                            }
+                        }
+                    }
+                });
+            }
+        });
+
+        let address = result.into_inner().unwrap()?;
+        let total = keys_tested.load(Ordering::Relaxed);
+        let elapsed = start.elapsed().as_secs_f64();
+        Some(GpuSearchResult {
+            address,
+            pattern: pattern.to_string(),
+            keys_tested: total,
+            time_secs: elapsed,
+            keys_per_second: if elapsed > 0.0 { total as f64 / elapsed } else { 0.0 },
+            found_on_device: config.device_indices.first().copied().unwrap_or(0),
+        })
+    }
+
+    fn benchmark(&self, duration_secs: u64, _config: &GpuSearchConfig) -> f64 {
+        let Some(chain) = get_chain(self.ticker) else { return 0.0 };
+        let address_type = chain.default_address_type();
+        let start = Instant::now();
+        let total_keys = AtomicU64::new(0);
+
+        (0..rayon::current_num_threads().max(1)).into_par_iter().for_each(|_| {
+            while start.elapsed().as_secs() < duration_secs {
+                let batch = chain.generate_batch(address_type, BATCH_SIZE);
+                total_keys.fetch_add(batch.len() as u64, Ordering::Relaxed);
+            }
+        });
+
+        let elapsed = start.elapsed().as_secs_f64();
+        let total = total_keys.load(Ordering::Relaxed);
+        if elapsed > 0.0 { total as f64 / elapsed } else { 0.0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cpu_engine_reports_known_chain_address_types() {
+        let engine = CpuVanitySearch::new("ETH").unwrap();
+        assert_eq!(engine.chain(), "ETH");
+        assert!(engine.address_types().contains(&AddressType::Evm));
+    }
+
+    #[test]
+    fn test_cpu_engine_rejects_unknown_ticker() {
+        assert!(CpuVanitySearch::new("NOT_A_REAL_CHAIN").is_none());
+    }
+
+    #[test]
+    fn test_cpu_engine_finds_single_nibble_prefix() {
+        let engine = CpuVanitySearch::new("ETH").unwrap();
+        let config = GpuSearchConfig { max_attempts: 200_000, ..Default::default() };
+        let result = engine.search("0", AddressType::Evm, &config).unwrap();
+        assert!(result.address.address.strip_prefix("0x").unwrap().starts_with('0'));
+    }
+
+    #[test]
+    fn test_cpu_engine_gives_up_after_max_attempts() {
+        let engine = CpuVanitySearch::new("ETH").unwrap();
+        // No 16-nibble prefix will ever be found within this tiny budget.
+        let config = GpuSearchConfig { max_attempts: 100, ..Default::default() };
+        let result = engine.search("0000000000000000", AddressType::Evm, &config);
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_cpu_engine_benchmark_reports_a_positive_rate() {
+        let engine = CpuVanitySearch::new("ETH").unwrap();
+        let config = GpuSearchConfig::default();
+        assert!(engine.benchmark(1, &config) > 0.0);
+    }
+}
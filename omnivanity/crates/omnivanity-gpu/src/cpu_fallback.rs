@@ -0,0 +1,164 @@
+//! CPU fallback kernels
+//!
+//! Mirrors `wgpu_backend`'s `ShaderKind::Wgpu` path for machines with no
+//! usable GPU adapter (headless CI, software-only VMs) using the same
+//! `Chain`/keccak primitives the rest of the crate already relies on. Slower
+//! than the GPU kernels, but it is a correct reference implementation: GPU
+//! results can be spot-checked against it.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use omnivanity_chains::{AddressType, Chain, ETH};
+
+use crate::search::{GpuSearchConfig, GpuSearchResult};
+use crate::wgpu_backend::{MatchType, PatternSpec};
+
+/// Which kernel implementation a `WgpuEngine` is actually dispatching to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShaderKind {
+    /// Compute shaders dispatched on a real GPU adapter via wgpu.
+    Wgpu,
+    /// Plain-Rust reference kernels, used when no adapter is available or
+    /// `GpuSearchConfig::force_cpu` is set.
+    Cpu,
+}
+
+fn address_matches(address: &str, pattern_bytes: &[u8], pattern_len: usize) -> bool {
+    let body = address.strip_prefix("0x").unwrap_or(address);
+    let Ok(addr_bytes) = omnivanity_crypto::hex::decode(body) else {
+        return false;
+    };
+    let check_len = pattern_len.min(addr_bytes.len()).min(pattern_bytes.len());
+    addr_bytes[..check_len] == pattern_bytes[..check_len]
+}
+
+/// CPU reference implementation of `WgpuEngine::search_evm`: generates fresh
+/// EVM keypairs in a plain loop and checks each one's address against
+/// `pattern`. Same signature/semantics as the GPU path, just single-threaded
+/// and orders of magnitude slower.
+pub fn search_evm_cpu(
+    config: &GpuSearchConfig,
+    pattern: &[u8],
+    pattern_len: usize,
+    stop_flag: Arc<AtomicBool>,
+) -> Option<GpuSearchResult> {
+    let start = Instant::now();
+    let max_time = Duration::from_secs(config.max_time_secs);
+    let mut keys_tested = 0u64;
+
+    loop {
+        if stop_flag.load(Ordering::Relaxed) {
+            break;
+        }
+        if config.max_time_secs > 0 && start.elapsed() > max_time {
+            break;
+        }
+        if config.max_attempts > 0 && keys_tested >= config.max_attempts {
+            break;
+        }
+
+        let candidate = ETH.generate(AddressType::Evm);
+        keys_tested += 1;
+
+        if address_matches(&candidate.address, pattern, pattern_len) {
+            let elapsed = start.elapsed().as_secs_f64();
+            let keys_per_second = if elapsed > 0.0 { keys_tested as f64 / elapsed } else { 0.0 };
+            return Some(GpuSearchResult {
+                address: candidate,
+                pattern: omnivanity_crypto::hex::encode(&pattern[..pattern_len.min(pattern.len())]),
+                keys_tested,
+                time_secs: elapsed,
+                keys_per_second,
+                found_on_device: config.device_indices.first().copied().unwrap_or(0),
+            });
+        }
+    }
+
+    None
+}
+
+/// CPU reference implementation of `WgpuEngine::pattern_match_batch`: a
+/// straightforward linear scan, identical in semantics to the GPU compaction
+/// path but without the PCIe round trip. Returns `(address_index,
+/// spec_index)` for every address matching at least one of `specs`, using
+/// whichever spec matched first (lowest index) - same tie-break the GPU
+/// kernel's per-address loop uses.
+pub fn pattern_match_batch_cpu(addresses: &[String], specs: &[PatternSpec]) -> Vec<(usize, usize)> {
+    let normalize = |s: &str, case_insensitive: bool| if case_insensitive { s.to_lowercase() } else { s.to_string() };
+
+    addresses
+        .iter()
+        .enumerate()
+        .filter_map(|(i, addr)| {
+            specs.iter().enumerate().find_map(|(spec_idx, spec)| {
+                let haystack = normalize(addr, spec.case_insensitive);
+                let needle = normalize(&spec.pattern, spec.case_insensitive);
+                let is_match = match spec.match_type {
+                    MatchType::Prefix => haystack.starts_with(&needle),
+                    MatchType::Suffix => haystack.ends_with(&needle),
+                    MatchType::Contains => haystack.contains(&needle),
+                };
+                is_match.then_some((i, spec_idx))
+            })
+        })
+        .collect()
+}
+
+/// CPU reference implementation of `WgpuEngine::benchmark`: counts how many
+/// fresh EVM keypairs can be generated per second.
+pub fn benchmark_cpu(duration_secs: u64) -> f64 {
+    let start = Instant::now();
+    let max_time = Duration::from_secs(duration_secs);
+    let mut total_keys = 0u64;
+
+    while start.elapsed() < max_time {
+        let _ = ETH.generate(AddressType::Evm);
+        total_keys += 1;
+    }
+
+    let elapsed = start.elapsed().as_secs_f64();
+    if elapsed > 0.0 { total_keys as f64 / elapsed } else { 0.0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(pattern: &str, match_type: MatchType, case_insensitive: bool) -> PatternSpec {
+        PatternSpec { pattern: pattern.to_string(), match_type, case_insensitive }
+    }
+
+    #[test]
+    fn test_pattern_match_batch_cpu_prefix() {
+        let addresses = vec!["0xdead0001".to_string(), "0xbeef0002".to_string()];
+        let matches = pattern_match_batch_cpu(&addresses, &[spec("0xdead", MatchType::Prefix, false)]);
+        assert_eq!(matches, vec![(0, 0)]);
+    }
+
+    #[test]
+    fn test_pattern_match_batch_cpu_case_insensitive() {
+        let addresses = vec!["0xDEAD0001".to_string()];
+        let matches = pattern_match_batch_cpu(&addresses, &[spec("0xdead", MatchType::Prefix, true)]);
+        assert_eq!(matches, vec![(0, 0)]);
+        let no_match = pattern_match_batch_cpu(&addresses, &[spec("0xdead", MatchType::Prefix, false)]);
+        assert!(no_match.is_empty());
+    }
+
+    #[test]
+    fn test_pattern_match_batch_cpu_multi_pattern_reports_winning_spec() {
+        let addresses = vec!["0xdead0001".to_string(), "0xbeef0002".to_string(), "0xcafe0003".to_string()];
+        let specs = [spec("0xdead", MatchType::Prefix, false), spec("0xbeef", MatchType::Prefix, false)];
+        let matches = pattern_match_batch_cpu(&addresses, &specs);
+        assert_eq!(matches, vec![(0, 0), (1, 1)]);
+    }
+
+    #[test]
+    fn test_search_evm_cpu_finds_single_byte_prefix() {
+        let config = GpuSearchConfig { max_attempts: 100_000, ..Default::default() };
+        let pattern = omnivanity_crypto::hex::decode("0").unwrap_or_else(|_| vec![0u8]);
+        let result = search_evm_cpu(&config, &pattern, 1, Arc::new(AtomicBool::new(false)));
+        assert!(result.is_some());
+    }
+}
@@ -17,6 +17,13 @@ pub struct GpuDevice {
     pub multiprocessors: u32,
     /// Backend type (CUDA, OpenCL, etc.)
     pub backend: GpuBackend,
+    /// Which concrete WebGPU implementation enumerated this device (e.g.
+    /// `"wgpu"`), if it came from one - `None` for non-WebGPU backends like
+    /// CUDA. Lets a future alternative implementation (e.g. a Dawn-backed
+    /// `GpuApi`) report itself distinctly from `wgpu-rs` without needing a
+    /// new `GpuBackend` variant, since `GpuBackend` already tracks the
+    /// native graphics API (Vulkan/Metal/Dx12), not the Rust crate driving it.
+    pub api_impl: Option<&'static str>,
 }
 
 /// GPU backend type
@@ -0,0 +1,76 @@
+//! Cross-process per-device GPU locks
+//!
+//! Several `omnivanity --gpu` processes on one box previously just picked
+//! devices independently and silently oversubscribed the same GPU, each
+//! slowing the other down. This takes an advisory exclusive file lock per
+//! device index in the system temp dir (`omnivanity-gpu-<idx>.lock`) before
+//! a search/benchmark claims that device, so a second process sees the lock
+//! held and moves on to the next free index instead - the same exclusive-
+//! device-access pattern GPU-accelerated provers use to avoid accidental 2x
+//! slowdowns from two workers sharing one card.
+
+use std::fs::{File, OpenOptions};
+use std::path::PathBuf;
+
+use fs2::FileExt;
+
+fn lock_path(device_index: usize) -> PathBuf {
+    std::env::temp_dir().join(format!("omnivanity-gpu-{}.lock", device_index))
+}
+
+/// Holds an exclusive advisory lock on one GPU device index for as long as
+/// it's alive. Dropping it (including on panic/early return) releases the
+/// lock - `File`'s own `Drop` closes the descriptor, which the OS treats as
+/// an implicit unlock for `flock`-style advisory locks.
+pub struct DeviceLock {
+    index: usize,
+    _file: File,
+}
+
+impl DeviceLock {
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+/// Try to exclusively lock `device_index`. Returns `None` if another process
+/// already holds it - the caller should move on to the next candidate index
+/// rather than wait, since a GPU search has no use for a device it'd only
+/// get once another process's search finishes anyway.
+pub fn try_lock_device(device_index: usize) -> Option<DeviceLock> {
+    let file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .open(lock_path(device_index))
+        .ok()?;
+    file.try_lock_exclusive().ok()?;
+    Some(DeviceLock {
+        index: device_index,
+        _file: file,
+    })
+}
+
+/// Try to lock every index in `candidates`, in order, skipping any already
+/// held by another process. Used by the CLI to narrow a `--device` list (or
+/// "all available") down to whatever's actually free before a search starts.
+pub fn lock_available(candidates: &[usize]) -> Vec<DeviceLock> {
+    candidates
+        .iter()
+        .filter_map(|&index| try_lock_device(index))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn second_lock_on_same_device_fails_until_first_drops() {
+        // Use a high index unlikely to collide with a real concurrent test run.
+        let index = 9001;
+        let first = try_lock_device(index).expect("first lock should succeed");
+        assert!(try_lock_device(index).is_none(), "second lock should be rejected while first is held");
+        drop(first);
+        assert!(try_lock_device(index).is_some(), "lock should be available again after drop");
+    }
+}
@@ -0,0 +1,96 @@
+//! GPU API shim
+//!
+//! `WgpuEngine` talks to the GPU exclusively through the `wgpu` crate today,
+//! but every call is funneled through the small set of operations below
+//! (buffer allocation, host writes, and "submit + wait for the device").
+//! Pulling those into a `GpuApi` trait - rather than calling `wgpu::Device`/
+//! `wgpu::Queue` directly everywhere - gives us a seam where an alternative
+//! WebGPU implementation (e.g. a Dawn-backed one, selected the same way
+//! `GpuBackend` already distinguishes Vulkan/Metal/Dx12) could be plugged
+//! in without touching the search/match algorithms themselves.
+//!
+//! `WgpuApi` is the default (and today, only) implementation, a thin
+//! pass-through to `wgpu::Device`/`wgpu::Queue`. The compute-heavy paths in
+//! `wgpu_backend` (`search_evm`, `pattern_match_batch`, `benchmark`) still
+//! build their buffers and bind groups with `wgpu` types directly - those
+//! are migrated onto this trait incrementally, buffer op by buffer op,
+//! rather than in one large rewrite.
+//!
+//! `WgpuApi::IMPL_NAME` is the string `list_wgpu_devices()` stamps onto
+//! `GpuDevice::api_impl` and that `GpuSearchConfig::webgpu_impl` compares
+//! against - an alternative implementation (e.g. a Dawn-backed one) would
+//! report its own name through both without touching `GpuBackend`, which
+//! already tracks the native graphics API (Vulkan/Metal/Dx12), not the Rust
+//! crate driving it.
+
+#[cfg(feature = "wgpu-backend")]
+use wgpu::{util::DeviceExt, Buffer, BufferUsages, Device, Queue};
+
+/// The GPU operations `WgpuEngine` needs, independent of which WebGPU
+/// implementation backs them.
+#[cfg(feature = "wgpu-backend")]
+pub trait GpuApi: Send + Sync {
+    /// Opaque handle to a device-resident buffer.
+    type Buffer;
+
+    /// Allocate a zeroed storage buffer of `size` bytes.
+    fn create_storage_buffer(&self, label: &str, size: u64, usage: BufferUsages) -> Self::Buffer;
+
+    /// Allocate a buffer pre-populated with `contents`.
+    fn create_buffer_init(&self, label: &str, contents: &[u8], usage: BufferUsages) -> Self::Buffer;
+
+    /// Write `data` into `buffer` at `offset`, without waiting for the GPU.
+    fn write_buffer(&self, buffer: &Self::Buffer, offset: u64, data: &[u8]);
+
+    /// Block until all previously submitted work has completed.
+    fn wait_idle(&self);
+}
+
+/// Default `GpuApi` implementation: a thin pass-through to `wgpu::Device`/
+/// `wgpu::Queue`, which `WgpuEngine` already owns.
+#[cfg(feature = "wgpu-backend")]
+pub struct WgpuApi<'a> {
+    pub device: &'a Device,
+    pub queue: &'a Queue,
+}
+
+#[cfg(feature = "wgpu-backend")]
+impl<'a> WgpuApi<'a> {
+    /// Name reported through `GpuDevice::api_impl` and `GpuSearchConfig::webgpu_impl`
+    /// for devices enumerated and engines driven through this implementation.
+    pub const IMPL_NAME: &'static str = "wgpu";
+
+    pub fn new(device: &'a Device, queue: &'a Queue) -> Self {
+        Self { device, queue }
+    }
+}
+
+#[cfg(feature = "wgpu-backend")]
+impl<'a> GpuApi for WgpuApi<'a> {
+    type Buffer = Buffer;
+
+    fn create_storage_buffer(&self, label: &str, size: u64, usage: BufferUsages) -> Buffer {
+        self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some(label),
+            size,
+            usage,
+            mapped_at_creation: false,
+        })
+    }
+
+    fn create_buffer_init(&self, label: &str, contents: &[u8], usage: BufferUsages) -> Buffer {
+        self.device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some(label),
+            contents,
+            usage,
+        })
+    }
+
+    fn write_buffer(&self, buffer: &Buffer, offset: u64, data: &[u8]) {
+        self.queue.write_buffer(buffer, offset, data);
+    }
+
+    fn wait_idle(&self) {
+        self.device.poll(wgpu::Maintain::Wait);
+    }
+}
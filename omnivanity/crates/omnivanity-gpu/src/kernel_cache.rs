@@ -0,0 +1,112 @@
+//! Disk cache for compiled GPU kernels
+//!
+//! `EvmCudaEngine::search`/`benchmark` ran `compile_ptx` (NVRTC) and
+//! `OpenClEngine::new` ran `Program::builder().build()` (`clBuildProgram`) on
+//! every single call, even though the kernel source is a compile-time
+//! constant and a device's compute capability rarely changes between runs.
+//! This caches the compiled output to disk, keyed by a hash of the source
+//! plus a device identifier string, so a warm run can skip the compiler
+//! entirely. Mirrors the common GPU-miner practice of shipping/reloading a
+//! `.bin` per device instead of rebuilding the kernel on every launch.
+//! `load`/`store` are used by the OpenCL engines; the CUDA engine still runs
+//! NVRTC on every call and only gets the driver's JIT cache, via
+//! `enable_cuda_jit_cache`.
+
+use std::path::PathBuf;
+use std::sync::Once;
+
+use omnivanity_crypto::hash::sha256;
+use omnivanity_crypto::hex;
+
+/// Directory compiled kernels are cached under -
+/// `dirs::cache_dir()/omnivanity/kernels`, falling back to the system temp
+/// directory if no platform cache directory can be determined.
+fn cache_dir() -> PathBuf {
+    dirs::cache_dir().unwrap_or_else(std::env::temp_dir).join("omnivanity").join("kernels")
+}
+
+/// Cache key for `source` compiled for `device_ident` (e.g. a device name or
+/// compute-capability string). A source or device change naturally produces
+/// a different key, so a stale cache entry is simply never looked up again -
+/// no explicit invalidation step is needed.
+fn cache_key(source: &str, device_ident: &str) -> String {
+    let mut data = Vec::with_capacity(source.len() + device_ident.len() + 1);
+    data.extend_from_slice(source.as_bytes());
+    data.push(0);
+    data.extend_from_slice(device_ident.as_bytes());
+    hex::encode(&sha256(&data))
+}
+
+fn cache_path(source: &str, device_ident: &str, ext: &str) -> PathBuf {
+    cache_dir().join(format!("{}.{}", cache_key(source, device_ident), ext))
+}
+
+/// Load a cached compiled kernel for `source`/`device_ident`, if present.
+pub fn load(source: &str, device_ident: &str, ext: &str) -> Option<Vec<u8>> {
+    std::fs::read(cache_path(source, device_ident, ext)).ok()
+}
+
+/// Write a compiled kernel to the cache, creating the cache directory if
+/// needed. Errors are deliberately swallowed - a failed cache write just
+/// means the next run recompiles, not a search failure.
+pub fn store(source: &str, device_ident: &str, ext: &str, bytes: &[u8]) {
+    let path = cache_path(source, device_ident, ext);
+    if let Some(parent) = path.parent() {
+        if std::fs::create_dir_all(parent).is_err() {
+            return;
+        }
+    }
+    let _ = std::fs::write(path, bytes);
+}
+
+static CUDA_JIT_CACHE_ENABLED: Once = Once::new();
+
+/// Point the CUDA driver's own PTX-to-binary JIT cache at our cache
+/// directory (`CUDA_CACHE_PATH`) instead of its platform default, so the
+/// compiled kernel binaries this process produces live alongside the rest
+/// of `omnivanity`'s cached kernels. The driver keys its cache internally by
+/// a hash of the PTX plus the target device, so repeat runs on the same GPU
+/// skip the JIT step entirely once warm - this only needs to run once per
+/// process, before the first CUDA context is created.
+pub fn enable_cuda_jit_cache() {
+    CUDA_JIT_CACHE_ENABLED.call_once(|| {
+        let dir = cache_dir().join("cuda-jit");
+        if std::fs::create_dir_all(&dir).is_ok() {
+            // Safety: called once, before any CUDA context exists, and no
+            // other thread reads/writes this process's environment at the
+            // same time (this is the only `set_var` call in the GPU crate).
+            unsafe {
+                std::env::set_var("CUDA_CACHE_PATH", dir);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_key_changes_with_source_or_device() {
+        let a = cache_key("kernel body", "rtx-4090");
+        let b = cache_key("kernel body v2", "rtx-4090");
+        let c = cache_key("kernel body", "rtx-3090");
+        assert_ne!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(a, cache_key("kernel body", "rtx-4090"));
+    }
+
+    #[test]
+    fn store_then_load_roundtrips() {
+        let source = "test kernel unique to kernel_cache tests";
+        let device = "test-device";
+        store(source, device, "bin", b"fake compiled bytes");
+        assert_eq!(load(source, device, "bin"), Some(b"fake compiled bytes".to_vec()));
+        let _ = std::fs::remove_file(cache_path(source, device, "bin"));
+    }
+
+    #[test]
+    fn load_is_none_for_an_uncached_source() {
+        assert_eq!(load("never compiled before", "test-device", "bin"), None);
+    }
+}
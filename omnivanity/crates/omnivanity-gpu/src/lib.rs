@@ -3,23 +3,81 @@
 //! Cross-platform GPU acceleration using wgpu and OpenCL.
 
 mod device;
+pub mod device_lock;
+mod kernel_cache;
+pub mod select;
 mod search;
+pub mod searcher;
+pub mod thermal;
+pub mod cpu_engine;
+pub mod registry;
 
 #[cfg(feature = "wgpu-backend")]
 pub mod wgpu_backend;
 
+#[cfg(feature = "wgpu-backend")]
+mod cpu_fallback;
+
+#[cfg(feature = "wgpu-backend")]
+pub mod gpu_api;
+
+#[cfg(feature = "wgpu-backend")]
+pub mod multi_gpu;
+
 #[cfg(feature = "opencl-backend")]
 pub mod opencl_backend;
 
+#[cfg(feature = "opencl-backend")]
+pub mod opencl_evm_engine;
+
+#[cfg(feature = "cuda")]
+pub mod cuda;
+
+#[cfg(feature = "cuda")]
+pub mod evm_engine;
+
+#[cfg(feature = "cuda")]
+pub mod utxo_engine;
+
+pub mod scheduler;
+
 pub use device::{GpuDevice, GpuInfo, GpuBackend};
+pub use device_lock::{DeviceLock, try_lock_device, lock_available};
 pub use search::{GpuVanitySearch, GpuSearchConfig, GpuSearchResult};
+pub use searcher::{GpuSearcher, GpuSearchOutcome};
+pub use scheduler::{GpuScheduler, SchedulerOutcome};
+pub use cpu_engine::CpuVanitySearch;
+pub use registry::{resolve_engine, search, benchmark};
+pub use select::{best_device, rank_devices};
+pub use thermal::{DeviceHealth, ThermalLimits, ThermalMonitor, format_health_line};
+
+#[cfg(feature = "cuda")]
+pub use cuda::{CudaEvmEngine, CudaError, is_cuda_available, list_cuda_devices};
+
+#[cfg(feature = "cuda")]
+pub use evm_engine::{EvmCudaEngine, EvmCudaError};
+
+#[cfg(feature = "cuda")]
+pub use utxo_engine::{UtxoCudaEngine, UtxoCudaError};
 
 #[cfg(feature = "wgpu-backend")]
-pub use wgpu_backend::{WgpuEngine, WgpuError, MatchType, list_wgpu_devices, is_wgpu_available};
+pub use wgpu_backend::{WgpuEngine, WgpuError, MatchType, PatternSpec, list_wgpu_devices, is_wgpu_available};
+
+#[cfg(feature = "wgpu-backend")]
+pub use cpu_fallback::ShaderKind;
+
+#[cfg(feature = "wgpu-backend")]
+pub use gpu_api::{GpuApi, WgpuApi};
+
+#[cfg(feature = "wgpu-backend")]
+pub use multi_gpu::{search_evm_multi_gpu, benchmark_multi_gpu};
 
 #[cfg(feature = "opencl-backend")]
 pub use opencl_backend::{OpenClEngine, OpenClError, OpenClDeviceInfo, OpenClSearchConfig, is_opencl_available, list_opencl_devices};
 
+#[cfg(feature = "opencl-backend")]
+pub use opencl_evm_engine::OpenClEvmEngine;
+
 /// Check if GPU acceleration is available
 pub fn is_gpu_available() -> bool {
     #[cfg(feature = "wgpu-backend")]
@@ -52,12 +110,36 @@ pub fn is_turbo_available() -> bool {
 /// Get list of available GPU devices
 pub fn list_devices() -> Vec<GpuDevice> {
     let mut devices = vec![];
-    
+
     #[cfg(feature = "wgpu-backend")]
     {
         devices.extend(wgpu_backend::list_wgpu_devices());
     }
-    
+
+    #[cfg(feature = "opencl-backend")]
+    {
+        // OpenCL devices are numbered separately from wgpu's (see
+        // `OpenClEngine::new`/`OpenClEvmEngine::new`, which both take a
+        // plain device index into `list_opencl_devices()`'s own ordering) -
+        // `GpuDevice::index` here still reflects that OpenCL-local index,
+        // not a position in this merged list, since that's what callers
+        // need to pass back into `OpenClEvmEngine::new`.
+        devices.extend(
+            opencl_backend::list_opencl_devices()
+                .into_iter()
+                .enumerate()
+                .map(|(index, info)| GpuDevice {
+                    index,
+                    name: info.name,
+                    compute_capability: info.platform,
+                    total_memory: info.global_mem_size,
+                    multiprocessors: info.compute_units,
+                    backend: GpuBackend::OpenCL,
+                    api_impl: None,
+                }),
+        );
+    }
+
     devices
 }
 
@@ -0,0 +1,128 @@
+//! Multi-GPU search coordinator
+//!
+//! `list_wgpu_devices()` already enumerates every adapter, but a single
+//! `WgpuEngine` only ever drives one of them. `search_evm_multi_gpu` spawns
+//! one worker thread per selected `GpuDevice`, each owning its own
+//! `WgpuEngine`/device/queue, and gives every worker a distinct
+//! `GpuSearchConfig::seed_salt`; the salt does not by itself keep two
+//! devices from deriving the same candidate key (see that field's doc).
+//! Workers race against a shared `stop_flag`: whichever finds
+//! a match first sets it so the rest wind down, and results are merged back
+//! onto the caller's thread through a channel.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use tracing::warn;
+
+use crate::device::GpuDevice;
+use crate::search::{GpuSearchConfig, GpuSearchResult};
+use crate::wgpu_backend::WgpuEngine;
+
+/// Multiplier used to spread `seed_salt` values far apart across workers -
+/// the exact constant doesn't matter, only that consecutive worker indices
+/// land on very different bit patterns (the usual Fibonacci-hashing trick).
+const SEED_SALT_STRIDE: u64 = 0x9E3779B97F4A7C15;
+
+/// Search for `pattern` across every device in `devices` at once, aggregating
+/// whichever worker finds a match first. Returns `None` if no device found a
+/// match before `stop_flag` was set or every worker's `max_time_secs` elapsed.
+pub fn search_evm_multi_gpu(
+    devices: &[GpuDevice],
+    pattern: &[u8],
+    pattern_len: usize,
+    base_config: &GpuSearchConfig,
+    stop_flag: Arc<AtomicBool>,
+) -> Option<GpuSearchResult> {
+    if devices.is_empty() {
+        return None;
+    }
+
+    let (tx, rx) = std::sync::mpsc::channel();
+
+    let handles: Vec<_> = devices
+        .iter()
+        .enumerate()
+        .map(|(worker_idx, device)| {
+            let pattern = pattern.to_vec();
+            let stop_flag = stop_flag.clone();
+            let tx = tx.clone();
+            let device_index = device.index;
+            let mut config = base_config.clone();
+            config.device_indices = vec![device_index];
+            config.seed_salt = (worker_idx as u64 + 1).wrapping_mul(SEED_SALT_STRIDE);
+
+            std::thread::spawn(move || {
+                let engine = match WgpuEngine::new_sync(device_index, config) {
+                    Ok(engine) => engine,
+                    Err(e) => {
+                        warn!("multi_gpu: worker for device {} failed to start: {}", device_index, e);
+                        return;
+                    }
+                };
+                let result = engine.search_evm(&pattern, pattern_len, stop_flag.clone());
+                if result.is_some() {
+                    // Tell every other worker to stop as soon as we have a
+                    // winner - mirrors how a single-device search already
+                    // honors an externally-set `stop_flag`.
+                    stop_flag.store(true, Ordering::Relaxed);
+                }
+                let _ = tx.send(result);
+            })
+        })
+        .collect();
+
+    drop(tx);
+
+    let mut found = None;
+    for result in rx {
+        if found.is_none() {
+            found = result;
+        }
+    }
+    for handle in handles {
+        let _ = handle.join();
+    }
+
+    found
+}
+
+/// Benchmark every device in `devices` at once for `duration_secs`, summing
+/// each worker's independently-measured throughput into one combined
+/// keys/second figure - the multi-device equivalent of `WgpuEngine::benchmark`.
+pub fn benchmark_multi_gpu(devices: &[GpuDevice], duration_secs: u64, base_config: &GpuSearchConfig) -> f64 {
+    if devices.is_empty() {
+        return 0.0;
+    }
+
+    let (tx, rx) = std::sync::mpsc::channel();
+
+    let handles: Vec<_> = devices
+        .iter()
+        .map(|device| {
+            let tx = tx.clone();
+            let device_index = device.index;
+            let config = GpuSearchConfig { device_indices: vec![device_index], ..base_config.clone() };
+
+            std::thread::spawn(move || {
+                let rate = match WgpuEngine::new_sync(device_index, config) {
+                    Ok(engine) => engine.benchmark(duration_secs).unwrap_or(0.0),
+                    Err(e) => {
+                        warn!("multi_gpu: benchmark worker for device {} failed to start: {}", device_index, e);
+                        0.0
+                    }
+                };
+                let _ = tx.send(rate);
+            })
+        })
+        .collect();
+
+    drop(tx);
+
+    let combined: f64 = rx.iter().sum();
+    for handle in handles {
+        let _ = handle.join();
+    }
+
+    combined
+}
@@ -6,9 +6,16 @@
 #[cfg(feature = "opencl-backend")]
 use ocl::{
     Buffer, Context, Device, Kernel, Platform, Program, Queue,
-    flags, core::DeviceInfo,
+    flags, core::DeviceInfo, enums::ProgramInfo, enums::ProgramInfoResult,
 };
 
+#[cfg(feature = "opencl-backend")]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "opencl-backend")]
+use std::sync::Arc;
+#[cfg(feature = "opencl-backend")]
+use std::time::{Duration, Instant};
+
 use thiserror::Error;
 use tracing::info;
 
@@ -66,6 +73,20 @@ impl Default for OpenClSearchConfig {
     }
 }
 
+/// Persist a just-built `Program`'s compiled binary to `kernel_cache`, keyed
+/// by `source`/`device_ident`. Best-effort: `clGetProgramInfo` can return
+/// more than one binary (one per device the program was built for - always
+/// exactly one here), and any failure just means the next run recompiles,
+/// same as today.
+#[cfg(feature = "opencl-backend")]
+fn cache_program_binary(program: &Program, source: &str, device_ident: &str) {
+    if let Ok(ProgramInfoResult::Binaries(binaries)) = program.info(ProgramInfo::Binaries) {
+        if let Some(binary) = binaries.into_iter().next() {
+            crate::kernel_cache::store(source, device_ident, "clbin", &binary);
+        }
+    }
+}
+
 /// OpenCL Engine for full GPU key generation
 #[cfg(feature = "opencl-backend")]
 pub struct OpenClEngine {
@@ -73,6 +94,7 @@ pub struct OpenClEngine {
     queue: Queue,
     program: Program,
     device_info: OpenClDeviceInfo,
+    device_index: usize,
 }
 
 #[cfg(feature = "opencl-backend")]
@@ -122,12 +144,29 @@ impl OpenClEngine {
 
         let queue = Queue::new(&context, device.clone(), None)?;
 
-        // Load and compile kernel
+        // Load and compile kernel - or, if an earlier run already built this
+        // exact source for this exact device, load the cached binary and
+        // skip clBuildProgram entirely.
         let kernel_source = include_str!("kernels/ed25519_solana.cl");
-        let program = Program::builder()
-            .src(kernel_source)
-            .devices(device.clone())
-            .build(&context)?;
+        let device_ident = format!("{}-{}", platform_name, device_name);
+        let program = match crate::kernel_cache::load(kernel_source, &device_ident, "clbin") {
+            Some(binary) => {
+                match Program::builder().devices(device.clone()).bins(&[device.clone()], &[binary.as_slice()]).build(&context) {
+                    Ok(program) => program,
+                    Err(e) => {
+                        info!("Cached OpenCL binary rejected ({}), recompiling from source", e);
+                        let program = Program::builder().src(kernel_source).devices(device.clone()).build(&context)?;
+                        cache_program_binary(&program, kernel_source, &device_ident);
+                        program
+                    }
+                }
+            }
+            None => {
+                let program = Program::builder().src(kernel_source).devices(device.clone()).build(&context)?;
+                cache_program_binary(&program, kernel_source, &device_ident);
+                program
+            }
+        };
 
         let device_info = OpenClDeviceInfo {
             name: device_name,
@@ -146,6 +185,7 @@ impl OpenClEngine {
             queue,
             program,
             device_info,
+            device_index,
         })
     }
 
@@ -154,21 +194,43 @@ impl OpenClEngine {
         &self.device_info
     }
 
-    /// Search for a vanity address matching the given prefix/suffix pattern
-    /// Returns (found: bool, private_key: [u8; 32]) if found
+    /// Search for a vanity address matching the given prefix/suffix pattern.
+    ///
+    /// `group_offset_base` displaces this call's `group_offset_buffer` value
+    /// so a caller driving several devices at once (see
+    /// [`crate::scheduler::GpuScheduler`]) can give each device a disjoint
+    /// slice of the keyspace instead of every device re-testing the same
+    /// `group_offset = 0` kernel iteration. Within one call, the offset is
+    /// further bumped by the iteration counter so repeated kernel launches
+    /// (needed once `max_time_secs` allows more than one) don't retest the
+    /// same slice either. Loops launching the kernel until a match is found,
+    /// `stop_flag` is set by another device's search, or `max_time_secs`
+    /// elapses.
     pub fn search_ed25519(
         &self,
-        _prefix: &str,
-        _suffix: &str,
-        _case_sensitive: bool,
+        prefix: &str,
+        suffix: &str,
+        case_sensitive: bool,
         config: &OpenClSearchConfig,
+        group_offset_base: u8,
+        max_time_secs: u64,
+        stop_flag: Arc<AtomicBool>,
+        base_key: Option<[u8; 32]>,
     ) -> Result<Option<[u8; 32]>, OpenClError> {
         // Calculate iteration_bytes = ceil(iteration_bits / 8) - same as solVanityPlus
         let iteration_bytes = ((config.iteration_bits + 7) / 8) as usize;
-        
-        // Generate key32 with last iteration_bytes zeroed (GPU will iterate over these)
+
+        // Generate key32 with last iteration_bytes zeroed (GPU will iterate over these).
+        // `base_key` lets a caller doing a seeded, resumable search (see
+        // `SearchConfig::seed`/`start_counter`) pin the untouched upper bytes
+        // to a value it derived itself instead of pulling fresh OS
+        // randomness on every call, so repeated calls never silently re-test
+        // the same keyspace slice.
         let mut key32 = [0u8; 32];
-        rand::RngCore::fill_bytes(&mut rand::thread_rng(), &mut key32[..(32 - iteration_bytes)]);
+        match base_key {
+            Some(base) => key32[..(32 - iteration_bytes)].copy_from_slice(&base[..(32 - iteration_bytes)]),
+            None => rand::RngCore::fill_bytes(&mut rand::thread_rng(), &mut key32[..(32 - iteration_bytes)]),
+        }
         // Last iteration_bytes are 0x00 - GPU will iterate over them
 
         // Create buffers
@@ -197,7 +259,26 @@ impl OpenClEngine {
             .queue(self.queue.clone())
             .flags(flags::MEM_READ_ONLY | flags::MEM_COPY_HOST_PTR)
             .len(1)
-            .copy_host_slice(&[0u8])
+            .copy_host_slice(&[group_offset_base])
+            .build()?;
+
+        // The kernel base58-encodes each candidate key on-device and compares
+        // it against these directly, so an empty pattern is passed through as
+        // a one-byte placeholder buffer + len 0 (the kernel skips the
+        // comparison entirely when the length is 0).
+        let prefix_bytes = prefix.as_bytes();
+        let suffix_bytes = suffix.as_bytes();
+        let prefix_buffer = Buffer::<u8>::builder()
+            .queue(self.queue.clone())
+            .flags(flags::MEM_READ_ONLY | flags::MEM_COPY_HOST_PTR)
+            .len(prefix_bytes.len().max(1))
+            .copy_host_slice(if prefix_bytes.is_empty() { &[0u8] } else { prefix_bytes })
+            .build()?;
+        let suffix_buffer = Buffer::<u8>::builder()
+            .queue(self.queue.clone())
+            .flags(flags::MEM_READ_ONLY | flags::MEM_COPY_HOST_PTR)
+            .len(suffix_bytes.len().max(1))
+            .copy_host_slice(if suffix_bytes.is_empty() { &[0u8] } else { suffix_bytes })
             .build()?;
 
         // Create and run kernel
@@ -211,24 +292,49 @@ impl OpenClEngine {
             .arg(&output_buffer)
             .arg(&occupied_bytes_buffer)
             .arg(&group_offset_buffer)
+            .arg(&prefix_buffer)
+            .arg(prefix_bytes.len() as i32)
+            .arg(&suffix_buffer)
+            .arg(suffix_bytes.len() as i32)
+            .arg(if case_sensitive { 1i32 } else { 0i32 })
             .build()?;
 
-        unsafe {
-            kernel.enq()?;
-        }
-        self.queue.finish()?;
+        let start = Instant::now();
+        let max_time = Duration::from_secs(max_time_secs.max(1));
+        let mut iteration: u8 = 0;
+        let thermal_limits = crate::thermal::ThermalLimits::default();
+        let thermal = if self.device_info.is_nvidia {
+            crate::thermal::ThermalMonitor::start_nvml(self.device_index, thermal_limits)
+        } else {
+            crate::thermal::ThermalMonitor::start_amd_sysfs(self.device_index, thermal_limits)
+        };
 
-        // Read result
-        let mut output = vec![0u8; 33];
-        output_buffer.read(&mut output).enq()?;
+        loop {
+            if stop_flag.load(Ordering::Relaxed) || start.elapsed() > max_time {
+                return Ok(None);
+            }
 
-        if output[0] != 0 {
-            // Found a match!
-            let mut private_key = [0u8; 32];
-            private_key.copy_from_slice(&output[1..33]);
-            Ok(Some(private_key))
-        } else {
-            Ok(None)
+            thermal.throttle_if_needed();
+
+            group_offset_buffer
+                .write(&[group_offset_base.wrapping_add(iteration)])
+                .enq()?;
+
+            unsafe {
+                kernel.enq()?;
+            }
+            self.queue.finish()?;
+
+            let mut output = vec![0u8; 33];
+            output_buffer.read(&mut output).enq()?;
+
+            if output[0] != 0 {
+                let mut private_key = [0u8; 32];
+                private_key.copy_from_slice(&output[1..33]);
+                return Ok(Some(private_key));
+            }
+
+            iteration = iteration.wrapping_add(1);
         }
     }
 
@@ -0,0 +1,371 @@
+//! OpenCL EVM Engine
+//!
+//! GPU-accelerated vanity address generation for EVM chains (ETH, etc.) on
+//! any OpenCL GPU - AMD, Intel, or an NVIDIA card too old for the `cudarc`
+//! toolkit `EvmCudaEngine` targets. Mirrors `EvmCudaEngine`'s host-seeded
+//! incremental walk (`evm_opencl.cl` is a line-for-line OpenCL C port of
+//! `evm_kernel.cu`'s math), but drives it through `ocl` the same way
+//! `OpenClEngine` already does for Ed25519/Solana search.
+
+use crate::opencl_backend::OpenClError;
+use crate::search::{GpuSearchConfig, GpuSearchResult, GpuVanitySearch};
+use omnivanity_chains::{AddressType, GeneratedAddress, Network};
+use omnivanity_crypto::Secp256k1Keypair;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
+
+#[cfg(feature = "opencl-backend")]
+use ocl::{
+    Buffer, Context, Device, Kernel, Platform, Program, Queue,
+    flags, core::DeviceInfo, enums::ProgramInfo, enums::ProgramInfoResult,
+};
+
+/// OpenCL kernel source for EVM keccak256 vanity generation - see
+/// `kernels/evm_opencl.cl`.
+const EVM_OPENCL_KERNEL_SRC: &str = include_str!("kernels/evm_opencl.cl");
+
+#[cfg(feature = "opencl-backend")]
+fn cache_program_binary(program: &Program, source: &str, device_ident: &str) {
+    if let Ok(ProgramInfoResult::Binaries(binaries)) = program.info(ProgramInfo::Binaries) {
+        if let Some(binary) = binaries.into_iter().next() {
+            crate::kernel_cache::store(source, device_ident, "clbin", &binary);
+        }
+    }
+}
+
+/// OpenCL EVM Engine for GPU vanity search
+#[cfg(feature = "opencl-backend")]
+pub struct OpenClEvmEngine {
+    context: Context,
+    queue: Queue,
+    program: Program,
+    device_index: usize,
+    config: GpuSearchConfig,
+}
+
+#[cfg(feature = "opencl-backend")]
+impl OpenClEvmEngine {
+    /// Create a new OpenCL EVM engine on the given device index (same
+    /// device numbering as [`crate::opencl_backend::list_opencl_devices`]).
+    pub fn new(device_index: usize, config: GpuSearchConfig) -> Result<Self, OpenClError> {
+        let platforms = Platform::list();
+        if platforms.is_empty() {
+            return Err(OpenClError::NoPlatforms);
+        }
+
+        let mut all_devices = Vec::new();
+        for platform in &platforms {
+            if let Ok(devices) = Device::list(platform, Some(flags::DeviceType::GPU)) {
+                for device in devices {
+                    let platform_name = platform.name().unwrap_or_default();
+                    all_devices.push((device, platform_name, platform.clone()));
+                }
+            }
+        }
+
+        if all_devices.is_empty() {
+            return Err(OpenClError::NoDevices);
+        }
+
+        let (device, platform_name, platform) = all_devices
+            .get(device_index)
+            .cloned()
+            .ok_or(OpenClError::NoDevices)?;
+
+        let device_name = device.name().unwrap_or_default();
+        info!(
+            "OpenCL EVM engine device: {} on {}",
+            device_name, platform_name
+        );
+
+        let context = Context::builder()
+            .platform(platform)
+            .devices(device.clone())
+            .build()?;
+        let queue = Queue::new(&context, device.clone(), None)?;
+
+        let device_ident = format!("{}-{}", platform_name, device_name);
+        let program = match crate::kernel_cache::load(EVM_OPENCL_KERNEL_SRC, &device_ident, "clbin") {
+            Some(binary) => {
+                match Program::builder().devices(device.clone()).bins(&[device.clone()], &[binary.as_slice()]).build(&context) {
+                    Ok(program) => program,
+                    Err(e) => {
+                        info!("Cached OpenCL binary rejected ({}), recompiling from source", e);
+                        let program = Program::builder().src(EVM_OPENCL_KERNEL_SRC).devices(device.clone()).build(&context)?;
+                        cache_program_binary(&program, EVM_OPENCL_KERNEL_SRC, &device_ident);
+                        program
+                    }
+                }
+            }
+            None => {
+                let program = Program::builder().src(EVM_OPENCL_KERNEL_SRC).devices(device.clone()).build(&context)?;
+                cache_program_binary(&program, EVM_OPENCL_KERNEL_SRC, &device_ident);
+                program
+            }
+        };
+
+        Ok(Self {
+            context,
+            queue,
+            program,
+            device_index,
+            config,
+        })
+    }
+
+    /// Same base-keypair generation as `EvmCudaEngine::gen_bases` - one real
+    /// scalar multiplication per thread, amortized over `keys_per_thread`
+    /// cheap point additions on-device.
+    fn gen_bases(total_threads: usize) -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+        let mut privkeys = Vec::with_capacity(total_threads * 32);
+        let mut xs = Vec::with_capacity(total_threads * 32);
+        let mut ys = Vec::with_capacity(total_threads * 32);
+        for _ in 0..total_threads {
+            let keypair = Secp256k1Keypair::generate();
+            privkeys.extend_from_slice(&keypair.private_key_bytes());
+            let xy = keypair.public_key_xy();
+            xs.extend_from_slice(&xy[..32]);
+            ys.extend_from_slice(&xy[32..]);
+        }
+        (privkeys, xs, ys)
+    }
+
+    /// Search for a vanity EVM address. `pattern` is the nibble-decoded hex
+    /// prefix (one byte per hex digit, matching the kernel's
+    /// `matches_pattern`), same shape `EvmCudaEngine::search` expects.
+    pub fn search(
+        &self,
+        pattern: &[u8],
+        stop_flag: Arc<AtomicBool>,
+    ) -> Option<GpuSearchResult> {
+        let local_work_size = self.config.block_size.max(1);
+        let global_work_size = if self.config.grid_size == 0 {
+            local_work_size * 64
+        } else {
+            self.config.grid_size * local_work_size
+        };
+        let keys_per_thread = self.config.keys_per_thread.max(1);
+        let total_threads = global_work_size;
+
+        info!(
+            "Launching OpenCL EVM search: {} threads x {} keys/thread = {} keys/iteration",
+            total_threads,
+            keys_per_thread,
+            total_threads * keys_per_thread
+        );
+
+        let found_flag_buffer = Buffer::<i32>::builder()
+            .queue(self.queue.clone())
+            .flags(flags::MEM_READ_WRITE)
+            .len(1)
+            .build()
+            .ok()?;
+        let result_privkey_buffer = Buffer::<u8>::builder()
+            .queue(self.queue.clone())
+            .flags(flags::MEM_READ_WRITE)
+            .len(32)
+            .build()
+            .ok()?;
+        let result_address_buffer = Buffer::<u8>::builder()
+            .queue(self.queue.clone())
+            .flags(flags::MEM_READ_WRITE)
+            .len(20)
+            .build()
+            .ok()?;
+        let prefix_buffer = Buffer::<u8>::builder()
+            .queue(self.queue.clone())
+            .flags(flags::MEM_READ_ONLY | flags::MEM_COPY_HOST_PTR)
+            .len(pattern.len().max(1))
+            .copy_host_slice(if pattern.is_empty() { &[0u8] } else { pattern })
+            .build()
+            .ok()?;
+
+        let start = Instant::now();
+        let max_time = Duration::from_secs(self.config.max_time_secs.max(1));
+        let mut total_keys = 0u64;
+        let mut iteration = 0u32;
+        let thermal = crate::thermal::ThermalMonitor::start_amd_sysfs(self.device_index, crate::thermal::ThermalLimits::default());
+
+        loop {
+            if stop_flag.load(Ordering::Relaxed) {
+                return None;
+            }
+            if self.config.max_time_secs > 0 && start.elapsed() > max_time {
+                return None;
+            }
+            if self.config.max_attempts > 0 && total_keys >= self.config.max_attempts {
+                return None;
+            }
+
+            thermal.throttle_if_needed();
+
+            let (privkeys_host, x_host, y_host) = Self::gen_bases(total_threads);
+            let base_privkeys_buffer = match Buffer::<u8>::builder()
+                .queue(self.queue.clone())
+                .flags(flags::MEM_READ_ONLY | flags::MEM_COPY_HOST_PTR)
+                .len(privkeys_host.len())
+                .copy_host_slice(&privkeys_host)
+                .build()
+            {
+                Ok(b) => b,
+                Err(e) => {
+                    warn!("Failed to upload base private keys: {}", e);
+                    return None;
+                }
+            };
+            let base_x_buffer = match Buffer::<u8>::builder()
+                .queue(self.queue.clone())
+                .flags(flags::MEM_READ_ONLY | flags::MEM_COPY_HOST_PTR)
+                .len(x_host.len())
+                .copy_host_slice(&x_host)
+                .build()
+            {
+                Ok(b) => b,
+                Err(e) => {
+                    warn!("Failed to upload base x-coordinates: {}", e);
+                    return None;
+                }
+            };
+            let base_y_buffer = match Buffer::<u8>::builder()
+                .queue(self.queue.clone())
+                .flags(flags::MEM_READ_ONLY | flags::MEM_COPY_HOST_PTR)
+                .len(y_host.len())
+                .copy_host_slice(&y_host)
+                .build()
+            {
+                Ok(b) => b,
+                Err(e) => {
+                    warn!("Failed to upload base y-coordinates: {}", e);
+                    return None;
+                }
+            };
+
+            let kernel = match Kernel::builder()
+                .program(&self.program)
+                .name("evm_vanity_search")
+                .queue(self.queue.clone())
+                .global_work_size(global_work_size)
+                .local_work_size(local_work_size)
+                .arg(&base_privkeys_buffer)
+                .arg(&base_x_buffer)
+                .arg(&base_y_buffer)
+                .arg(&found_flag_buffer)
+                .arg(&result_privkey_buffer)
+                .arg(&result_address_buffer)
+                .arg(&prefix_buffer)
+                .arg(pattern.len() as i32)
+                .arg(keys_per_thread as i32)
+                .build()
+            {
+                Ok(k) => k,
+                Err(e) => {
+                    warn!("Failed to build EVM OpenCL kernel: {}", e);
+                    return None;
+                }
+            };
+
+            unsafe {
+                if let Err(e) = kernel.enq() {
+                    warn!("Kernel enqueue failed: {}", e);
+                    return None;
+                }
+            }
+            if let Err(e) = self.queue.finish() {
+                warn!("Queue finish failed: {}", e);
+                return None;
+            }
+
+            let mut flag_host = [0i32; 1];
+            if found_flag_buffer.read(&mut flag_host[..]).enq().is_err() {
+                return None;
+            }
+
+            if flag_host[0] != 0 {
+                let mut privkey = vec![0u8; 32];
+                let mut address = vec![0u8; 20];
+                if result_privkey_buffer.read(&mut privkey).enq().is_err() {
+                    return None;
+                }
+                if result_address_buffer.read(&mut address).enq().is_err() {
+                    return None;
+                }
+
+                let elapsed = start.elapsed().as_secs_f64();
+                let keys_per_second = total_keys as f64 / elapsed;
+
+                info!(
+                    "Match found on OpenCL device {} after {} keys",
+                    self.device_index, total_keys
+                );
+
+                return Some(GpuSearchResult {
+                    address: GeneratedAddress {
+                        address: format!("0x{}", hex::encode(&address)),
+                        private_key_hex: hex::encode(&privkey),
+                        private_key_native: hex::encode(&privkey),
+                        public_key_hex: String::new(),
+                        chain: "ETH".to_string(),
+                        address_type: AddressType::Evm,
+                        mnemonic: None,
+                        derivation_path: None,
+                        network: Network::Mainnet,
+                    },
+                    pattern: String::new(),
+                    keys_tested: total_keys,
+                    time_secs: elapsed,
+                    keys_per_second,
+                    found_on_device: self.device_index,
+                });
+            }
+
+            total_keys += (total_threads * keys_per_thread) as u64;
+            iteration += 1;
+
+            if iteration % 10 == 0 {
+                let elapsed = start.elapsed().as_secs_f64();
+                let rate = total_keys as f64 / elapsed / 1_000_000.0;
+                debug!(
+                    "OpenCL EVM {}: {} keys tested ({:.2} Mkey/s)",
+                    self.device_index, total_keys, rate
+                );
+            }
+        }
+    }
+}
+
+impl GpuVanitySearch for OpenClEvmEngine {
+    fn chain(&self) -> &'static str {
+        "ETH"
+    }
+
+    fn address_types(&self) -> Vec<AddressType> {
+        vec![AddressType::Evm]
+    }
+
+    fn search(
+        &self,
+        pattern: &str,
+        _address_type: AddressType,
+        _config: &GpuSearchConfig,
+    ) -> Option<GpuSearchResult> {
+        let stop_flag = Arc::new(AtomicBool::new(false));
+        let pattern_bytes = hex::decode(pattern.trim_start_matches("0x")).unwrap_or_default();
+        self.search(&pattern_bytes, stop_flag)
+    }
+
+    fn benchmark(&self, _duration_secs: u64, _config: &GpuSearchConfig) -> f64 {
+        0.0
+    }
+}
+
+#[cfg(not(feature = "opencl-backend"))]
+pub struct OpenClEvmEngine;
+
+#[cfg(not(feature = "opencl-backend"))]
+impl OpenClEvmEngine {
+    pub fn new(_device_index: usize, _config: GpuSearchConfig) -> Result<Self, OpenClError> {
+        Err(OpenClError::NoPlatforms)
+    }
+}
@@ -0,0 +1,92 @@
+//! Shared search-engine registry
+//!
+//! Resolves a chain ticker + `AddressType` to the best `GpuVanitySearch`
+//! engine available in this build - a real GPU kernel when one exists for
+//! that ticker/feature combination, otherwise `CpuVanitySearch`. This is the
+//! single `search`/`benchmark` entry point callers reach for instead of
+//! hand-matching on ticker to pick an engine themselves.
+
+use omnivanity_chains::AddressType;
+
+use crate::cpu_engine::CpuVanitySearch;
+use crate::search::{GpuSearchConfig, GpuSearchResult, GpuVanitySearch};
+
+/// Resolve the best engine available for `ticker`/`address_type` on
+/// `device_index`, preferring a GPU kernel this build was compiled with and
+/// falling back to the CPU reference engine. `None` if `ticker` isn't a
+/// chain `omnivanity_chains::get_chain` recognizes, or if the CPU engine for
+/// it doesn't list `address_type`.
+pub fn resolve_engine(
+    ticker: &'static str,
+    address_type: AddressType,
+    device_index: usize,
+    config: GpuSearchConfig,
+) -> Option<Box<dyn GpuVanitySearch>> {
+    #[cfg(feature = "cuda")]
+    if ticker == "ETH" && address_type == AddressType::Evm {
+        if let Ok(engine) = crate::evm_engine::EvmCudaEngine::new(device_index, config.clone()) {
+            return Some(Box::new(engine));
+        }
+    }
+
+    #[cfg(feature = "opencl-backend")]
+    if ticker == "ETH" && address_type == AddressType::Evm {
+        if let Ok(engine) = crate::opencl_evm_engine::OpenClEvmEngine::new(device_index, config.clone()) {
+            return Some(Box::new(engine));
+        }
+    }
+
+    let _ = (device_index, &config);
+    CpuVanitySearch::new(ticker)
+        .filter(|engine| engine.address_types().contains(&address_type))
+        .map(|engine| Box::new(engine) as Box<dyn GpuVanitySearch>)
+}
+
+/// Run a single search against the best engine available for `ticker`.
+pub fn search(
+    ticker: &'static str,
+    pattern: &str,
+    address_type: AddressType,
+    config: &GpuSearchConfig,
+) -> Option<GpuSearchResult> {
+    let engine = resolve_engine(ticker, address_type, 0, config.clone())?;
+    engine.search(pattern, address_type, config)
+}
+
+/// Benchmark the best engine available for `ticker`; 0.0 if no engine
+/// resolves (see `resolve_engine`).
+pub fn benchmark(ticker: &'static str, address_type: AddressType, duration_secs: u64, config: &GpuSearchConfig) -> f64 {
+    resolve_engine(ticker, address_type, 0, config.clone())
+        .map(|engine| engine.benchmark(duration_secs, config))
+        .unwrap_or(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_engine_falls_back_to_cpu_for_eth() {
+        // Without the cuda/opencl-backend features this build doesn't even
+        // attempt a GPU kernel, so this always lands on `CpuVanitySearch`.
+        let engine = resolve_engine("ETH", AddressType::Evm, 0, GpuSearchConfig::default()).unwrap();
+        assert_eq!(engine.chain(), "ETH");
+    }
+
+    #[test]
+    fn test_resolve_engine_none_for_unknown_ticker() {
+        assert!(resolve_engine("NOT_A_REAL_CHAIN", AddressType::Evm, 0, GpuSearchConfig::default()).is_none());
+    }
+
+    #[test]
+    fn test_registry_search_finds_single_nibble_prefix() {
+        let config = GpuSearchConfig { max_attempts: 200_000, ..Default::default() };
+        let result = search("ETH", "0", AddressType::Evm, &config).unwrap();
+        assert!(result.address.address.strip_prefix("0x").unwrap().starts_with('0'));
+    }
+
+    #[test]
+    fn test_registry_benchmark_reports_a_positive_rate() {
+        assert!(benchmark("ETH", AddressType::Evm, 1, &GpuSearchConfig::default()) > 0.0);
+    }
+}
@@ -0,0 +1,277 @@
+//! Cross-backend multi-device search scheduler
+//!
+//! `multi_gpu::search_evm_multi_gpu` already solves "drive every device at
+//! once" for the wgpu/EVM path: one worker thread per device, a shared
+//! `Arc<AtomicBool>` stop flag, first match wins, results merged back through
+//! a channel. Neither `OpenClEngine` nor `EvmCudaEngine` had an equivalent -
+//! each only ever drove the one device it was constructed with. `GpuScheduler`
+//! brings the same pattern to both of them.
+
+#[cfg(any(feature = "opencl-backend", feature = "cuda"))]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(any(feature = "opencl-backend", feature = "cuda"))]
+use std::sync::Arc;
+
+#[cfg(any(feature = "opencl-backend", feature = "cuda"))]
+use tracing::warn;
+
+use crate::search::{GpuSearchConfig, GpuSearchResult};
+
+/// Outcome of a scheduler run: the winning match (if any) plus a combined
+/// throughput figure - each `search_*` method documents how it fills it in.
+pub struct SchedulerOutcome<T> {
+    pub result: Option<T>,
+    pub combined_keys_per_second: f64,
+}
+
+/// Drives a search across every device detected for one backend.
+pub struct GpuScheduler;
+
+impl GpuScheduler {
+    /// Race every OpenCL device against `group_offset_base`-disjoint slices
+    /// of the keyspace. Each worker's `group_offset_base` is its position in
+    /// `list_opencl_devices()` times 32 (wrapping in a `u8`), and
+    /// `search_ed25519` adds one per kernel launch on top of that, so two
+    /// devices' offsets stay apart for their first 32 launches.
+    /// `combined_keys_per_second` sums each
+    /// participating device's `estimated_keys_per_second()` - OpenCL has no
+    /// real per-run throughput measurement today, only that static estimate.
+    #[cfg(feature = "opencl-backend")]
+    pub fn search_opencl(
+        prefix: &str,
+        suffix: &str,
+        case_sensitive: bool,
+        config: &crate::opencl_backend::OpenClSearchConfig,
+        max_time_secs: u64,
+    ) -> SchedulerOutcome<[u8; 32]> {
+        use crate::opencl_backend::{list_opencl_devices, OpenClEngine};
+
+        let device_count = list_opencl_devices().len();
+        if device_count == 0 {
+            return SchedulerOutcome { result: None, combined_keys_per_second: 0.0 };
+        }
+
+        let stop_flag = Arc::new(AtomicBool::new(false));
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        let handles: Vec<_> = (0..device_count)
+            .map(|device_index| {
+                let prefix = prefix.to_string();
+                let suffix = suffix.to_string();
+                let config = config.clone();
+                let stop_flag = stop_flag.clone();
+                let tx = tx.clone();
+
+                std::thread::spawn(move || {
+                    let engine = match OpenClEngine::new(device_index) {
+                        Ok(engine) => engine,
+                        Err(e) => {
+                            warn!("scheduler: opencl device {} failed to start: {}", device_index, e);
+                            let _ = tx.send((None, 0));
+                            return;
+                        }
+                    };
+                    let rate = engine.estimated_keys_per_second();
+                    let group_offset_base = (device_index as u8).wrapping_mul(32);
+                    let result = engine.search_ed25519(
+                        &prefix,
+                        &suffix,
+                        case_sensitive,
+                        &config,
+                        group_offset_base,
+                        max_time_secs,
+                        stop_flag.clone(),
+                        None,
+                    );
+                    match result {
+                        Ok(found) => {
+                            if found.is_some() {
+                                stop_flag.store(true, Ordering::Relaxed);
+                            }
+                            let _ = tx.send((found, rate));
+                        }
+                        Err(e) => {
+                            warn!("scheduler: opencl device {} search failed: {}", device_index, e);
+                            let _ = tx.send((None, 0));
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        drop(tx);
+
+        let mut found = None;
+        let mut combined_rate = 0u64;
+        for (result, rate) in rx {
+            combined_rate += rate;
+            if found.is_none() {
+                found = result;
+            }
+        }
+        for handle in handles {
+            let _ = handle.join();
+        }
+
+        SchedulerOutcome { result: found, combined_keys_per_second: combined_rate as f64 }
+    }
+
+    #[cfg(not(feature = "opencl-backend"))]
+    pub fn search_opencl(
+        _prefix: &str,
+        _suffix: &str,
+        _case_sensitive: bool,
+        _config: &crate::opencl_backend::OpenClSearchConfig,
+        _max_time_secs: u64,
+    ) -> SchedulerOutcome<[u8; 32]> {
+        SchedulerOutcome { result: None, combined_keys_per_second: 0.0 }
+    }
+
+    /// Race every CUDA device (per `list_cuda_devices()`) through its own
+    /// `EvmCudaEngine`, sharing a stop flag so the first match cancels the
+    /// rest - mirrors `search_evm_multi_gpu` in `multi_gpu`, but for the CUDA
+    /// backend instead of wgpu. `found_on_device` on the winning
+    /// `GpuSearchResult` already identifies which device matched.
+    /// `combined_keys_per_second` is the winning device's own
+    /// `keys_per_second`, and 0.0 when no device finds a match.
+    #[cfg(feature = "cuda")]
+    pub fn search_cuda_evm(
+        pattern: &[u8],
+        pattern_len: usize,
+        case_insensitive: bool,
+        base_config: &GpuSearchConfig,
+    ) -> SchedulerOutcome<GpuSearchResult> {
+        use crate::cuda::list_cuda_devices;
+        use crate::evm_engine::EvmCudaEngine;
+
+        let devices = list_cuda_devices();
+        if devices.is_empty() {
+            return SchedulerOutcome { result: None, combined_keys_per_second: 0.0 };
+        }
+
+        let stop_flag = Arc::new(AtomicBool::new(false));
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        let handles: Vec<_> = devices
+            .iter()
+            .map(|device| {
+                let pattern = pattern.to_vec();
+                let stop_flag = stop_flag.clone();
+                let tx = tx.clone();
+                let device_index = device.index;
+                let mut config = base_config.clone();
+                config.device_indices = vec![device_index];
+
+                std::thread::spawn(move || {
+                    let engine = match EvmCudaEngine::new(device_index, config) {
+                        Ok(engine) => engine,
+                        Err(e) => {
+                            warn!("scheduler: cuda device {} failed to start: {}", device_index, e);
+                            let _ = tx.send(None);
+                            return;
+                        }
+                    };
+                    let result = engine.search(&pattern, pattern_len, case_insensitive, stop_flag.clone());
+                    if result.is_some() {
+                        stop_flag.store(true, Ordering::Relaxed);
+                    }
+                    let _ = tx.send(result);
+                })
+            })
+            .collect();
+
+        drop(tx);
+
+        let mut found = None;
+        for result in rx {
+            if found.is_none() {
+                found = result;
+            }
+        }
+        for handle in handles {
+            let _ = handle.join();
+        }
+
+        let combined_keys_per_second = found.as_ref().map(|r| r.keys_per_second).unwrap_or(0.0);
+        SchedulerOutcome { result: found, combined_keys_per_second }
+    }
+
+    #[cfg(not(feature = "cuda"))]
+    pub fn search_cuda_evm(
+        _pattern: &[u8],
+        _pattern_len: usize,
+        _case_insensitive: bool,
+        _base_config: &GpuSearchConfig,
+    ) -> SchedulerOutcome<GpuSearchResult> {
+        SchedulerOutcome { result: None, combined_keys_per_second: 0.0 }
+    }
+
+    /// Sum every CUDA device's measured `EvmCudaEngine::benchmark` throughput,
+    /// the same "one worker per device, combine the results" shape
+    /// `multi_gpu::benchmark_multi_gpu` uses for wgpu.
+    #[cfg(feature = "cuda")]
+    pub fn benchmark_cuda_evm(duration_secs: u64, base_config: &GpuSearchConfig) -> f64 {
+        use crate::cuda::list_cuda_devices;
+        use crate::evm_engine::EvmCudaEngine;
+
+        let devices = list_cuda_devices();
+        if devices.is_empty() {
+            return 0.0;
+        }
+
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        let handles: Vec<_> = devices
+            .iter()
+            .map(|device| {
+                let tx = tx.clone();
+                let device_index = device.index;
+                let config = GpuSearchConfig { device_indices: vec![device_index], ..base_config.clone() };
+
+                std::thread::spawn(move || {
+                    let rate = match EvmCudaEngine::new(device_index, config) {
+                        Ok(engine) => engine.benchmark(duration_secs).unwrap_or(0.0),
+                        Err(e) => {
+                            warn!("scheduler: cuda benchmark worker for device {} failed to start: {}", device_index, e);
+                            0.0
+                        }
+                    };
+                    let _ = tx.send(rate);
+                })
+            })
+            .collect();
+
+        drop(tx);
+
+        let combined: f64 = rx.iter().sum();
+        for handle in handles {
+            let _ = handle.join();
+        }
+
+        combined
+    }
+
+    #[cfg(not(feature = "cuda"))]
+    pub fn benchmark_cuda_evm(_duration_secs: u64, _base_config: &GpuSearchConfig) -> f64 {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_opencl_returns_empty_outcome_without_devices() {
+        let outcome = GpuScheduler::search_opencl("a", "", true, &Default::default(), 1);
+        assert!(outcome.result.is_none());
+        assert!(outcome.combined_keys_per_second >= 0.0);
+    }
+
+    #[test]
+    fn search_cuda_evm_returns_empty_outcome_without_devices() {
+        let outcome = GpuScheduler::search_cuda_evm(b"a", 1, true, &GpuSearchConfig::default());
+        assert!(outcome.result.is_none());
+        assert!(outcome.combined_keys_per_second >= 0.0);
+    }
+}
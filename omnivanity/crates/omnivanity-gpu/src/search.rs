@@ -18,6 +18,21 @@ pub struct GpuSearchConfig {
     pub max_attempts: u64,
     /// Maximum time in seconds (0 = unlimited)
     pub max_time_secs: u64,
+    /// Force the CPU fallback kernel even if a GPU adapter is available -
+    /// useful for validating GPU results against the reference path.
+    pub force_cpu: bool,
+    /// Force a specific WebGPU implementation by the name it reports through
+    /// `GpuDevice::api_impl` (e.g. `"wgpu"`) - `None` accepts whichever
+    /// implementation this build was compiled with. Only `"wgpu"` exists
+    /// today; this is the selector a second `gpu_api::GpuApi` implementation
+    /// (e.g. a Dawn-backed one) would plug into.
+    pub webgpu_impl: Option<String>,
+    /// XORed into each thread's seed in `WgpuEngine::search_evm` - see
+    /// `multi_gpu`, which assigns every device a distinct salt. The seed
+    /// words it is XORed into are random draws themselves, so distinct salts
+    /// do not guarantee that two devices never derive the same candidate
+    /// key. Zero for a single-device search.
+    pub seed_salt: u64,
 }
 
 impl Default for GpuSearchConfig {
@@ -29,6 +44,9 @@ impl Default for GpuSearchConfig {
             keys_per_thread: 256,
             max_attempts: 0,
             max_time_secs: 0,
+            force_cpu: false,
+            webgpu_impl: None,
+            seed_salt: 0,
         }
     }
 }
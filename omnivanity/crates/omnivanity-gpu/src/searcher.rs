@@ -0,0 +1,208 @@
+//! `GpuSearcher`: the batched search loop the `GpuDevice`/`GpuInfo` hardware
+//! abstraction was built to drive
+//!
+//! Ties a `Chain`, a `Pattern`, and a selected `GpuDevice` together: batch
+//! size is auto-sized from the device's `multiprocessors`/`total_memory`,
+//! and the measured keys/sec feeds `estimate_time_50pct` for an ETA. When
+//! the `wgpu-backend` feature is enabled and the chain is EVM with a prefix
+//! pattern of whole hex bytes (an even number of hex digits), batches are
+//! generated and matched on-device via
+//! `WgpuEngine::search_evm`; otherwise batches fall back to CPU generation
+//! through the same `Chain::generate` trait method the rest of the
+//! workspace uses, tested with the same `PatternMatcher` the CPU engine uses.
+
+#[cfg(feature = "wgpu-backend")]
+use std::sync::atomic::AtomicBool;
+#[cfg(feature = "wgpu-backend")]
+use std::sync::Arc;
+use std::time::Instant;
+
+use omnivanity_chains::{AddressType, Chain, ChainFamily, GeneratedAddress};
+use omnivanity_pattern::{Pattern, PatternMatcher, calculate_difficulty, estimate_time_50pct};
+
+use crate::device::GpuDevice;
+
+/// Rough per-lane GPU working-set size (keypair + scratch buffers) used to
+/// cap the batch size to what `total_memory` can actually hold.
+const BYTES_PER_LANE: u64 = 256;
+
+/// Result of one `GpuSearcher::run` call.
+pub struct GpuSearchOutcome {
+    pub matched: Option<GeneratedAddress>,
+    pub keys_tested: u64,
+    pub elapsed_secs: f64,
+    pub keys_per_second: f64,
+    pub eta_50pct_secs: f64,
+}
+
+/// Drives a batched search for `pattern` against `chain` on `device`.
+pub struct GpuSearcher<'a> {
+    chain: &'a dyn Chain,
+    address_type: AddressType,
+    pattern: Pattern,
+    matcher: PatternMatcher,
+    device: GpuDevice,
+    // Lazily built on the first wgpu-backed `run()` call and kept for the
+    // searcher's lifetime so repeated calls reuse the same device/queue and
+    // precompiled pipelines instead of re-requesting a device and
+    // recompiling shaders every time - see `reset()` to release it early.
+    #[cfg(feature = "wgpu-backend")]
+    wgpu_engine: std::cell::RefCell<Option<crate::wgpu_backend::WgpuEngine>>,
+}
+
+impl<'a> GpuSearcher<'a> {
+    pub fn new(chain: &'a dyn Chain, address_type: AddressType, pattern: Pattern, device: GpuDevice) -> Self {
+        let matcher = PatternMatcher::single(pattern.clone());
+        Self {
+            chain,
+            address_type,
+            pattern,
+            matcher,
+            device,
+            #[cfg(feature = "wgpu-backend")]
+            wgpu_engine: std::cell::RefCell::new(None),
+        }
+    }
+
+    /// Drop the cached `WgpuEngine` (device, queue, and compiled pipelines),
+    /// releasing its GPU resources. The next `run()` call rebuilds it lazily.
+    #[cfg(feature = "wgpu-backend")]
+    pub fn reset(&self) {
+        self.wgpu_engine.borrow_mut().take();
+    }
+
+    /// Auto-size the batch to the device's compute width and memory budget:
+    /// 256 threads per multiprocessor (matches `GpuSearchConfig`'s default
+    /// block size), capped by how many `BYTES_PER_LANE`-sized lanes fit in
+    /// `total_memory`.
+    pub fn auto_batch_size(&self) -> usize {
+        const THREADS_PER_MULTIPROCESSOR: usize = 256;
+        let by_compute = (self.device.multiprocessors as usize).max(1) * THREADS_PER_MULTIPROCESSOR;
+        let by_memory = (self.device.total_memory / BYTES_PER_LANE).max(1) as usize;
+        by_compute.min(by_memory).max(1)
+    }
+
+    fn difficulty(&self) -> f64 {
+        calculate_difficulty(
+            &self.pattern.value,
+            self.pattern.pattern_type,
+            self.chain.valid_address_chars(self.address_type).len(),
+            self.pattern.case_insensitive,
+        )
+    }
+
+    /// Generate and test one batch of `batch_size` candidates on the CPU via
+    /// `Chain::generate`, testing each with `PatternMatcher`. `run` tries
+    /// `run_batch_wgpu` first when an engine is available.
+    fn run_batch_cpu(&self, batch_size: usize) -> Option<GeneratedAddress> {
+        for _ in 0..batch_size {
+            let addr = self.chain.generate(self.address_type);
+            if self.matcher.matches(&addr.address, self.chain.address_prefix(self.address_type)).is_some() {
+                return Some(addr);
+            }
+        }
+        None
+    }
+
+    #[cfg(feature = "wgpu-backend")]
+    fn run_batch_wgpu(&self, engine: &crate::wgpu_backend::WgpuEngine) -> Option<GeneratedAddress> {
+        use omnivanity_pattern::PatternType as PT;
+
+        if self.chain.family() != ChainFamily::Evm || !matches!(self.pattern.pattern_type, PT::Prefix) {
+            return None;
+        }
+        let stop_flag = Arc::new(AtomicBool::new(false));
+        // `search_evm` compares raw address bytes, so the hex pattern is
+        // decoded first; an odd-length or non-hex pattern stays on the CPU.
+        let needle = omnivanity_crypto::hex::decode(&self.pattern.value).ok()?;
+        let result = engine.search_evm(&needle, needle.len(), stop_flag)?;
+        Some(result.address)
+    }
+
+    /// Run until a match is found, returning the match, total keys tested,
+    /// the achieved keys/sec, and the 50%-probability ETA at that rate.
+    pub fn run(&self) -> GpuSearchOutcome {
+        let start = Instant::now();
+        let batch_size = self.auto_batch_size();
+        let mut keys_tested: u64 = 0;
+        let mut matched = None;
+
+        #[cfg(feature = "wgpu-backend")]
+        {
+            if self.wgpu_engine.borrow().is_none() {
+                let engine = crate::wgpu_backend::WgpuEngine::new_sync(
+                    self.device.index,
+                    crate::search::GpuSearchConfig { grid_size: (batch_size / 256).max(1), ..Default::default() },
+                )
+                .ok();
+                *self.wgpu_engine.borrow_mut() = engine;
+            }
+        }
+
+        while matched.is_none() {
+            #[cfg(feature = "wgpu-backend")]
+            if let Some(engine) = self.wgpu_engine.borrow().as_ref() {
+                if let Some(found) = self.run_batch_wgpu(engine) {
+                    matched = Some(found);
+                    keys_tested += batch_size as u64;
+                    break;
+                }
+            }
+
+            matched = self.run_batch_cpu(batch_size);
+            keys_tested += batch_size as u64;
+
+            // A missing device/backend still needs to terminate eventually;
+            // CPU fallback makes forward progress every iteration regardless.
+            if keys_tested > u64::from(u32::MAX) && matched.is_none() {
+                break;
+            }
+        }
+
+        let elapsed_secs = start.elapsed().as_secs_f64().max(1e-9);
+        let keys_per_second = keys_tested as f64 / elapsed_secs;
+
+        GpuSearchOutcome {
+            matched,
+            keys_tested,
+            elapsed_secs,
+            keys_per_second,
+            eta_50pct_secs: estimate_time_50pct(self.difficulty(), keys_per_second.max(1.0)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::device::GpuBackend;
+    use omnivanity_chains::ETH;
+
+    fn test_device() -> GpuDevice {
+        GpuDevice {
+            index: 0,
+            name: "test-device".to_string(),
+            compute_capability: "0.0".to_string(),
+            total_memory: 8 * 1024 * 1024 * 1024,
+            multiprocessors: 20,
+            backend: GpuBackend::Wgpu,
+            api_impl: Some("wgpu"),
+        }
+    }
+
+    #[test]
+    fn auto_batch_size_is_bounded_by_memory_and_compute() {
+        let searcher = GpuSearcher::new(&ETH, AddressType::Evm, Pattern::prefix("0"), test_device());
+        let batch = searcher.auto_batch_size();
+        assert!(batch > 0);
+        assert!(batch <= 20 * 256);
+    }
+
+    #[test]
+    fn run_finds_a_single_hex_nibble_match() {
+        let searcher = GpuSearcher::new(&ETH, AddressType::Evm, Pattern::prefix("0"), test_device());
+        let outcome = searcher.run();
+        assert!(outcome.matched.is_some());
+        assert!(outcome.keys_per_second >= 0.0);
+    }
+}
@@ -0,0 +1,86 @@
+//! Automatic best-device selection
+//!
+//! `OpenClEngine::new`/`EvmCudaEngine::new` both take a raw `device_index`
+//! with no way to ask for "the best GPU available", and the throughput
+//! estimate (`estimated_keys_per_second`/`num_sms`) is only reachable after
+//! constructing an engine for a specific index. `rank_devices` enumerates
+//! every CUDA and OpenCL device up front via their existing `list_*_devices`
+//! calls, filters out anything under `min_memory_bytes`, and returns them
+//! sorted best-first so a caller can either take `.first()` or feed the
+//! whole ranking into [`crate::scheduler::GpuScheduler`].
+//!
+//! Mirrors the common `configureGpu`-style logic of CUDA vanity-search
+//! tools: iterate all GPUs, prefer the highest compute capability, and
+//! only keep devices with enough memory to actually run a search.
+
+use crate::device::GpuDevice;
+
+/// Parse a `"major.minor"` compute capability string into a comparable key.
+/// Unparsable/`"N/A"` values (every device today - see the comment in
+/// `cuda.rs::list_cuda_devices`) sort as the lowest capability rather than
+/// erroring, so they still participate in the ranking.
+fn compute_capability_key(raw: &str) -> (u32, u32) {
+    let mut parts = raw.split('.');
+    let major = parts.next().and_then(|p| p.parse().ok()).unwrap_or(0);
+    let minor = parts.next().and_then(|p| p.parse().ok()).unwrap_or(0);
+    (major, minor)
+}
+
+/// All CUDA and OpenCL devices with at least `min_memory_bytes` of global
+/// memory, ranked best-first by (compute capability, compute unit count,
+/// total memory).
+pub fn rank_devices(min_memory_bytes: u64) -> Vec<GpuDevice> {
+    let mut devices = Vec::new();
+
+    #[cfg(feature = "cuda")]
+    devices.extend(crate::cuda::list_cuda_devices());
+
+    #[cfg(feature = "opencl-backend")]
+    devices.extend(crate::opencl_backend::list_opencl_devices().into_iter().enumerate().map(|(index, info)| GpuDevice {
+        index,
+        name: info.name,
+        compute_capability: "N/A".to_string(),
+        total_memory: info.global_mem_size,
+        multiprocessors: info.compute_units,
+        backend: crate::device::GpuBackend::OpenCL,
+        api_impl: None,
+    }));
+
+    devices.retain(|device| device.total_memory >= min_memory_bytes);
+    devices.sort_by(|a, b| {
+        compute_capability_key(&b.compute_capability)
+            .cmp(&compute_capability_key(&a.compute_capability))
+            .then(b.multiprocessors.cmp(&a.multiprocessors))
+            .then(b.total_memory.cmp(&a.total_memory))
+    });
+
+    devices
+}
+
+/// The single highest-ranked device with at least `min_memory_bytes`, or
+/// `None` if no device meets the bound.
+pub fn best_device(min_memory_bytes: u64) -> Option<GpuDevice> {
+    rank_devices(min_memory_bytes).into_iter().next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compute_capability_key_orders_higher_minor_above_lower() {
+        assert!(compute_capability_key("8.9") > compute_capability_key("8.6"));
+        assert!(compute_capability_key("9.0") > compute_capability_key("8.9"));
+    }
+
+    #[test]
+    fn compute_capability_key_treats_na_as_lowest() {
+        assert_eq!(compute_capability_key("N/A"), (0, 0));
+        assert!(compute_capability_key("1.0") > compute_capability_key("N/A"));
+    }
+
+    #[test]
+    fn rank_devices_excludes_everything_below_an_impossible_memory_bound() {
+        assert!(rank_devices(u64::MAX).is_empty());
+    }
+}
@@ -0,0 +1,282 @@
+//! GPU thermal/power monitoring and automatic throttling
+//!
+//! `OpenClDeviceInfo`/`GpuDevice` only ever report static capabilities -
+//! there was no visibility into a device's actual temperature, fan speed, or
+//! power draw while a search ran, and nothing stopped an unattended search
+//! from cooking a GPU. `ThermalMonitor` polls a device's health on a
+//! background timer (NVML for CUDA devices, `hwmon` sysfs for AMD OpenCL
+//! devices, mirroring the ADL-initialization-and-monitoring approach
+//! established OpenCL miners use) and lets the hot search loop check in
+//! between kernel launches via `throttle_if_needed`, which sleeps once the
+//! device is over `max_temp_c` until it has cooled back below
+//! `resume_temp_c`.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use tracing::{info, warn};
+
+/// One snapshot of a device's health. Any field is `None` when that sensor
+/// isn't available (e.g. no NVML on this machine, or a card with no
+/// `fan1_input` hwmon node).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DeviceHealth {
+    pub temperature_c: Option<f32>,
+    pub fan_speed_pct: Option<f32>,
+    pub power_watts: Option<f32>,
+    /// GPU compute utilization, 0-100. NVML-only today (see `poll_nvml`) -
+    /// there's no `hwmon` node for this on the AMD sysfs path, so it's
+    /// always `None` from `poll_amd_sysfs`.
+    pub utilization_pct: Option<f32>,
+}
+
+/// Thresholds governing when `ThermalMonitor` pauses kernel launches.
+#[derive(Debug, Clone, Copy)]
+pub struct ThermalLimits {
+    /// Stop launching new kernel iterations once a reading is at or above this.
+    pub max_temp_c: f32,
+    /// Resume at full speed once a reading drops at or below this - kept
+    /// below `max_temp_c` so the monitor doesn't flap at the boundary.
+    pub resume_temp_c: f32,
+    /// How often the background thread re-reads the sensors.
+    pub poll_interval: Duration,
+    /// Length of each sleep step `throttle_if_needed` takes while throttled.
+    pub cooldown_sleep: Duration,
+}
+
+impl Default for ThermalLimits {
+    fn default() -> Self {
+        Self {
+            max_temp_c: 85.0,
+            resume_temp_c: 78.0,
+            poll_interval: Duration::from_secs(2),
+            cooldown_sleep: Duration::from_millis(500),
+        }
+    }
+}
+
+enum DeviceKind {
+    Nvml(usize),
+    AmdSysfs(usize),
+}
+
+/// Polls one device's temperature/fan/power in the background and enforces
+/// `ThermalLimits` against it. Dropping the monitor stops the background
+/// thread and joins it.
+pub struct ThermalMonitor {
+    limits: ThermalLimits,
+    latest: Arc<Mutex<DeviceHealth>>,
+    throttled: Arc<AtomicBool>,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl ThermalMonitor {
+    /// Start polling `device_index` via NVML (NVIDIA/CUDA devices).
+    pub fn start_nvml(device_index: usize, limits: ThermalLimits) -> Self {
+        Self::start(DeviceKind::Nvml(device_index), limits)
+    }
+
+    /// Start polling `device_index` via `hwmon` sysfs (AMD/OpenCL devices on
+    /// Linux - there's no NVML-equivalent cross-distro binding for ADL).
+    pub fn start_amd_sysfs(device_index: usize, limits: ThermalLimits) -> Self {
+        Self::start(DeviceKind::AmdSysfs(device_index), limits)
+    }
+
+    fn start(kind: DeviceKind, limits: ThermalLimits) -> Self {
+        let latest = Arc::new(Mutex::new(DeviceHealth::default()));
+        let throttled = Arc::new(AtomicBool::new(false));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let latest_bg = latest.clone();
+        let throttled_bg = throttled.clone();
+        let stop_bg = stop.clone();
+        let poll_interval = limits.poll_interval;
+        let max_temp_c = limits.max_temp_c;
+        let resume_temp_c = limits.resume_temp_c;
+        let device_index = kind.index();
+
+        let handle = std::thread::spawn(move || {
+            while !stop_bg.load(Ordering::Relaxed) {
+                let health = kind.poll();
+
+                if let Some(temp) = health.temperature_c {
+                    if temp >= max_temp_c && !throttled_bg.swap(true, Ordering::Relaxed) {
+                        warn!(
+                            "GPU {}: {:.1}C exceeds {:.1}C limit, throttling kernel launches",
+                            device_index, temp, max_temp_c
+                        );
+                    } else if temp <= resume_temp_c && throttled_bg.swap(false, Ordering::Relaxed) {
+                        info!("GPU {}: cooled to {:.1}C, resuming full speed", device_index, temp);
+                    }
+                }
+
+                *latest_bg.lock().unwrap() = health;
+                std::thread::sleep(poll_interval);
+            }
+        });
+
+        Self { limits, latest, throttled, stop, handle: Some(handle) }
+    }
+
+    /// Latest polled reading - may lag the true state by up to `poll_interval`.
+    pub fn health(&self) -> DeviceHealth {
+        *self.latest.lock().unwrap()
+    }
+
+    /// Call between kernel launches in the hot search loop: once a poll has
+    /// seen `max_temp_c` or more, sleeps in `cooldown_sleep` steps until a
+    /// poll sees `resume_temp_c` or less.
+    pub fn throttle_if_needed(&self) {
+        while self.throttled.load(Ordering::Relaxed) {
+            std::thread::sleep(self.limits.cooldown_sleep);
+        }
+    }
+}
+
+/// Format one device's latest reading as a single status-line fragment, e.g.
+/// `"GPU 0: 97% util, 68.4C, 214.1W"` - falling back to `"unavailable"` per
+/// missing sensor rather than omitting the device entirely, so a caller
+/// printing one of these per device at least confirms which devices it's
+/// watching even on a machine with no NVML/hwmon access.
+pub fn format_health_line(device_index: usize, health: DeviceHealth) -> String {
+    let util = health.utilization_pct.map(|p| format!("{:.0}% util", p)).unwrap_or_else(|| "util unavailable".to_string());
+    let temp = health.temperature_c.map(|t| format!("{:.1}C", t)).unwrap_or_else(|| "temp unavailable".to_string());
+    let power = health.power_watts.map(|w| format!("{:.1}W", w)).unwrap_or_else(|| "power unavailable".to_string());
+    format!("GPU {}: {}, {}, {}", device_index, util, temp, power)
+}
+
+impl Drop for ThermalMonitor {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl DeviceKind {
+    fn index(&self) -> usize {
+        match self {
+            DeviceKind::Nvml(i) | DeviceKind::AmdSysfs(i) => *i,
+        }
+    }
+
+    fn poll(&self) -> DeviceHealth {
+        match self {
+            DeviceKind::Nvml(i) => poll_nvml(*i),
+            DeviceKind::AmdSysfs(i) => poll_amd_sysfs(*i),
+        }
+    }
+}
+
+/// A fresh `Nvml` handle is built on every poll rather than held across the
+/// monitor's lifetime - NVML documents init as cheap relative to a
+/// multi-second poll interval, and this avoids threading a non-`Send`-until-
+/// wrapped handle into the background thread's captured state.
+///
+/// Gated behind its own `nvml` feature rather than `cuda` - `EvmCudaEngine`
+/// et al. need the `cudarc`/NVRTC toolchain to build at all, which isn't
+/// installed on every machine that still has an NVIDIA card and wants
+/// telemetry, so a build can pull in `nvml-wrapper` without also requiring a
+/// working CUDA toolkit.
+#[cfg(feature = "nvml")]
+fn poll_nvml(device_index: usize) -> DeviceHealth {
+    use nvml_wrapper::enum_wrappers::device::TemperatureSensor;
+    use nvml_wrapper::Nvml;
+
+    let Ok(nvml) = Nvml::init() else {
+        return DeviceHealth::default();
+    };
+    let Ok(device) = nvml.device_by_index(device_index as u32) else {
+        return DeviceHealth::default();
+    };
+
+    DeviceHealth {
+        temperature_c: device.temperature(TemperatureSensor::Gpu).ok().map(|t| t as f32),
+        fan_speed_pct: device.fan_speed(0).ok().map(|pct| pct as f32),
+        power_watts: device.power_usage().ok().map(|milliwatts| milliwatts as f32 / 1000.0),
+        utilization_pct: device.utilization_rates().ok().map(|u| u.gpu as f32),
+    }
+}
+
+/// Without the `nvml` feature, NVIDIA telemetry degrades gracefully to
+/// "unavailable" (every field `None`) instead of failing the build.
+#[cfg(not(feature = "nvml"))]
+fn poll_nvml(_device_index: usize) -> DeviceHealth {
+    DeviceHealth::default()
+}
+
+/// AMD has no cross-distro equivalent of NVML; the established workaround
+/// (what ADL-based miners fall back to on Linux) is reading the kernel's own
+/// `hwmon` sysfs nodes for the card directly.
+fn poll_amd_sysfs(device_index: usize) -> DeviceHealth {
+    let Some(hwmon_dir) = find_hwmon_dir(device_index) else {
+        return DeviceHealth::default();
+    };
+
+    let temperature_c = read_sysfs_u64(&hwmon_dir.join("temp1_input")).map(|milli_c| milli_c as f32 / 1000.0);
+    let fan_rpm = read_sysfs_u64(&hwmon_dir.join("fan1_input"));
+    let fan_max_rpm = read_sysfs_u64(&hwmon_dir.join("fan1_max"));
+    let fan_speed_pct = match (fan_rpm, fan_max_rpm) {
+        (Some(rpm), Some(max_rpm)) if max_rpm > 0 => Some(rpm as f32 / max_rpm as f32 * 100.0),
+        _ => None,
+    };
+    let power_watts = read_sysfs_u64(&hwmon_dir.join("power1_average")).map(|micro_w| micro_w as f32 / 1_000_000.0);
+
+    DeviceHealth { temperature_c, fan_speed_pct, power_watts, utilization_pct: None }
+}
+
+fn find_hwmon_dir(device_index: usize) -> Option<std::path::PathBuf> {
+    let card_dir = std::path::PathBuf::from(format!("/sys/class/drm/card{}/device/hwmon", device_index));
+    std::fs::read_dir(&card_dir).ok()?.filter_map(Result::ok).map(|entry| entry.path()).next()
+}
+
+fn read_sysfs_u64(path: &std::path::Path) -> Option<u64> {
+    std::fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_limits_keep_resume_below_max() {
+        let limits = ThermalLimits::default();
+        assert!(limits.resume_temp_c < limits.max_temp_c);
+    }
+
+    #[test]
+    fn find_hwmon_dir_is_none_for_a_nonexistent_card() {
+        assert!(find_hwmon_dir(9999).is_none());
+    }
+
+    #[test]
+    fn monitor_reports_not_throttled_before_any_hot_reading() {
+        let monitor = ThermalMonitor::start_amd_sysfs(9999, ThermalLimits::default());
+        // No hwmon node exists for this fake index, so health stays at
+        // defaults and the monitor should never flip into throttled.
+        std::thread::sleep(Duration::from_millis(50));
+        assert_eq!(monitor.health(), DeviceHealth::default());
+        assert!(!monitor.throttled.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn format_health_line_falls_back_to_unavailable() {
+        let line = format_health_line(0, DeviceHealth::default());
+        assert_eq!(line, "GPU 0: util unavailable, temp unavailable, power unavailable");
+    }
+
+    #[test]
+    fn format_health_line_renders_present_readings() {
+        let health = DeviceHealth {
+            temperature_c: Some(68.4),
+            fan_speed_pct: None,
+            power_watts: Some(214.1),
+            utilization_pct: Some(97.0),
+        };
+        assert_eq!(format_health_line(0, health), "GPU 0: 97% util, 68.4C, 214.1W");
+    }
+}
@@ -0,0 +1,421 @@
+//! UTXO Base58Check CUDA Engine
+//!
+//! GPU-accelerated vanity address generation for `ChainFamily::UtxoSecp256k1`
+//! chains (BTC, LTC, DOGE, RVN, ...). One engine instance serves whichever
+//! chain it's constructed with - the only chain-specific input is the
+//! Base58Check version byte (`Chain::address_version_byte`), since HASH160
+//! and Base58 are otherwise identical across the family.
+
+use crate::device::{GpuBackend, GpuDevice};
+use crate::search::{GpuSearchConfig, GpuSearchResult, GpuVanitySearch};
+use omnivanity_chains::{AddressType, GeneratedAddress, Network};
+use omnivanity_crypto::{encoding::base58check_encode, Secp256k1Keypair};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
+
+#[cfg(feature = "cuda")]
+use cudarc::driver::{CudaDevice, CudaSlice, LaunchAsync, LaunchConfig};
+#[cfg(feature = "cuda")]
+use cudarc::nvrtc::compile_ptx;
+
+/// CUDA kernel source for Base58Check P2PKH vanity generation
+const UTXO_KERNEL_SRC: &str = include_str!("kernels/utxo_kernel.cu");
+
+/// UTXO CUDA Engine for GPU vanity search
+pub struct UtxoCudaEngine {
+    #[cfg(feature = "cuda")]
+    device: Arc<CudaDevice>,
+    device_index: usize,
+    config: GpuSearchConfig,
+    ticker: &'static str,
+    version_byte: u8,
+}
+
+impl UtxoCudaEngine {
+    /// Create a new UTXO CUDA engine for `ticker`, encoding candidates with
+    /// `version_byte` (see `Chain::address_version_byte`).
+    #[cfg(feature = "cuda")]
+    pub fn new(device_index: usize, config: GpuSearchConfig, ticker: &'static str, version_byte: u8) -> Result<Self, UtxoCudaError> {
+        let device = CudaDevice::new(device_index)?;
+
+        info!(
+            "Initialized UTXO CUDA engine on device {} for {}: {}",
+            device_index,
+            ticker,
+            device.name().unwrap_or_default()
+        );
+
+        Ok(Self {
+            device: Arc::new(device),
+            device_index,
+            config,
+            ticker,
+            version_byte,
+        })
+    }
+
+    #[cfg(not(feature = "cuda"))]
+    pub fn new(_device_index: usize, _config: GpuSearchConfig, _ticker: &'static str, _version_byte: u8) -> Result<Self, UtxoCudaError> {
+        Err(UtxoCudaError::NotEnabled)
+    }
+
+    /// Compile `UTXO_KERNEL_SRC` via NVRTC, reusing the CUDA driver's own JIT
+    /// cache the same way `EvmCudaEngine::compile_ptx_cached` does.
+    #[cfg(feature = "cuda")]
+    fn compile_ptx_cached(&self) -> Result<cudarc::nvrtc::Ptx, UtxoCudaError> {
+        crate::kernel_cache::enable_cuda_jit_cache();
+        Ok(compile_ptx(UTXO_KERNEL_SRC)?)
+    }
+
+    /// Derive one real secp256k1 base keypair per thread - see
+    /// `EvmCudaEngine::gen_bases`, which this mirrors exactly. The base
+    /// keypair derivation is chain-agnostic, so it isn't worth sharing
+    /// between the two engines over duplicating these few lines.
+    #[cfg(feature = "cuda")]
+    fn gen_bases(total_threads: usize) -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+        let mut privkeys = Vec::with_capacity(total_threads * 32);
+        let mut xs = Vec::with_capacity(total_threads * 32);
+        let mut ys = Vec::with_capacity(total_threads * 32);
+        for _ in 0..total_threads {
+            let keypair = Secp256k1Keypair::generate();
+            privkeys.extend_from_slice(&keypair.private_key_bytes());
+            let xy = keypair.public_key_xy();
+            xs.extend_from_slice(&xy[..32]);
+            ys.extend_from_slice(&xy[32..]);
+        }
+        (privkeys, xs, ys)
+    }
+
+    /// Search for a vanity Base58Check P2PKH address. `pattern` is the
+    /// desired Base58 prefix as ASCII bytes (e.g. `b"1Love"` for BTC) -
+    /// unlike the EVM engine's hex pattern, there's no case-insensitive
+    /// variant here: Base58's mixed-case alphabet means case is always
+    /// significant.
+    #[cfg(feature = "cuda")]
+    pub fn search(&self, pattern: &[u8], stop_flag: Arc<AtomicBool>) -> Option<GpuSearchResult> {
+        let block_size = self.config.block_size as u32;
+        let grid_size = if self.config.grid_size == 0 {
+            (self.device.num_sms() * 4) as u32
+        } else {
+            self.config.grid_size as u32
+        };
+        let keys_per_thread = self.config.keys_per_thread;
+        let total_threads = (grid_size * block_size) as usize;
+
+        info!(
+            "Launching {} search: {} blocks x {} threads x {} keys/thread = {} keys/iteration",
+            self.ticker,
+            grid_size,
+            block_size,
+            keys_per_thread,
+            total_threads * keys_per_thread
+        );
+
+        let ptx = match self.compile_ptx_cached() {
+            Ok(p) => p,
+            Err(e) => {
+                warn!("Failed to compile UTXO kernel: {:?}", e);
+                return None;
+            }
+        };
+
+        if let Err(e) = self.device.load_ptx(ptx, "utxo_search", &["utxo_vanity_search", "utxo_benchmark"]) {
+            warn!("Failed to load PTX: {:?}", e);
+            return None;
+        }
+
+        let func = match self.device.get_func("utxo_search", "utxo_vanity_search") {
+            Some(f) => f,
+            None => {
+                warn!("Kernel function not found");
+                return None;
+            }
+        };
+
+        let (privkeys_host, x_host, y_host) = Self::gen_bases(total_threads);
+
+        let mut base_privkeys_dev = match self.device.htod_sync_copy(&privkeys_host) {
+            Ok(s) => s,
+            Err(e) => {
+                warn!("Failed to copy base private keys to device: {:?}", e);
+                return None;
+            }
+        };
+        let mut base_x_dev = match self.device.htod_sync_copy(&x_host) {
+            Ok(s) => s,
+            Err(e) => {
+                warn!("Failed to copy base x-coordinates to device: {:?}", e);
+                return None;
+            }
+        };
+        let mut base_y_dev = match self.device.htod_sync_copy(&y_host) {
+            Ok(s) => s,
+            Err(e) => {
+                warn!("Failed to copy base y-coordinates to device: {:?}", e);
+                return None;
+            }
+        };
+
+        let found_flag = self.device.alloc_zeros::<i32>(1).ok()?;
+        let result_privkey = self.device.alloc_zeros::<u8>(32).ok()?;
+        let result_hash160 = self.device.alloc_zeros::<u8>(20).ok()?;
+
+        let pattern_dev = self.device.htod_sync_copy(pattern).ok()?;
+
+        let cfg = LaunchConfig {
+            block_dim: (block_size, 1, 1),
+            grid_dim: (grid_size, 1, 1),
+            shared_mem_bytes: 0,
+        };
+
+        let start = Instant::now();
+        let max_time = Duration::from_secs(self.config.max_time_secs);
+        let mut total_keys = 0u64;
+        let mut iteration = 0u32;
+        let thermal = crate::thermal::ThermalMonitor::start_nvml(self.device_index, crate::thermal::ThermalLimits::default());
+
+        loop {
+            if stop_flag.load(Ordering::Relaxed) {
+                break;
+            }
+
+            if self.config.max_time_secs > 0 && start.elapsed() > max_time {
+                break;
+            }
+
+            if self.config.max_attempts > 0 && total_keys >= self.config.max_attempts {
+                break;
+            }
+
+            thermal.throttle_if_needed();
+
+            if iteration > 0 {
+                let (privkeys_host, x_host, y_host) = Self::gen_bases(total_threads);
+                base_privkeys_dev = match self.device.htod_sync_copy(&privkeys_host) {
+                    Ok(s) => s,
+                    Err(e) => {
+                        warn!("Failed to copy base private keys to device: {:?}", e);
+                        break;
+                    }
+                };
+                base_x_dev = match self.device.htod_sync_copy(&x_host) {
+                    Ok(s) => s,
+                    Err(e) => {
+                        warn!("Failed to copy base x-coordinates to device: {:?}", e);
+                        break;
+                    }
+                };
+                base_y_dev = match self.device.htod_sync_copy(&y_host) {
+                    Ok(s) => s,
+                    Err(e) => {
+                        warn!("Failed to copy base y-coordinates to device: {:?}", e);
+                        break;
+                    }
+                };
+            }
+
+            unsafe {
+                if let Err(e) = func.launch(
+                    cfg.clone(),
+                    (
+                        &base_privkeys_dev,
+                        &base_x_dev,
+                        &base_y_dev,
+                        &found_flag,
+                        &result_privkey,
+                        &result_hash160,
+                        &pattern_dev,
+                        pattern.len() as i32,
+                        keys_per_thread as i32,
+                        self.version_byte,
+                    ),
+                ) {
+                    warn!("Kernel launch failed: {:?}", e);
+                    break;
+                }
+            }
+
+            if let Err(e) = self.device.synchronize() {
+                warn!("Sync failed: {:?}", e);
+                break;
+            }
+
+            let mut flag_host = [0i32; 1];
+            if let Err(e) = self.device.dtoh_sync_copy_into(&found_flag, &mut flag_host) {
+                warn!("Failed to copy found flag: {:?}", e);
+                break;
+            }
+
+            if flag_host[0] != 0 {
+                let mut privkey = vec![0u8; 32];
+                let mut h160 = vec![0u8; 20];
+                if let Err(e) = self.device.dtoh_sync_copy_into(&result_privkey, &mut privkey) {
+                    warn!("Failed to copy result privkey: {:?}", e);
+                    break;
+                }
+                if let Err(e) = self.device.dtoh_sync_copy_into(&result_hash160, &mut h160) {
+                    warn!("Failed to copy result hash160: {:?}", e);
+                    break;
+                }
+
+                let elapsed = start.elapsed().as_secs_f64();
+                let keys_per_second = total_keys as f64 / elapsed;
+
+                info!(
+                    "Match found on device {} after {} keys",
+                    self.device_index,
+                    total_keys
+                );
+
+                // The device only ever Base58-encodes `version || hash160`
+                // (no checksum, see utxo_kernel.cu's header comment) - redo
+                // the real, checksummed encoding here for the one candidate
+                // that actually matched.
+                let address = base58check_encode(self.version_byte, &h160);
+
+                return Some(GpuSearchResult {
+                    address: GeneratedAddress {
+                        address,
+                        private_key_hex: hex::encode(&privkey),
+                        private_key_native: hex::encode(&privkey),
+                        public_key_hex: String::new(),
+                        chain: self.ticker.to_string(),
+                        address_type: AddressType::P2pkh,
+                        mnemonic: None,
+                        derivation_path: None,
+                        network: Network::Mainnet,
+                    },
+                    pattern: String::new(),
+                    keys_tested: total_keys,
+                    time_secs: elapsed,
+                    keys_per_second,
+                    found_on_device: self.device_index,
+                });
+            }
+
+            total_keys += (total_threads * keys_per_thread) as u64;
+            iteration += 1;
+
+            if iteration % 10 == 0 {
+                let elapsed = start.elapsed().as_secs_f64();
+                let rate = total_keys as f64 / elapsed / 1_000_000.0;
+                debug!(
+                    "GPU {}: {} keys tested ({:.2} Mkey/s)",
+                    self.device_index,
+                    total_keys,
+                    rate
+                );
+            }
+        }
+
+        None
+    }
+
+    #[cfg(not(feature = "cuda"))]
+    pub fn search(&self, _pattern: &[u8], _stop_flag: Arc<AtomicBool>) -> Option<GpuSearchResult> {
+        None
+    }
+
+    /// Benchmark GPU HASH160 + Base58 throughput
+    #[cfg(feature = "cuda")]
+    pub fn benchmark(&self, duration_secs: u64) -> Result<f64, UtxoCudaError> {
+        let block_size = self.config.block_size as u32;
+        let grid_size = if self.config.grid_size == 0 {
+            (self.device.num_sms() * 4) as u32
+        } else {
+            self.config.grid_size as u32
+        };
+        let keys_per_thread = self.config.keys_per_thread;
+        let total_threads = (grid_size * block_size) as usize;
+
+        let ptx = self.compile_ptx_cached()?;
+        self.device.load_ptx(ptx, "utxo_bench", &["utxo_benchmark"])?;
+
+        let func = self.device.get_func("utxo_bench", "utxo_benchmark")
+            .ok_or(UtxoCudaError::KernelNotFound)?;
+
+        let (_, x_host, y_host) = Self::gen_bases(total_threads);
+        let mut base_x_dev = self.device.htod_sync_copy(&x_host)?;
+        let mut base_y_dev = self.device.htod_sync_copy(&y_host)?;
+        let counter_dev = self.device.alloc_zeros::<u64>(1)?;
+
+        let cfg = LaunchConfig {
+            block_dim: (block_size, 1, 1),
+            grid_dim: (grid_size, 1, 1),
+            shared_mem_bytes: 0,
+        };
+
+        unsafe {
+            func.launch(cfg.clone(), (&base_x_dev, &base_y_dev, &counter_dev, keys_per_thread as i32, self.version_byte))?;
+        }
+        self.device.synchronize()?;
+
+        let start = Instant::now();
+        let mut total_keys = 0u64;
+        let max_time = Duration::from_secs(duration_secs);
+
+        while start.elapsed() < max_time {
+            let (_, x_host, y_host) = Self::gen_bases(total_threads);
+            base_x_dev = self.device.htod_sync_copy(&x_host)?;
+            base_y_dev = self.device.htod_sync_copy(&y_host)?;
+            unsafe {
+                func.launch(cfg.clone(), (&base_x_dev, &base_y_dev, &counter_dev, keys_per_thread as i32, self.version_byte))?;
+            }
+            self.device.synchronize()?;
+            total_keys += (total_threads * keys_per_thread) as u64;
+        }
+
+        let elapsed = start.elapsed().as_secs_f64();
+        let keys_per_second = total_keys as f64 / elapsed;
+
+        Ok(keys_per_second)
+    }
+
+    #[cfg(not(feature = "cuda"))]
+    pub fn benchmark(&self, _duration_secs: u64) -> Result<f64, UtxoCudaError> {
+        Err(UtxoCudaError::NotEnabled)
+    }
+}
+
+impl GpuVanitySearch for UtxoCudaEngine {
+    fn chain(&self) -> &'static str {
+        self.ticker
+    }
+
+    fn address_types(&self) -> Vec<AddressType> {
+        vec![AddressType::P2pkh]
+    }
+
+    fn search(
+        &self,
+        pattern: &str,
+        _address_type: AddressType,
+        _config: &GpuSearchConfig,
+    ) -> Option<GpuSearchResult> {
+        let stop_flag = Arc::new(AtomicBool::new(false));
+        self.search(pattern.as_bytes(), stop_flag)
+    }
+
+    fn benchmark(&self, duration_secs: u64, _config: &GpuSearchConfig) -> f64 {
+        self.benchmark(duration_secs).unwrap_or(0.0)
+    }
+}
+
+/// UTXO CUDA error type
+#[derive(Debug, thiserror::Error)]
+pub enum UtxoCudaError {
+    #[cfg(feature = "cuda")]
+    #[error("CUDA driver error: {0}")]
+    DriverError(#[from] cudarc::driver::DriverError),
+    #[cfg(feature = "cuda")]
+    #[error("NVRTC compilation error: {0}")]
+    CompileError(#[from] cudarc::nvrtc::CompileError),
+    #[error("No CUDA devices found")]
+    NoDevices,
+    #[error("Kernel not found")]
+    KernelNotFound,
+    #[error("CUDA not enabled")]
+    NotEnabled,
+}
@@ -2,12 +2,31 @@
 
 use crate::PatternType;
 
-/// Calculate the difficulty (expected number of attempts) for a pattern
+/// Calculate the difficulty (expected number of attempts) for a pattern.
+///
+/// `eip55` marks a pattern whose upper/lowercase hex letters must match an
+/// EIP-55 checksum exactly (see `Pattern::eip55`) - each letter's casing is
+/// effectively an independent random coin flip of the address hash, on top
+/// of needing the right hex digit, so it's harder than an ordinary
+/// case-sensitive literal by a factor of `2^num_letters` and must not be
+/// folded into `case_insensitive`'s (which makes matching *easier*) branch.
 pub fn calculate_difficulty(
     pattern: &str,
     pattern_type: PatternType,
     alphabet_size: usize,
     case_insensitive: bool,
+) -> f64 {
+    calculate_difficulty_ex(pattern, pattern_type, alphabet_size, case_insensitive, false)
+}
+
+/// Like [`calculate_difficulty`], but lets the caller opt into the EIP-55
+/// checksum-case accounting described there.
+pub fn calculate_difficulty_ex(
+    pattern: &str,
+    pattern_type: PatternType,
+    alphabet_size: usize,
+    case_insensitive: bool,
+    eip55: bool,
 ) -> f64 {
     let effective_alphabet = if case_insensitive {
         // For case insensitive, we have more matches possible
@@ -18,15 +37,17 @@ pub fn calculate_difficulty(
     };
 
     let pattern_len = pattern.len();
+    let num_letters = pattern.chars().filter(|c| c.is_alphabetic()).count();
 
     match pattern_type {
         PatternType::Prefix | PatternType::Suffix => {
             // Difficulty = alphabet_size ^ pattern_length
             let base_difficulty = (effective_alphabet as f64).powi(pattern_len as i32);
-            
-            if case_insensitive {
+
+            if eip55 {
+                base_difficulty * (2.0_f64).powi(num_letters as i32)
+            } else if case_insensitive {
                 // Reduce difficulty by factor of 2^num_letters
-                let num_letters = pattern.chars().filter(|c| c.is_alphabetic()).count();
                 base_difficulty / (2.0_f64).powi(num_letters as i32)
             } else {
                 base_difficulty
@@ -38,11 +59,12 @@ pub fn calculate_difficulty(
             // Assuming ~40 char address
             let address_len = 40.0;
             let positions = (address_len - pattern_len as f64 + 1.0).max(1.0);
-            
+
             let base_difficulty = (effective_alphabet as f64).powi(pattern_len as i32) / positions;
-            
-            if case_insensitive {
-                let num_letters = pattern.chars().filter(|c| c.is_alphabetic()).count();
+
+            if eip55 {
+                base_difficulty * (2.0_f64).powi(num_letters as i32)
+            } else if case_insensitive {
                 base_difficulty / (2.0_f64).powi(num_letters as i32)
             } else {
                 base_difficulty
@@ -51,6 +73,29 @@ pub fn calculate_difficulty(
     }
 }
 
+/// Combined (union) difficulty across several patterns searched at once -
+/// matching *any* of them is easier than matching one, so the combined
+/// difficulty is always <= the easiest individual pattern's. Treats each
+/// pattern's per-attempt match probability as independent (the same
+/// approximation `PatternType::Contains`'s own difficulty already makes) and
+/// returns `1 / (1 - product(1 - 1/d_i))`, i.e. the inverse of the union
+/// probability. An empty slice falls back to `1.0`, same as `new`'s
+/// no-patterns case.
+pub fn calculate_combined_difficulty(difficulties: &[f64]) -> f64 {
+    if difficulties.is_empty() {
+        return 1.0;
+    }
+
+    let none_match: f64 = difficulties.iter().map(|&d| 1.0 - (1.0 / d)).product();
+    let union_probability = 1.0 - none_match;
+
+    if union_probability <= 0.0 {
+        f64::INFINITY
+    } else {
+        1.0 / union_probability
+    }
+}
+
 /// Format difficulty as human-readable string
 pub fn format_difficulty(difficulty: f64) -> String {
     if difficulty >= 1e15 {
@@ -112,11 +157,35 @@ mod tests {
     fn test_case_insensitive_reduces_difficulty() {
         let case_sensitive = calculate_difficulty("dead", PatternType::Prefix, 16, false);
         let case_insensitive = calculate_difficulty("dead", PatternType::Prefix, 16, true);
-        
+
         // Case insensitive should be easier (lower difficulty)
         assert!(case_insensitive < case_sensitive);
     }
 
+    #[test]
+    fn test_eip55_increases_difficulty_over_plain_case_sensitive() {
+        let literal = calculate_difficulty_ex("dead", PatternType::Prefix, 16, false, false);
+        let checksummed = calculate_difficulty_ex("dead", PatternType::Prefix, 16, false, true);
+
+        // Matching the checksum casing too is strictly harder: on top of the
+        // 16^4 hex digits, each of the 4 letters must also land on the right
+        // side of a checksum coin flip.
+        assert_eq!(checksummed, literal * 16.0);
+    }
+
+    #[test]
+    fn test_combined_difficulty_is_easier_than_either_alone() {
+        let d1 = calculate_difficulty("dead", PatternType::Prefix, 16, false);
+        let d2 = calculate_difficulty("beef", PatternType::Prefix, 16, false);
+        let combined = calculate_combined_difficulty(&[d1, d2]);
+        assert!(combined < d1.min(d2));
+    }
+
+    #[test]
+    fn test_combined_difficulty_empty_is_one() {
+        assert_eq!(calculate_combined_difficulty(&[]), 1.0);
+    }
+
     #[test]
     fn test_format_difficulty() {
         assert_eq!(format_difficulty(1000.0), "1.00K");
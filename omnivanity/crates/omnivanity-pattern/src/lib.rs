@@ -6,4 +6,4 @@ mod matcher;
 mod difficulty;
 
 pub use matcher::{Pattern, PatternType, PatternMatcher};
-pub use difficulty::calculate_difficulty;
+pub use difficulty::{calculate_difficulty, calculate_difficulty_ex, calculate_combined_difficulty, format_difficulty, estimate_time_50pct, format_duration};
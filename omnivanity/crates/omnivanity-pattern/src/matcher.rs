@@ -33,6 +33,14 @@ pub struct Pattern {
     pub pattern_type: PatternType,
     /// Case insensitive matching
     pub case_insensitive: bool,
+    /// EIP-55 checksum-case matching: `value`'s upper/lowercase hex letters
+    /// must match the EIP-55-checksummed address exactly, instead of either
+    /// being ignored (`case_insensitive`) or treated as a fixed literal whose
+    /// difficulty doesn't account for checksum casing being effectively
+    /// random per letter. Mutually exclusive with `case_insensitive` - set on
+    /// an EVM pattern to opt into checksum-case-sensitive grinding.
+    #[serde(default)]
+    pub eip55: bool,
 }
 
 impl Pattern {
@@ -42,6 +50,7 @@ impl Pattern {
             value: value.into(),
             pattern_type: PatternType::Prefix,
             case_insensitive: false,
+            eip55: false,
         }
     }
 
@@ -51,6 +60,7 @@ impl Pattern {
             value: value.into(),
             pattern_type: PatternType::Suffix,
             case_insensitive: false,
+            eip55: false,
         }
     }
 
@@ -60,12 +70,22 @@ impl Pattern {
             value: value.into(),
             pattern_type: PatternType::Contains,
             case_insensitive: false,
+            eip55: false,
         }
     }
 
     /// Make pattern case insensitive
     pub fn case_insensitive(mut self) -> Self {
         self.case_insensitive = true;
+        self.eip55 = false;
+        self
+    }
+
+    /// Require the pattern's upper/lowercase hex letters to match an
+    /// EIP-55-checksummed address exactly (see the `eip55` field).
+    pub fn eip55(mut self) -> Self {
+        self.eip55 = true;
+        self.case_insensitive = false;
         self
     }
 
@@ -114,11 +134,14 @@ impl PatternMatcher {
         Self { patterns: vec![pattern] }
     }
 
-    /// Check if address matches any pattern
+    /// Check if address matches any pattern. `chain_prefix` is the address's
+    /// real fixed prefix (e.g. `Chain::address_prefix(address_type)`) and is
+    /// stripped before prefix matching, instead of guessing it from the
+    /// address's leading characters.
     /// Returns the index of the matching pattern, or None
-    pub fn matches(&self, address: &str) -> Option<usize> {
+    pub fn matches(&self, address: &str, chain_prefix: &str) -> Option<usize> {
         for (i, pattern) in self.patterns.iter().enumerate() {
-            if self.check_pattern(address, pattern) {
+            if self.check_pattern(address, chain_prefix, pattern) {
                 return Some(i);
             }
         }
@@ -126,7 +149,7 @@ impl PatternMatcher {
     }
 
     /// Check if address matches a specific pattern
-    fn check_pattern(&self, address: &str, pattern: &Pattern) -> bool {
+    fn check_pattern(&self, address: &str, chain_prefix: &str, pattern: &Pattern) -> bool {
         let addr = if pattern.case_insensitive {
             address.to_lowercase()
         } else {
@@ -141,21 +164,17 @@ impl PatternMatcher {
 
         match pattern.pattern_type {
             PatternType::Prefix => {
-                // Skip common prefixes like 0x
-                let addr_to_check = if addr.starts_with("0x") {
-                    &addr[2..]
-                } else if addr.starts_with("bc1q") || addr.starts_with("bc1p") {
-                    &addr[4..]
-                } else if addr.starts_with("ltc1q") {
-                    &addr[5..]
-                } else if addr.starts_with("t1") {
-                    &addr[2..]
-                } else if addr.len() > 1 && (addr.starts_with('1') || addr.starts_with('3') || 
-                          addr.starts_with('L') || addr.starts_with('M') || addr.starts_with('D')) {
-                    &addr[1..]
+                // Strip the chain's real address prefix (its exact fixed
+                // prefix or bech32 HRP, e.g. "0x", "bc1q"/"bc1p", "t1"),
+                // rather than guessing from the address's leading characters -
+                // a fixed list can't tell apart e.g. a base58 address that
+                // legitimately starts with '1' from a stripped prefix.
+                let prefix_to_strip = if pattern.case_insensitive {
+                    chain_prefix.to_lowercase()
                 } else {
-                    &addr
+                    chain_prefix.to_string()
                 };
+                let addr_to_check = addr.strip_prefix(prefix_to_strip.as_str()).unwrap_or(&addr);
                 addr_to_check.starts_with(&pat)
             }
             PatternType::Suffix => addr.ends_with(&pat),
@@ -176,49 +195,78 @@ mod tests {
     #[test]
     fn test_prefix_match() {
         let matcher = PatternMatcher::single(Pattern::prefix("dead"));
-        
+
         // ETH address with 0x prefix
-        assert!(matcher.matches("0xdeadbeef1234567890abcdef1234567890abcdef").is_some());
-        assert!(matcher.matches("0xabcd1234567890abcdef1234567890abcdef1234").is_none());
+        assert!(matcher.matches("0xdeadbeef1234567890abcdef1234567890abcdef", "0x").is_some());
+        assert!(matcher.matches("0xabcd1234567890abcdef1234567890abcdef1234", "0x").is_none());
     }
 
     #[test]
     fn test_suffix_match() {
         let matcher = PatternMatcher::single(Pattern::suffix("dead"));
-        
-        assert!(matcher.matches("0x1234567890abcdef1234567890abcdef1234dead").is_some());
-        assert!(matcher.matches("0x1234567890abcdef1234567890abcdef12341234").is_none());
+
+        assert!(matcher.matches("0x1234567890abcdef1234567890abcdef1234dead", "0x").is_some());
+        assert!(matcher.matches("0x1234567890abcdef1234567890abcdef12341234", "0x").is_none());
     }
 
     #[test]
     fn test_contains_match() {
         let matcher = PatternMatcher::single(Pattern::contains("cafe"));
-        
-        assert!(matcher.matches("0x1234cafe567890abcdef1234567890abcdef1234").is_some());
-        assert!(matcher.matches("0x1234567890abcdef1234567890abcdef12341234").is_none());
+
+        assert!(matcher.matches("0x1234cafe567890abcdef1234567890abcdef1234", "0x").is_some());
+        assert!(matcher.matches("0x1234567890abcdef1234567890abcdef12341234", "0x").is_none());
+    }
+
+    #[test]
+    fn test_eip55_requires_exact_casing() {
+        let matcher = PatternMatcher::single(Pattern::prefix("DeAd").eip55());
+
+        assert!(matcher.matches("0xDeAdbeef1234567890abcdef1234567890abcdef", "0x").is_some());
+        // Wrong letter casing doesn't match, unlike a case-insensitive pattern.
+        assert!(matcher.matches("0xdeadbeef1234567890abcdef1234567890abcdef", "0x").is_none());
+    }
+
+    #[test]
+    fn test_eip55_builder_clears_case_insensitive() {
+        let pattern = Pattern::prefix("DeAd").case_insensitive().eip55();
+        assert!(pattern.eip55);
+        assert!(!pattern.case_insensitive);
     }
 
     #[test]
     fn test_case_insensitive() {
         let matcher = PatternMatcher::single(Pattern::prefix("DEAD").case_insensitive());
-        
-        assert!(matcher.matches("0xdeadbeef1234567890abcdef1234567890abcdef").is_some());
-        assert!(matcher.matches("0xDEADbeef1234567890abcdef1234567890abcdef").is_some());
+
+        assert!(matcher.matches("0xdeadbeef1234567890abcdef1234567890abcdef", "0x").is_some());
+        assert!(matcher.matches("0xDEADbeef1234567890abcdef1234567890abcdef", "0x").is_some());
     }
 
     #[test]
     fn test_btc_prefix() {
         let matcher = PatternMatcher::single(Pattern::prefix("Love"));
-        
-        // BTC legacy address starts with 1
-        assert!(matcher.matches("1Love1234567890abcdef1234567890ab").is_some());
+
+        // BTC legacy addresses start with '1', which is BTC's P2PKH
+        // `address_prefix` - stripped as an exact-character match, not by
+        // the old heuristic's blanket strip.
+        assert!(matcher.matches("1Love1234567890abcdef1234567890ab", "1").is_some());
+    }
+
+    #[test]
+    fn test_prefix_strip_is_exact_not_heuristic() {
+        // A base58 address legitimately starting with '1' (no real prefix to
+        // strip) used to have that leading '1' silently eaten by the old
+        // "any address starting with 1/3/L/M/D" heuristic. Passing the real
+        // (empty) chain prefix means the literal leading '1' must be matched.
+        let matcher = PatternMatcher::single(Pattern::prefix("1"));
+        assert!(matcher.matches("1Love1234567890abcdef1234567890ab", "").is_some());
+        assert!(matcher.matches("Love1234567890abcdef1234567890ab", "").is_none());
     }
 
     #[test]
     fn test_validate_pattern() {
         let pattern = Pattern::prefix("dead");
         assert!(pattern.validate("0123456789abcdef").is_ok());
-        
+
         let bad_pattern = Pattern::prefix("ghij");
         assert!(bad_pattern.validate("0123456789abcdef").is_err());
     }
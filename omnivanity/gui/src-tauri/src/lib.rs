@@ -45,6 +45,10 @@ pub struct GuiSearchResult {
     pub keys_tested_formatted: String,
     pub time_secs: f64,
     pub keys_per_second: f64,
+    /// Set when the match came from HD (BIP39/BIP32) search - the seed
+    /// phrase a wallet can import to recover this exact address. `None` for
+    /// plain random-key search, which has no mnemonic to report.
+    pub mnemonic: Option<String>,
 }
 
 #[tauri::command]
@@ -57,6 +61,13 @@ async fn search_vanity(
     address_type: Option<String>,
     use_gpu: Option<bool>,
     batch_size: Option<u32>,
+    hd_base_path: Option<String>,
+    hd_mnemonic: Option<String>,
+    hd_passphrase: Option<String>,
+    /// Opt into EIP-55 checksum-case-sensitive matching for EVM chains
+    /// instead of a fast case-insensitive/literal prefix search - see
+    /// `omnivanity_pattern::Pattern::eip55`. Ignored for non-EVM chains.
+    eip55: Option<bool>,
 ) -> Result<GuiSearchResult, String> {
     // Reset stop flag
     STOP_FLAG.store(false, Ordering::Relaxed);
@@ -89,10 +100,12 @@ async fn search_vanity(
     };
     
     // Create pattern
+    let eip55 = eip55.unwrap_or(false) && chain_impl.family() == omnivanity_core::ChainFamily::Evm;
     let mut pat = Pattern {
         value: pattern.clone(),
         pattern_type: pat_type,
-        case_insensitive,
+        case_insensitive: case_insensitive && !eip55,
+        eip55,
     };
     
     // Validate pattern
@@ -116,8 +129,15 @@ async fn search_vanity(
         max_attempts: 0,
         max_time_secs: 0, // No limit
         use_gpu: use_gpu.unwrap_or(true),
+        hybrid: false,
+        device_indices: vec![],
+        gpu_backend: None,
+        hd_base_path,
+        hd_mnemonic,
+        hd_passphrase: hd_passphrase.unwrap_or_default(),
+        ..Default::default()
     };
-    
+
     // Create and run search
     let search = VanitySearch::new(
         chain_impl,
@@ -190,6 +210,7 @@ async fn search_vanity(
                 keys_tested_formatted: format_keys(r.keys_tested),
                 time_secs: r.time_secs,
                 keys_per_second: r.keys_per_second,
+                mnemonic: r.address.mnemonic,
             })
         },
         None => {
@@ -214,6 +235,245 @@ fn stop_search() {
     STOP_FLAG.store(true, Ordering::Relaxed);
 }
 
+/// Split-key ("delegated") search result - deliberately has no private key
+/// field, since the worker running this command never has one to offer.
+/// The owner recovers the real key from `offset_hex` via `combine_split_key`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GuiSplitResult {
+    pub address: String,
+    pub offset_hex: String,
+    pub keys_tested_formatted: String,
+    pub time_secs: f64,
+    pub keys_per_second: f64,
+}
+
+/// Parse an `address_type` argument the same way `search_vanity` does,
+/// falling back to `chain`'s default when absent/unrecognized.
+fn parse_address_type(chain: &dyn omnivanity_core::Chain, address_type: Option<&str>) -> AddressType {
+    match address_type {
+        Some("legacy") | Some("p2pkh") => AddressType::P2pkh,
+        Some("segwit") | Some("p2wpkh") => AddressType::P2wpkh,
+        Some("taproot") | Some("p2tr") => AddressType::P2tr,
+        _ => chain.default_address_type(),
+    }
+}
+
+/// Grind a vanity address for an untrusted worker, given only the owner's
+/// compressed secp256k1 public point - never a private key, so the worker
+/// can never spend from whatever address it finds. See
+/// `omnivanity_chains::split_search` for the underlying offset walk.
+#[tauri::command]
+async fn search_vanity_split(
+    app: AppHandle,
+    chain: String,
+    pattern: String,
+    pattern_type: String,
+    case_insensitive: bool,
+    address_type: Option<String>,
+    partial_pubkey_hex: String,
+) -> Result<GuiSplitResult, String> {
+    use omnivanity_chains::split_search::SplitVanitySearch;
+    use omnivanity_chains::vanity::PatternSpec;
+
+    STOP_FLAG.store(false, Ordering::Relaxed);
+
+    let chain_impl = get_chain(&chain).ok_or_else(|| format!("Unknown chain: {}", chain))?;
+    let addr_type = parse_address_type(chain_impl.as_ref(), address_type.as_deref());
+
+    let mut spec = match pattern_type.as_str() {
+        "suffix" => PatternSpec::suffix(pattern.clone()),
+        _ => PatternSpec::prefix(pattern.clone()),
+    };
+    if case_insensitive {
+        spec = spec.case_insensitive();
+    }
+
+    let pubkey = hex::decode(partial_pubkey_hex.trim_start_matches("0x"))
+        .map_err(|e| format!("Invalid public key hex: {}", e))?;
+
+    let app_handle = app.clone();
+    tokio::task::spawn_blocking(move || {
+        let search = SplitVanitySearch::new(chain_impl.as_ref(), addr_type, spec, &pubkey).map_err(|e| e.to_string())?;
+        let found = search.run(|keys_tested, kps| {
+            if STOP_FLAG.load(Ordering::Relaxed) {
+                // `SplitVanitySearch` has no mid-search cancellation hook, so
+                // a stop request only silences these progress events.
+                return;
+            }
+            let _ = app_handle.emit("search-stats", SearchStatsEvent {
+                keys_tested: format_keys(keys_tested),
+                keys_per_second: kps,
+                keys_per_second_fmt: format!("{} keys/s", format_keys_short(kps as u64)),
+                probability_percent: 0.0,
+                est_time_50_percent: "calculating...".to_string(),
+            });
+        });
+
+        Ok(GuiSplitResult {
+            address: found.address,
+            offset_hex: hex::encode(found.offset),
+            keys_tested_formatted: format_keys(found.attempts),
+            time_secs: found.elapsed_secs,
+            keys_per_second: found.attempts as f64 / found.elapsed_secs.max(1e-9),
+        })
+    })
+    .await
+    .map_err(|e| format!("Split search task failed: {}", e))?
+}
+
+/// Owner-side recovery: combine a split-key search's reported offset with
+/// the secret scalar only the owner ever held, and return the address the
+/// resulting private key derives. The command is not given the address the
+/// search reported, so it does not compare the two.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GuiCombinedKey {
+    pub private_key_hex: String,
+    pub address: String,
+}
+
+#[tauri::command]
+fn combine_split_key(
+    chain: String,
+    address_type: Option<String>,
+    secret_hex: String,
+    offset_hex: String,
+) -> Result<GuiCombinedKey, String> {
+    let chain_impl = get_chain(&chain).ok_or_else(|| format!("Unknown chain: {}", chain))?;
+    let addr_type = parse_address_type(chain_impl.as_ref(), address_type.as_deref());
+
+    let secret = hex::decode(secret_hex.trim_start_matches("0x")).map_err(|e| format!("Invalid secret hex: {}", e))?;
+    let offset = hex::decode(offset_hex.trim_start_matches("0x")).map_err(|e| format!("Invalid offset hex: {}", e))?;
+    if secret.len() != 32 || offset.len() != 32 {
+        return Err("secret and offset must each be 32 bytes".to_string());
+    }
+    let mut secret_bytes = [0u8; 32];
+    let mut offset_bytes = [0u8; 32];
+    secret_bytes.copy_from_slice(&secret);
+    offset_bytes.copy_from_slice(&offset);
+
+    let private_key =
+        omnivanity_crypto::combine_split_key(&secret_bytes, &offset_bytes).map_err(|e| e.to_string())?;
+    let address = chain_impl
+        .generate_from_bytes(&private_key, addr_type)
+        .ok_or_else(|| "combined private key did not produce a valid address".to_string())?;
+
+    Ok(GuiCombinedKey { private_key_hex: hex::encode(private_key), address: address.address })
+}
+
+/// HD gap-limit search result: a vanity address reachable from an existing
+/// wallet's own account tree, instead of an orphan key. `xpub` is the
+/// extended public key at `derivation_path`, shareable with anyone who needs
+/// to verify `address` without learning the private key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GuiHdResult {
+    pub address: String,
+    pub derivation_path: String,
+    pub xpub: String,
+    pub child_index: u64,
+    pub keys_tested_formatted: String,
+    pub time_secs: f64,
+    pub keys_per_second: f64,
+}
+
+/// Grind a vanity address by varying only the last (non-hardened) index of
+/// `base_path` off a fixed seed/mnemonic - `base_path` excludes that index,
+/// e.g. `"m/44'/60'/0'/0"` to scan `.../0`, `.../1`, ... up to `gap_limit`.
+/// Gives up (`Err`) once `gap_limit` is exhausted with no match, the same
+/// way a wallet stops looking for used addresses past its own gap limit.
+#[tauri::command]
+async fn search_vanity_hd(
+    app: AppHandle,
+    chain: String,
+    pattern: String,
+    pattern_type: String,
+    case_insensitive: bool,
+    address_type: Option<String>,
+    seed_mnemonic: String,
+    seed_passphrase: Option<String>,
+    base_path: String,
+    gap_limit: u64,
+) -> Result<GuiHdResult, String> {
+    use omnivanity_chains::hd_search::HdVanitySearch;
+    use omnivanity_chains::vanity::PatternSpec;
+
+    STOP_FLAG.store(false, Ordering::Relaxed);
+
+    let chain_impl = get_chain(&chain).ok_or_else(|| format!("Unknown chain: {}", chain))?;
+    let addr_type = parse_address_type(chain_impl.as_ref(), address_type.as_deref());
+
+    let mut spec = match pattern_type.as_str() {
+        "suffix" => PatternSpec::suffix(pattern.clone()),
+        _ => PatternSpec::prefix(pattern.clone()),
+    };
+    if case_insensitive {
+        spec = spec.case_insensitive();
+    }
+
+    let seed = omnivanity_crypto::mnemonic_to_seed(&seed_mnemonic, &seed_passphrase.unwrap_or_default())
+        .map_err(|e| format!("Invalid seed mnemonic: {}", e))?
+        .to_vec();
+
+    let app_handle = app.clone();
+    let base_path_for_search = base_path.clone();
+    tokio::task::spawn_blocking(move || {
+        let search = HdVanitySearch::new(chain_impl.as_ref(), addr_type, spec, seed.clone(), base_path_for_search)
+            .map_err(|e| e.to_string())?;
+        let found = search
+            .run_bounded(gap_limit, |progress| {
+                let _ = app_handle.emit("search-stats", SearchStatsEvent {
+                    keys_tested: format_keys(progress.attempts),
+                    keys_per_second: progress.attempts_per_sec,
+                    keys_per_second_fmt: format!("{} keys/s", format_keys_short(progress.attempts_per_sec as u64)),
+                    probability_percent: 0.0,
+                    est_time_50_percent: "calculating...".to_string(),
+                });
+            })
+            .ok_or_else(|| format!("No match found within the first {} child addresses", gap_limit))?;
+
+        let derivation_path = found.address.derivation_path.clone().unwrap_or_default();
+        let (_, xpub) = omnivanity_crypto::derive_bip32_extended(&seed, &derivation_path).map_err(|e| e.to_string())?;
+        let child_index = derivation_path
+            .rsplit('/')
+            .next()
+            .and_then(|segment| segment.trim_end_matches(['\'', 'h']).parse::<u64>().ok())
+            .unwrap_or(0);
+
+        Ok(GuiHdResult {
+            address: found.address.address,
+            derivation_path,
+            xpub,
+            child_index,
+            keys_tested_formatted: format_keys(found.attempts),
+            time_secs: found.elapsed_secs,
+            keys_per_second: found.attempts as f64 / found.elapsed_secs.max(1e-9),
+        })
+    })
+    .await
+    .map_err(|e| format!("HD search task failed: {}", e))?
+}
+
+/// Produce a portable ownership proof for a found vanity address, in
+/// whichever signed-message format that chain's wallets expect - see
+/// `Chain::sign_message` for the exact per-family framing.
+#[tauri::command]
+fn sign_message(chain: String, private_key_hex: String, message: String) -> Result<String, String> {
+    let chain_impl = get_chain(&chain).ok_or_else(|| format!("Unknown chain: {}", chain))?;
+    let private_key =
+        hex::decode(private_key_hex.trim_start_matches("0x")).map_err(|e| format!("Invalid private key hex: {}", e))?;
+    chain_impl.sign_message(&private_key, message.as_bytes()).map_err(|e| e.to_string())
+}
+
+/// Check a `sign_message` proof: recovers the signer and confirms it
+/// re-derives `address` - see `Chain::verify_message`.
+#[tauri::command]
+fn verify_message(chain: String, address: String, message: String, signature: String) -> Result<bool, String> {
+    let chain_impl = get_chain(&chain).ok_or_else(|| format!("Unknown chain: {}", chain))?;
+    Ok(chain_impl.verify_message(&address, message.as_bytes(), &signature))
+}
+
 #[tauri::command]
 fn list_chains() -> Vec<ChainInfo> {
     omnivanity_core::all_chains()
@@ -255,6 +515,8 @@ fn list_chains() -> Vec<ChainInfo> {
                         AddressType::Cardano => ("cardano", "Cardano"),
                         AddressType::Monero => ("monero", "Monero"),
                         AddressType::Icp => ("icp", "ICP Principal"),
+                        AddressType::Penumbra => ("penumbra", "Penumbra (shielded)"),
+                        _ => ("other", "Other"),
                     };
                     AddressTypeInfo {
                         id: id.to_string(),
@@ -334,7 +596,12 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             search_vanity,
             stop_search,
-            list_chains
+            list_chains,
+            search_vanity_split,
+            combine_split_key,
+            search_vanity_hd,
+            sign_message,
+            verify_message
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");